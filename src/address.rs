@@ -0,0 +1,127 @@
+use alloc::format;
+use core::fmt::Display;
+
+use crate::{align_up, io, Class, PackableClass, UnpackError, UnpackableClass};
+
+/// An address or file offset that is sized depending on the ELF class
+///
+/// Other than a bare `u64`, packing an address that does
+/// not fit into an ELF32 file fails instead of truncating it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Address(pub u64);
+
+impl Address {
+    /// Returns the raw value of this address
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Adds `offset` to this address
+    /// # Arguments
+    /// * `offset` - The offset to add
+    /// # Returns
+    /// `None` if the result does not fit into a `u64`
+    pub fn checked_add(&self, offset: u64) -> Option<Address> {
+        self.0.checked_add(offset).map(Self)
+    }
+
+    /// Subtracts `offset` from this address
+    /// # Arguments
+    /// * `offset` - The offset to subtract
+    /// # Returns
+    /// `None` if the result would be negative
+    pub fn checked_sub(&self, offset: u64) -> Option<Address> {
+        self.0.checked_sub(offset).map(Self)
+    }
+
+    /// Rounds this address up to the next multiple of `align`
+    /// # Arguments
+    /// * `align` - The alignment to round up to, `0` and `1` mean no alignment
+    /// # Returns
+    /// `None` if the result does not fit into a `u64`
+    pub fn align_up(&self, align: u64) -> Option<Address> {
+        align_up(self.0, align).map(Self)
+    }
+
+    /// Returns whether this address can be stored in a file of `class`
+    /// # Arguments
+    /// * `class` - The ELF class to check against
+    pub fn fits_in(&self, class: Class) -> bool {
+        match class {
+            Class::ELF32 => self.0 <= u32::MAX as u64,
+            Class::ELF64 => true,
+        }
+    }
+
+    /// Returns a formatter printing this address with as many
+    /// hex digits as the addresses of `class` have (`8` or `16`)
+    /// # Arguments
+    /// * `class` - The ELF class to format for
+    pub fn display(&self, class: Class) -> AddressDisplay {
+        AddressDisplay {
+            address: *self,
+            class,
+        }
+    }
+}
+
+impl From<u64> for Address {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Address> for u64 {
+    fn from(value: Address) -> Self {
+        value.0
+    }
+}
+
+impl Display for Address {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+/// Formats an [Address] zero padded to the address width of a class
+pub struct AddressDisplay {
+    address: Address,
+    class: Class,
+}
+
+impl Display for AddressDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.class {
+            Class::ELF32 => write!(f, "0x{:08x}", self.address.0),
+            Class::ELF64 => write!(f, "0x{:016x}", self.address.0),
+        }
+    }
+}
+
+impl PackableClass for Address {
+    fn pack_class<W: io::Write + io::Seek>(
+        self,
+        w: &mut W,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<(), io::Error> {
+        if !self.fits_in(class) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("address {} does not fit into an ELF32 file", self),
+            ));
+        }
+
+        self.0.pack_class(w, big_endian, class)
+    }
+}
+
+impl UnpackableClass for Address {
+    fn unpack_class<R: io::Read + io::Seek>(
+        r: &mut R,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<Self, UnpackError> {
+        Ok(Self(u64::unpack_class(r, big_endian, class)?))
+    }
+}
@@ -0,0 +1,32 @@
+/// Rounds `value` up to the next multiple of `align`
+///
+/// An alignment of `0` or `1` means no alignment, as in the ELF headers
+/// # Arguments
+/// * `value` - The value to align
+/// * `align` - The alignment to round up to
+/// # Returns
+/// `None` if the aligned value does not fit into a `u64`
+pub fn align_up(value: u64, align: u64) -> Option<u64> {
+    if align <= 1 {
+        return Some(value);
+    }
+
+    match value % align {
+        0 => Some(value),
+        rem => value.checked_add(align - rem),
+    }
+}
+
+/// Rounds `value` down to the previous multiple of `align`
+///
+/// An alignment of `0` or `1` means no alignment, as in the ELF headers
+/// # Arguments
+/// * `value` - The value to align
+/// * `align` - The alignment to round down to
+pub fn align_down(value: u64, align: u64) -> u64 {
+    if align <= 1 {
+        return value;
+    }
+
+    value - value % align
+}
@@ -1,31 +1,284 @@
-use std::{fmt::Debug, io};
+#[cfg(feature = "serde")]
+use alloc::{format, string::String};
+use alloc::{vec, vec::Vec};
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+use crate::io;
 
 /// Just a binary blob
+#[derive(Clone, PartialEq, Eq, Default)]
 pub struct Blob {
     /// The contained data
     pub blob: Vec<u8>,
 }
 
 impl Debug for Blob {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Blob of {} bytes", self.blob.len())
     }
 }
 
 impl Blob {
+    /// Creates a blob containing `data`
+    /// # Arguments
+    /// * `data` - The data to contain
+    pub fn new(data: Vec<u8>) -> Self {
+        Self { blob: data }
+    }
+
+    /// Returns the amount of bytes in this blob
+    pub fn len(&self) -> usize {
+        self.blob.len()
+    }
+
+    /// Returns whether this blob contains no bytes
+    pub fn is_empty(&self) -> bool {
+        self.blob.is_empty()
+    }
+
+    /// Returns a cursor over the contents of this blob for use with [crate::Unpackable]
+    pub fn as_cursor(&self) -> io::Cursor<&[u8]> {
+        io::Cursor::new(&self.blob)
+    }
+
+    /// Turns this blob into a cursor over its contents
+    pub fn into_cursor(self) -> io::Cursor<Vec<u8>> {
+        io::Cursor::new(self.blob)
+    }
+
+    /// Reads a byte at `offset`
+    /// # Arguments
+    /// * `offset` - The offset into the blob
+    /// # Returns
+    /// `None` if `offset` is out of bounds
+    pub fn read_u8(&self, offset: usize) -> Option<u8> {
+        self.blob.get(offset).copied()
+    }
+
+    /// Reads a `u16` at `offset`
+    /// # Arguments
+    /// * `offset` - The offset into the blob
+    /// * `big_endian` - Whether the value is stored big endian
+    /// # Returns
+    /// `None` if the value exceeds the blob
+    pub fn read_u16(&self, offset: usize, big_endian: bool) -> Option<u16> {
+        let bytes = self.read_array(offset)?;
+
+        Some(match big_endian {
+            true => u16::from_be_bytes(bytes),
+            false => u16::from_le_bytes(bytes),
+        })
+    }
+
+    /// Reads a `u32` at `offset`
+    /// # Arguments
+    /// * `offset` - The offset into the blob
+    /// * `big_endian` - Whether the value is stored big endian
+    /// # Returns
+    /// `None` if the value exceeds the blob
+    pub fn read_u32(&self, offset: usize, big_endian: bool) -> Option<u32> {
+        let bytes = self.read_array(offset)?;
+
+        Some(match big_endian {
+            true => u32::from_be_bytes(bytes),
+            false => u32::from_le_bytes(bytes),
+        })
+    }
+
+    /// Reads a `u64` at `offset`
+    /// # Arguments
+    /// * `offset` - The offset into the blob
+    /// * `big_endian` - Whether the value is stored big endian
+    /// # Returns
+    /// `None` if the value exceeds the blob
+    pub fn read_u64(&self, offset: usize, big_endian: bool) -> Option<u64> {
+        let bytes = self.read_array(offset)?;
+
+        Some(match big_endian {
+            true => u64::from_be_bytes(bytes),
+            false => u64::from_le_bytes(bytes),
+        })
+    }
+
+    /// Overwrites the byte at `offset`
+    /// # Arguments
+    /// * `offset` - The offset into the blob
+    /// * `value` - The value to write
+    /// # Returns
+    /// `None` if `offset` is out of bounds
+    pub fn write_u8(&mut self, offset: usize, value: u8) -> Option<()> {
+        *self.blob.get_mut(offset)? = value;
+        Some(())
+    }
+
+    /// Overwrites the `u16` at `offset`
+    /// # Arguments
+    /// * `offset` - The offset into the blob
+    /// * `value` - The value to write
+    /// * `big_endian` - Whether to store the value big endian
+    /// # Returns
+    /// `None` if the value exceeds the blob, leaving it untouched
+    pub fn write_u16(&mut self, offset: usize, value: u16, big_endian: bool) -> Option<()> {
+        match big_endian {
+            true => self.write_bytes(offset, &value.to_be_bytes()),
+            false => self.write_bytes(offset, &value.to_le_bytes()),
+        }
+    }
+
+    /// Overwrites the `u32` at `offset`
+    /// # Arguments
+    /// * `offset` - The offset into the blob
+    /// * `value` - The value to write
+    /// * `big_endian` - Whether to store the value big endian
+    /// # Returns
+    /// `None` if the value exceeds the blob, leaving it untouched
+    pub fn write_u32(&mut self, offset: usize, value: u32, big_endian: bool) -> Option<()> {
+        match big_endian {
+            true => self.write_bytes(offset, &value.to_be_bytes()),
+            false => self.write_bytes(offset, &value.to_le_bytes()),
+        }
+    }
+
+    /// Overwrites the `u64` at `offset`
+    /// # Arguments
+    /// * `offset` - The offset into the blob
+    /// * `value` - The value to write
+    /// * `big_endian` - Whether to store the value big endian
+    /// # Returns
+    /// `None` if the value exceeds the blob, leaving it untouched
+    pub fn write_u64(&mut self, offset: usize, value: u64, big_endian: bool) -> Option<()> {
+        match big_endian {
+            true => self.write_bytes(offset, &value.to_be_bytes()),
+            false => self.write_bytes(offset, &value.to_le_bytes()),
+        }
+    }
+
+    /// Reads `N` bytes at `offset`
+    fn read_array<const N: usize>(&self, offset: usize) -> Option<[u8; N]> {
+        self.blob
+            .get(offset..offset.checked_add(N)?)?
+            .try_into()
+            .ok()
+    }
+
+    /// Overwrites the bytes at `offset` with `data`
+    fn write_bytes(&mut self, offset: usize, data: &[u8]) -> Option<()> {
+        self.blob
+            .get_mut(offset..offset.checked_add(data.len())?)?
+            .copy_from_slice(data);
+        Some(())
+    }
+
+    /// Finds the first occurrence of `pattern` in this blob
+    /// # Arguments
+    /// * `pattern` - The bytes to search for
+    /// # Returns
+    /// The offset of the occurrence, `None` if there is none or `pattern` is empty
+    pub fn find_bytes(&self, pattern: &[u8]) -> Option<usize> {
+        self.find_bytes_from(pattern, 0)
+    }
+
+    /// Finds all occurrences of `pattern` in this blob, including overlapping ones
+    /// # Arguments
+    /// * `pattern` - The bytes to search for
+    /// # Returns
+    /// The offsets of the occurrences in ascending order
+    pub fn find_all_bytes(&self, pattern: &[u8]) -> Vec<usize> {
+        let mut res = Vec::new();
+        let mut start = 0;
+
+        while let Some(offset) = self.find_bytes_from(pattern, start) {
+            res.push(offset);
+            start = offset + 1;
+        }
+
+        res
+    }
+
+    /// Replaces the first occurrence of `pattern` with `replacement`
+    /// # Arguments
+    /// * `pattern` - The bytes to search for
+    /// * `replacement` - The bytes to replace `pattern` with, of the same length
+    /// # Returns
+    /// `false` if the lengths differ or `pattern` was not found
+    pub fn replace_first(&mut self, pattern: &[u8], replacement: &[u8]) -> bool {
+        if pattern.len() != replacement.len() {
+            return false;
+        }
+
+        match self.find_bytes(pattern) {
+            Some(offset) => {
+                self.blob[offset..offset + pattern.len()].copy_from_slice(replacement);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replaces all non-overlapping occurrences of `pattern` with `replacement`,
+    /// scanning from the start of the blob
+    /// # Arguments
+    /// * `pattern` - The bytes to search for
+    /// * `replacement` - The bytes to replace `pattern` with, of the same length
+    /// # Returns
+    /// The amount of replacements, `0` if the lengths differ
+    pub fn replace_all(&mut self, pattern: &[u8], replacement: &[u8]) -> usize {
+        if pattern.len() != replacement.len() {
+            return 0;
+        }
+
+        let mut count = 0;
+        let mut start = 0;
+
+        while let Some(offset) = self.find_bytes_from(pattern, start) {
+            self.blob[offset..offset + pattern.len()].copy_from_slice(replacement);
+            start = offset + pattern.len();
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Finds the first occurrence of `pattern` at or after `start`
+    fn find_bytes_from(&self, pattern: &[u8], start: usize) -> Option<usize> {
+        if pattern.is_empty() || start > self.blob.len() {
+            return None;
+        }
+
+        self.blob[start..]
+            .windows(pattern.len())
+            .position(|w| w == pattern)
+            .map(|offset| start + offset)
+    }
+
     /// Loads a blob from `r`
     /// # Arguments
     /// * `r` - The stream to read from
     /// * `offset` - The offset where to read from the stream
     /// * `size` - The amount of bytes to read from the stream
+    /// # Returns
+    /// An error of kind [io::ErrorKind::UnexpectedEof] if the data exceeds the
+    /// stream, which is checked before allocating memory for the data
     pub fn load<R: io::Read + io::Seek>(
         r: &mut R,
         offset: u64,
         size: usize,
     ) -> Result<Self, io::Error> {
-        let mut res = vec![0u8; size];
-
         let old_pos = r.stream_position()?;
+        let len = r.seek(io::SeekFrom::End(0))?;
+
+        if offset.checked_add(size as u64).is_none_or(|end| end > len) {
+            r.seek(io::SeekFrom::Start(old_pos))?;
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "data exceeds the end of the stream",
+            ));
+        }
+
+        let mut res = vec![0u8; size];
         r.seek(io::SeekFrom::Start(offset))?;
         r.read_exact(&mut res)?;
         r.seek(io::SeekFrom::Start(old_pos))?;
@@ -46,3 +299,84 @@ impl Blob {
         Ok(())
     }
 }
+
+impl Deref for Blob {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.blob
+    }
+}
+
+impl DerefMut for Blob {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.blob
+    }
+}
+
+impl AsRef<[u8]> for Blob {
+    fn as_ref(&self) -> &[u8] {
+        &self.blob
+    }
+}
+
+impl AsMut<[u8]> for Blob {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.blob
+    }
+}
+
+impl From<Vec<u8>> for Blob {
+    fn from(value: Vec<u8>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&[u8]> for Blob {
+    fn from(value: &[u8]) -> Self {
+        Self::new(value.to_vec())
+    }
+}
+
+impl From<Blob> for Vec<u8> {
+    fn from(value: Blob) -> Self {
+        value.blob
+    }
+}
+
+/// Serializes the contents as a lowercase hex string
+#[cfg(feature = "serde")]
+impl serde::Serialize for Blob {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut hex = String::with_capacity(self.blob.len() * 2);
+        for byte in &self.blob {
+            hex.push_str(&format!("{:02x}", byte));
+        }
+
+        serializer.serialize_str(&hex)
+    }
+}
+
+/// Deserializes the contents from a hex string
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Blob {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let hex = String::deserialize(deserializer)?;
+        if hex.len() % 2 != 0 {
+            return Err(D::Error::custom("hex string has an odd length"));
+        }
+
+        let blob = (0..hex.len())
+            .step_by(2)
+            .map(|i| {
+                hex.get(i..i + 2)
+                    .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                    .ok_or_else(|| D::Error::custom("invalid hex string"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { blob })
+    }
+}
@@ -0,0 +1,231 @@
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use crate::{
+    align_up, Blob, Class, ELFType, Endianness, Header, Machine, Packable, PackableClass,
+    ProgramHeader, ProgramHeaderType, SectionFlags, SectionHeader, SectionType,
+};
+
+/// A section queued in an [ELFBuilder]
+#[derive(Debug, Clone)]
+struct BuilderSection {
+    name: String,
+    header: SectionHeader,
+    data: Vec<u8>,
+}
+
+/// Constructs ELF files from scratch
+///
+/// The builder lays out the file in the following order:
+/// ELF header, program headers, section data, `.shstrtab`, section headers
+#[derive(Debug, Clone)]
+pub struct ELFBuilder {
+    class: Class,
+    endianness: Endianness,
+    machine: Machine,
+    ty: ELFType,
+    entry_point: u64,
+    sections: Vec<BuilderSection>,
+    program_headers: Vec<ProgramHeader>,
+}
+
+impl ELFBuilder {
+    /// Creates a builder for an empty relocatable file
+    /// # Arguments
+    /// * `class` - The ELF class of the file
+    /// * `endianness` - The endianness of the file
+    /// * `machine` - The machine the file targets
+    pub fn new(class: Class, endianness: Endianness, machine: Machine) -> ELFBuilder {
+        Self {
+            class,
+            endianness,
+            machine,
+            ty: ELFType::Relocatable,
+            entry_point: 0,
+            sections: Vec::new(),
+            program_headers: Vec::new(),
+        }
+    }
+
+    /// Sets the entry point of the file
+    /// # Arguments
+    /// * `addr` - The virtual address of the entry point
+    pub fn set_entry_point(&mut self, addr: u64) -> &mut Self {
+        self.entry_point = addr;
+        self
+    }
+
+    /// Sets the type of the file
+    /// # Arguments
+    /// * `ty` - The type of ELF file
+    pub fn set_type(&mut self, ty: ELFType) -> &mut Self {
+        self.ty = ty;
+        self
+    }
+
+    /// Adds a section to the file
+    ///
+    /// The sections get the indices `1..` in the order they are added, the
+    /// null section at index `0` and `.shstrtab` after the last one are added
+    /// automatically. The `name`, `offset` and `size` of `header` are
+    /// computed, except for the size of `SHT_NOBITS` sections
+    /// # Arguments
+    /// * `name` - The name of the section
+    /// * `header` - The section header
+    /// * `data` - The contents of the section
+    pub fn add_section(&mut self, name: &str, header: SectionHeader, data: Vec<u8>) -> &mut Self {
+        self.sections.push(BuilderSection {
+            name: name.to_owned(),
+            header,
+            data,
+        });
+        self
+    }
+
+    /// Adds a program header to the file
+    ///
+    /// Program headers are written as provided, only a `PT_PHDR`
+    /// segment gets its offset and size adjusted to the program header table
+    /// # Arguments
+    /// * `header` - The program header
+    pub fn add_program_header(&mut self, header: ProgramHeader) -> &mut Self {
+        self.program_headers.push(header);
+        self
+    }
+
+    /// Serializes the ELF file to `w`
+    /// # Arguments
+    /// * `w` - The stream to write to, starting at offset `0`
+    pub fn build<W: Write + Seek>(&self, w: &mut W) -> Result<(), io::Error> {
+        let big_endian = self.endianness == Endianness::Big;
+
+        let mut header = Header::new(self.class, self.endianness, self.ty, self.machine);
+        header.entry_point = self.entry_point;
+
+        let mut shstrtab = vec![0u8];
+        let mut name_offsets = Vec::new();
+        for section in &self.sections {
+            name_offsets.push(shstrtab.len() as u32);
+            shstrtab.extend_from_slice(section.name.as_bytes());
+            shstrtab.push(0);
+        }
+        let shstrtab_name = shstrtab.len() as u32;
+        shstrtab.extend_from_slice(b".shstrtab\0");
+
+        let ph_offset = header.get_header_size();
+        let mut offset =
+            ph_offset + header.get_program_header_size() * self.program_headers.len() as u64;
+
+        let mut sections = vec![null_section()];
+        for (section, name) in self.sections.iter().zip(name_offsets) {
+            let mut sh = section.header.clone();
+            sh.name = name;
+            sh.offset = checked_align_up(offset, sh.addr_align)?;
+
+            if sh.ty != SectionType::NoBits {
+                sh.size = section.data.len() as u64;
+                offset = sh.offset + sh.size;
+            }
+
+            sh.data = Blob {
+                blob: section.data.clone(),
+            };
+            sections.push(sh);
+        }
+
+        sections.push(SectionHeader {
+            name: shstrtab_name,
+            ty: SectionType::StringTable,
+            flags: SectionFlags::default(),
+            address: 0,
+            offset,
+            size: shstrtab.len() as u64,
+            link: 0,
+            info: 0,
+            addr_align: 1,
+            entry_size: 0,
+            data: Blob { blob: shstrtab },
+        });
+        offset += sections[sections.len() - 1].size;
+
+        let word_size = match self.class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        let sh_offset = checked_align_up(offset, word_size)?;
+
+        if !self.program_headers.is_empty() {
+            sections[0].info =
+                header.set_program_header_table(ph_offset, self.program_headers.len());
+        }
+        let (null_size, null_link) =
+            header.set_section_header_table(sh_offset, sections.len(), sections.len() - 1);
+        sections[0].size = null_size;
+        sections[0].link = null_link;
+
+        w.seek(SeekFrom::Start(0))?;
+        header.pack(w, big_endian)?;
+
+        for ph in &self.program_headers {
+            let mut ph = ph.clone();
+
+            if ph.ty == ProgramHeaderType::ProgramHeaderTable {
+                ph.offset = ph_offset;
+                ph.file_size = header.get_program_header_size() * self.program_headers.len() as u64;
+                ph.mem_size = ph.file_size;
+            }
+
+            ph.pack_class(w, big_endian, self.class)?;
+        }
+
+        let mut pos = w.stream_position()?;
+        for section in &sections {
+            if section.ty == SectionType::NoBits || section.data.blob.is_empty() {
+                continue;
+            }
+
+            pad_to(w, &mut pos, section.offset)?;
+            w.write_all(&section.data.blob)?;
+            pos += section.data.blob.len() as u64;
+        }
+        pad_to(w, &mut pos, sh_offset)?;
+
+        for section in sections {
+            section.pack_class(w, big_endian, self.class)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the mandatory null section at index `0`
+fn null_section() -> SectionHeader {
+    SectionHeader {
+        name: 0,
+        ty: SectionType::Null,
+        flags: SectionFlags::default(),
+        address: 0,
+        offset: 0,
+        size: 0,
+        link: 0,
+        info: 0,
+        addr_align: 0,
+        entry_size: 0,
+        data: Blob { blob: Vec::new() },
+    }
+}
+
+/// Aligns `offset` to `align`, failing if the file would grow too large
+fn checked_align_up(offset: u64, align: u64) -> Result<u64, io::Error> {
+    align_up(offset, align)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "file offset overflows"))
+}
+
+/// Writes zeros to `w` until `pos` reaches `target`
+fn pad_to<W: Write>(w: &mut W, pos: &mut u64, target: u64) -> Result<(), io::Error> {
+    if target > *pos {
+        io::copy(&mut io::repeat(0).take(target - *pos), w)?;
+        *pos = target;
+    }
+
+    Ok(())
+}
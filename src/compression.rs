@@ -0,0 +1,343 @@
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+#[cfg(feature = "std")]
+use crate::ELFFile;
+use crate::{
+    io::{self, Read},
+    Class, Packable, PackableClass, SectionHeader, UnpackError, Unpackable, UnpackableClass,
+};
+
+/// The compression algorithm of a `SHF_COMPRESSED` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum CompressionType {
+    /// `ELFCOMPRESS_ZLIB`: The data is a zlib stream
+    Zlib = 1,
+    /// `ELFCOMPRESS_ZSTD`: The data is a zstd stream
+    Zstd = 2,
+    /// Any other unknown compression algorithm
+    Other(u32),
+}
+
+impl CompressionType {
+    /// Returns the raw value of this compression type
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::Zlib => 1,
+            Self::Zstd => 2,
+            Self::Other(ty) => *ty,
+        }
+    }
+}
+
+impl From<u32> for CompressionType {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Zlib,
+            2 => Self::Zstd,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// The header prefixed to the data of `SHF_COMPRESSED` sections (`Elf32_Chdr`, `Elf64_Chdr`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionHeader {
+    /// The compression algorithm
+    pub ty: CompressionType,
+    /// The size of the uncompressed data
+    pub size: u64,
+    /// The alignment of the uncompressed data
+    pub addr_align: u64,
+}
+
+/// An error while decompressing the data of a section
+#[derive(Debug)]
+pub enum DecompressError {
+    /// The data is compressed with an unknown algorithm
+    UnsupportedCompression {
+        /// The raw compression type
+        ty: u32,
+    },
+    /// The size of the decompressed data does not match the size recorded for it
+    SizeMismatch {
+        /// The recorded size
+        expected: u64,
+        /// The size of the decompressed data
+        got: u64,
+    },
+    /// Decompressing the data requires a cargo feature that is not enabled
+    MissingFeature {
+        /// The name of the feature
+        feature: &'static str,
+    },
+    /// The section or its compression header could not be unpacked
+    Unpack(UnpackError),
+    /// The compressed data is corrupt
+    IO(io::Error),
+}
+
+impl From<UnpackError> for DecompressError {
+    fn from(value: UnpackError) -> Self {
+        Self::Unpack(value)
+    }
+}
+
+impl From<io::Error> for DecompressError {
+    fn from(value: io::Error) -> Self {
+        Self::IO(value)
+    }
+}
+
+impl Display for DecompressError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedCompression { ty } => {
+                write!(f, "unsupported compression type {:#x}", ty)
+            }
+            Self::SizeMismatch { expected, got } => write!(
+                f,
+                "decompressed size mismatch: expected {:#x} bytes, got {:#x}",
+                expected, got
+            ),
+            Self::MissingFeature { feature } => {
+                write!(f, "the '{}' feature is required but not enabled", feature)
+            }
+            Self::Unpack(e) => write!(f, "{}", e),
+            Self::IO(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl core::error::Error for DecompressError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Unpack(e) => Some(e),
+            Self::IO(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl CompressionHeader {
+    /// Returns the size of the header in the file
+    /// # Arguments
+    /// * `class` - The class of the file
+    pub fn packed_size(class: Class) -> usize {
+        match class {
+            Class::ELF32 => 12,
+            Class::ELF64 => 24,
+        }
+    }
+}
+
+impl PackableClass for CompressionHeader {
+    fn pack_class<W: io::Write + io::Seek>(
+        self,
+        w: &mut W,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<(), io::Error> {
+        self.ty.value().pack(w, big_endian)?;
+
+        if class == Class::ELF64 {
+            0u32.pack(w, big_endian)?;
+        }
+
+        self.size.pack_class(w, big_endian, class)?;
+        self.addr_align.pack_class(w, big_endian, class)?;
+
+        Ok(())
+    }
+}
+
+impl UnpackableClass for CompressionHeader {
+    fn unpack_class<R: io::Read + io::Seek>(
+        r: &mut R,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<Self, UnpackError> {
+        let ty = CompressionType::from(u32::unpack(r, big_endian)?);
+
+        if class == Class::ELF64 {
+            let _reserved = u32::unpack(r, big_endian)?;
+        }
+
+        Ok(Self {
+            ty,
+            size: u64::unpack_class(r, big_endian, class)?,
+            addr_align: u64::unpack_class(r, big_endian, class)?,
+        })
+    }
+}
+
+impl SectionHeader {
+    /// Returns the compression header of a `SHF_COMPRESSED` section
+    /// # Arguments
+    /// * `class` - The class of the file
+    /// * `big_endian` - Whether the file is big endian
+    /// # Returns
+    /// `None` if the section is not compressed
+    pub fn compression_header(
+        &self,
+        class: Class,
+        big_endian: bool,
+    ) -> Result<Option<CompressionHeader>, UnpackError> {
+        if !self.flags.is_compressed() {
+            return Ok(None);
+        }
+
+        let header =
+            CompressionHeader::unpack_class(&mut self.data.as_cursor(), big_endian, class)?;
+        Ok(Some(header))
+    }
+
+    /// Returns the size of the uncompressed data of a `SHF_COMPRESSED` section
+    /// # Arguments
+    /// * `class` - The class of the file
+    /// * `big_endian` - Whether the file is big endian
+    /// # Returns
+    /// `None` if the section is not compressed or its compression header is invalid
+    pub fn decompressed_size(&self, class: Class, big_endian: bool) -> Option<u64> {
+        Some(self.compression_header(class, big_endian).ok()??.size)
+    }
+
+    /// Returns the uncompressed data of this section
+    ///
+    /// The data of `SHF_COMPRESSED` sections is decompressed, the
+    /// data of all other sections is returned unchanged
+    /// # Arguments
+    /// * `class` - The class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn decompress_data(
+        &self,
+        class: Class,
+        big_endian: bool,
+    ) -> Result<Vec<u8>, DecompressError> {
+        let Some(header) = self.compression_header(class, big_endian)? else {
+            return Ok(self.data.to_vec());
+        };
+
+        let data = &self.data[CompressionHeader::packed_size(class)..];
+        let res = match header.ty {
+            CompressionType::Zlib => inflate(data, header.size)?,
+            CompressionType::Zstd => decompress_zstd(data, header.size)?,
+            ty => return Err(DecompressError::UnsupportedCompression { ty: ty.value() }),
+        };
+
+        check_size(res, header.size)
+    }
+
+    /// Returns the uncompressed data of this section
+    /// # Arguments
+    /// * `class` - The class of the file
+    /// * `big_endian` - Whether the file is big endian
+    #[deprecated(note = "use decompress_data")]
+    pub fn decompressed_data(
+        &self,
+        class: Class,
+        big_endian: bool,
+    ) -> Result<Vec<u8>, DecompressError> {
+        self.decompress_data(class, big_endian)
+    }
+}
+
+/// The magic prefixed to the data of legacy `.zdebug_*` sections
+const ZDEBUG_MAGIC: &[u8; 4] = b"ZLIB";
+
+/// Decompresses the data of a legacy `.zdebug_*` section
+///
+/// The data starts with the magic `ZLIB` and the big endian `64` bit
+/// size of the uncompressed data, followed by a zlib stream
+/// # Arguments
+/// * `data` - The data of the section
+pub fn decompress_zdebug(data: &[u8]) -> Result<Vec<u8>, DecompressError> {
+    let mut r = io::Cursor::new(data);
+
+    let mut magic = [0u8; 4];
+    r.read_exact(&mut magic)?;
+    if &magic != ZDEBUG_MAGIC {
+        return Err(UnpackError::InvalidMagic {
+            expected: ZDEBUG_MAGIC.to_vec(),
+            got: magic.to_vec(),
+        }
+        .into());
+    }
+
+    let size = u64::unpack(&mut r, true)?;
+    check_size(inflate(&data[12..], size)?, size)
+}
+
+#[cfg(feature = "std")]
+impl ELFFile {
+    /// Returns the uncompressed data of the section at `index`
+    ///
+    /// This decompresses `SHF_COMPRESSED` sections as well as the legacy
+    /// `.zdebug_*` sections, the data of all other sections is returned unchanged
+    /// # Arguments
+    /// * `index` - The index of the section
+    pub fn decompressed_section_data(&self, index: usize) -> Result<Vec<u8>, DecompressError> {
+        let section = self
+            .section_headers
+            .get(index)
+            .ok_or(UnpackError::InvalidSectionIndex { index })?;
+
+        if !section.flags.is_compressed()
+            && section.data.starts_with(ZDEBUG_MAGIC)
+            && self.section_name(index)?.starts_with(".zdebug")
+        {
+            return decompress_zdebug(&section.data);
+        }
+
+        section.decompress_data(self.header.ident.class, self.header.ident.is_big_endian())
+    }
+}
+
+/// Checks that the size of the uncompressed data matches the recorded size
+fn check_size(data: Vec<u8>, size: u64) -> Result<Vec<u8>, DecompressError> {
+    if data.len() as u64 != size {
+        return Err(DecompressError::SizeMismatch {
+            expected: size,
+            got: data.len() as u64,
+        });
+    }
+
+    Ok(data)
+}
+
+/// Inflates the zlib stream `data`
+#[cfg(feature = "flate2")]
+fn inflate(data: &[u8], size: u64) -> Result<Vec<u8>, DecompressError> {
+    let mut res = Vec::with_capacity(size.min(data.len() as u64 * 64) as usize);
+    // Reading one byte past the size detects streams longer than recorded
+    flate2::read::ZlibDecoder::new(data)
+        .take(size.saturating_add(1))
+        .read_to_end(&mut res)?;
+
+    Ok(res)
+}
+
+/// Inflates the zlib stream `data`
+#[cfg(not(feature = "flate2"))]
+fn inflate(_data: &[u8], _size: u64) -> Result<Vec<u8>, DecompressError> {
+    Err(DecompressError::MissingFeature { feature: "flate2" })
+}
+
+/// Decompresses the zstd stream `data`
+#[cfg(feature = "zstd")]
+fn decompress_zstd(data: &[u8], size: u64) -> Result<Vec<u8>, DecompressError> {
+    let mut res = Vec::with_capacity(size.min(data.len() as u64 * 64) as usize);
+    // Reading one byte past the size detects streams longer than recorded
+    zstd::stream::read::Decoder::new(data)?
+        .take(size.saturating_add(1))
+        .read_to_end(&mut res)?;
+
+    Ok(res)
+}
+
+/// Decompresses the zstd stream `data`
+#[cfg(not(feature = "zstd"))]
+fn decompress_zstd(_data: &[u8], _size: u64) -> Result<Vec<u8>, DecompressError> {
+    Err(DecompressError::MissingFeature { feature: "zstd" })
+}
@@ -0,0 +1,610 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use crate::{
+    io::{Cursor, Read, Seek, SeekFrom},
+    Class, Machine, UnpackError, Unpackable, UnpackableClass,
+};
+#[cfg(feature = "std")]
+use crate::{ELFFile, ELFType};
+
+/// `NT_PRSTATUS`: The status of a thread
+#[cfg(feature = "std")]
+const NT_PRSTATUS: u32 = 1;
+/// `NT_PRPSINFO`: Information about the process
+#[cfg(feature = "std")]
+const NT_PRPSINFO: u32 = 3;
+/// `NT_AUXV`: The auxiliary vector of the process
+#[cfg(feature = "std")]
+const NT_AUXV: u32 = 6;
+/// `NT_FILE`: The files mapped into the process
+#[cfg(feature = "std")]
+const NT_FILE: u32 = 0x46494c45;
+
+/// A time value of a core dump (`struct timeval`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimeVal {
+    /// The seconds
+    pub seconds: u64,
+    /// The microseconds
+    pub microseconds: u64,
+}
+
+impl TimeVal {
+    /// Unpacks a time value of two class-sized words
+    fn unpack<R: Read + Seek>(
+        r: &mut R,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<Self, UnpackError> {
+        Ok(Self {
+            seconds: u64::unpack_class(r, big_endian, class)?,
+            microseconds: u64::unpack_class(r, big_endian, class)?,
+        })
+    }
+}
+
+/// The status of a thread from a `NT_PRSTATUS` note of a core dump (`struct elf_prstatus`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrStatus {
+    /// The number of the signal that caused the dump
+    pub signal: i32,
+    /// The code of the signal
+    pub signal_code: i32,
+    /// The errno value associated with the signal
+    pub signal_errno: i32,
+    /// The signal pending for the thread
+    pub current_signal: u16,
+    /// The set of pending signals
+    pub pending_signals: u64,
+    /// The set of held signals
+    pub held_signals: u64,
+    /// The id of the thread
+    pub pid: i32,
+    /// The id of the parent process
+    pub ppid: i32,
+    /// The id of the process group
+    pub pgrp: i32,
+    /// The id of the session
+    pub sid: i32,
+    /// The user time of the thread
+    pub user_time: TimeVal,
+    /// The system time of the thread
+    pub system_time: TimeVal,
+    /// The cumulative user time of the children
+    pub children_user_time: TimeVal,
+    /// The cumulative system time of the children
+    pub children_system_time: TimeVal,
+    /// The general purpose registers, laid out depending on the machine
+    pub registers: Vec<u8>,
+    /// The machine of the core dump, which decides the layout of the registers
+    pub machine: Machine,
+    /// Whether the floating point registers are contained in the dump
+    pub fp_valid: bool,
+}
+
+impl PrStatus {
+    /// Parses the descriptor of a `NT_PRSTATUS` note
+    ///
+    /// The size of the register area depends on the machine,
+    /// it is taken from the size of the descriptor
+    /// # Arguments
+    /// * `desc` - The descriptor of the note
+    /// * `big_endian` - Whether the file is big endian
+    /// * `class` - The class of the file
+    /// * `machine` - The machine of the file
+    pub fn from_desc(
+        desc: &[u8],
+        big_endian: bool,
+        class: Class,
+        machine: Machine,
+    ) -> Result<Self, UnpackError> {
+        let mut r = Cursor::new(desc);
+
+        let signal = i32::unpack(&mut r, big_endian)?;
+        let signal_code = i32::unpack(&mut r, big_endian)?;
+        let signal_errno = i32::unpack(&mut r, big_endian)?;
+        let current_signal = u16::unpack(&mut r, big_endian)?;
+
+        r.seek(SeekFrom::Start(16))?;
+        let pending_signals = u64::unpack_class(&mut r, big_endian, class)?;
+        let held_signals = u64::unpack_class(&mut r, big_endian, class)?;
+
+        let pid = i32::unpack(&mut r, big_endian)?;
+        let ppid = i32::unpack(&mut r, big_endian)?;
+        let pgrp = i32::unpack(&mut r, big_endian)?;
+        let sid = i32::unpack(&mut r, big_endian)?;
+
+        let user_time = TimeVal::unpack(&mut r, big_endian, class)?;
+        let system_time = TimeVal::unpack(&mut r, big_endian, class)?;
+        let children_user_time = TimeVal::unpack(&mut r, big_endian, class)?;
+        let children_system_time = TimeVal::unpack(&mut r, big_endian, class)?;
+
+        // The registers are followed by `pr_fpvalid`, padded to the word size
+        let registers_start = r.position() as usize;
+        let registers_end = desc.len().saturating_sub(match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        });
+        let registers = desc
+            .get(registers_start..registers_end)
+            .unwrap_or_default()
+            .to_vec();
+
+        r.seek(SeekFrom::Start(registers_end as u64))?;
+        let fp_valid = i32::unpack(&mut r, big_endian)? != 0;
+
+        Ok(Self {
+            signal,
+            signal_code,
+            signal_errno,
+            current_signal,
+            pending_signals,
+            held_signals,
+            pid,
+            ppid,
+            pgrp,
+            sid,
+            user_time,
+            system_time,
+            children_user_time,
+            children_system_time,
+            registers,
+            machine,
+            fp_valid,
+        })
+    }
+
+    /// Returns the registers of an x86_64 thread
+    /// # Returns
+    /// `None` if the machine is not x86_64 or the register area is too small
+    pub fn registers_x86_64(&self) -> Option<UserRegsX86_64> {
+        if self.machine != Machine::X86_64 {
+            return None;
+        }
+
+        let mut r = Cursor::new(&self.registers);
+        let mut next = || u64::unpack(&mut r, false).ok();
+
+        Some(UserRegsX86_64 {
+            r15: next()?,
+            r14: next()?,
+            r13: next()?,
+            r12: next()?,
+            rbp: next()?,
+            rbx: next()?,
+            r11: next()?,
+            r10: next()?,
+            r9: next()?,
+            r8: next()?,
+            rax: next()?,
+            rcx: next()?,
+            rdx: next()?,
+            rsi: next()?,
+            rdi: next()?,
+            orig_rax: next()?,
+            rip: next()?,
+            cs: next()?,
+            eflags: next()?,
+            rsp: next()?,
+            ss: next()?,
+            fs_base: next()?,
+            gs_base: next()?,
+            ds: next()?,
+            es: next()?,
+            fs: next()?,
+            gs: next()?,
+        })
+    }
+}
+
+/// The general purpose registers of an x86_64 thread (`struct user_regs_struct`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UserRegsX86_64 {
+    /// The `r15` register
+    pub r15: u64,
+    /// The `r14` register
+    pub r14: u64,
+    /// The `r13` register
+    pub r13: u64,
+    /// The `r12` register
+    pub r12: u64,
+    /// The `rbp` register
+    pub rbp: u64,
+    /// The `rbx` register
+    pub rbx: u64,
+    /// The `r11` register
+    pub r11: u64,
+    /// The `r10` register
+    pub r10: u64,
+    /// The `r9` register
+    pub r9: u64,
+    /// The `r8` register
+    pub r8: u64,
+    /// The `rax` register
+    pub rax: u64,
+    /// The `rcx` register
+    pub rcx: u64,
+    /// The `rdx` register
+    pub rdx: u64,
+    /// The `rsi` register
+    pub rsi: u64,
+    /// The `rdi` register
+    pub rdi: u64,
+    /// The system call number the thread was interrupted in
+    pub orig_rax: u64,
+    /// The `rip` register
+    pub rip: u64,
+    /// The `cs` register
+    pub cs: u64,
+    /// The `eflags` register
+    pub eflags: u64,
+    /// The `rsp` register
+    pub rsp: u64,
+    /// The `ss` register
+    pub ss: u64,
+    /// The `fs_base` register
+    pub fs_base: u64,
+    /// The `gs_base` register
+    pub gs_base: u64,
+    /// The `ds` register
+    pub ds: u64,
+    /// The `es` register
+    pub es: u64,
+    /// The `fs` register
+    pub fs: u64,
+    /// The `gs` register
+    pub gs: u64,
+}
+
+/// Information about the process from a `NT_PRPSINFO` note of a core dump (`struct elf_prpsinfo`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrPsInfo {
+    /// The numeric state of the process
+    pub state: u8,
+    /// The state of the process as a character (e.g. `R` for running)
+    pub state_name: char,
+    /// Whether the process is a zombie
+    pub zombie: bool,
+    /// The nice value of the process
+    pub nice: i8,
+    /// The flags of the process
+    pub flags: u64,
+    /// The user id of the process
+    pub uid: u32,
+    /// The group id of the process
+    pub gid: u32,
+    /// The id of the process
+    pub pid: i32,
+    /// The id of the parent process
+    pub ppid: i32,
+    /// The id of the process group
+    pub pgrp: i32,
+    /// The id of the session
+    pub sid: i32,
+    /// The name of the executable, truncated to `16` bytes
+    pub filename: String,
+    /// The command line arguments, truncated to `80` bytes
+    pub args: String,
+}
+
+impl PrPsInfo {
+    /// Parses the descriptor of a `NT_PRPSINFO` note
+    ///
+    /// ELF32 dumps are expected to use `16` bit user and group ids, like i386 and ARM
+    /// # Arguments
+    /// * `desc` - The descriptor of the note
+    /// * `big_endian` - Whether the file is big endian
+    /// * `class` - The class of the file
+    pub fn from_desc(desc: &[u8], big_endian: bool, class: Class) -> Result<Self, UnpackError> {
+        let mut r = Cursor::new(desc);
+
+        let state = u8::unpack(&mut r, big_endian)?;
+        let state_name = u8::unpack(&mut r, big_endian)? as char;
+        let zombie = u8::unpack(&mut r, big_endian)? != 0;
+        let nice = i8::unpack(&mut r, big_endian)?;
+
+        if class == Class::ELF64 {
+            r.seek(SeekFrom::Start(8))?;
+        }
+        let flags = u64::unpack_class(&mut r, big_endian, class)?;
+
+        let (uid, gid) = match class {
+            Class::ELF32 => (
+                u16::unpack(&mut r, big_endian)? as u32,
+                u16::unpack(&mut r, big_endian)? as u32,
+            ),
+            Class::ELF64 => (
+                u32::unpack(&mut r, big_endian)?,
+                u32::unpack(&mut r, big_endian)?,
+            ),
+        };
+
+        let pid = i32::unpack(&mut r, big_endian)?;
+        let ppid = i32::unpack(&mut r, big_endian)?;
+        let pgrp = i32::unpack(&mut r, big_endian)?;
+        let sid = i32::unpack(&mut r, big_endian)?;
+
+        let mut filename = [0u8; 16];
+        r.read_exact(&mut filename)?;
+        let mut args = [0u8; 80];
+        r.read_exact(&mut args)?;
+
+        Ok(Self {
+            state,
+            state_name,
+            zombie,
+            nice,
+            flags,
+            uid,
+            gid,
+            pid,
+            ppid,
+            pgrp,
+            sid,
+            filename: fixed_string(&filename),
+            args: fixed_string(&args),
+        })
+    }
+}
+
+/// A file mapped into the memory of a process
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappedFile {
+    /// The start address of the mapping
+    pub start: u64,
+    /// The end address of the mapping
+    pub end: u64,
+    /// The offset of the mapping in the file, in units of [MappedFiles::page_size]
+    pub page_offset: u64,
+    /// The path of the file
+    pub path: String,
+}
+
+/// The files mapped into a process from a `NT_FILE` note of a core dump
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MappedFiles {
+    /// The unit of [MappedFile::page_offset]
+    pub page_size: u64,
+    /// The mappings in order of their addresses
+    pub files: Vec<MappedFile>,
+}
+
+impl MappedFiles {
+    /// Parses the descriptor of a `NT_FILE` note
+    ///
+    /// The count and page size are followed by a start, end and offset triplet per
+    /// mapping and the null terminated paths. Truncated notes yield the mappings
+    /// that have both a triplet and a path, so none if the triplets are cut off
+    /// # Arguments
+    /// * `desc` - The descriptor of the note
+    /// * `big_endian` - Whether the file is big endian
+    /// * `class` - The class of the file
+    pub fn from_desc(desc: &[u8], big_endian: bool, class: Class) -> Result<Self, UnpackError> {
+        let mut r = Cursor::new(desc);
+
+        let count = u64::unpack_class(&mut r, big_endian, class)?;
+        let page_size = u64::unpack_class(&mut r, big_endian, class)?;
+
+        let word_size: u64 = match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        let available = (desc.len() as u64 - r.position()) / (word_size * 3);
+        let truncated = count > available;
+        let count = count.min(available);
+
+        let mut ranges = Vec::new();
+        for _ in 0..count {
+            let start = u64::unpack_class(&mut r, big_endian, class)?;
+            let end = u64::unpack_class(&mut r, big_endian, class)?;
+            let page_offset = u64::unpack_class(&mut r, big_endian, class)?;
+            ranges.push((start, end, page_offset));
+        }
+
+        // The paths follow all triplets, so none are left if the triplets are cut off
+        let strings = match truncated {
+            true => &[],
+            false => &desc[r.position() as usize..],
+        };
+        let paths = strings
+            .split_inclusive(|c| *c == 0)
+            .filter(|s| s.ends_with(&[0]))
+            .map(|s| String::from_utf8_lossy(&s[..s.len() - 1]).into_owned());
+
+        let files = ranges
+            .into_iter()
+            .zip(paths)
+            .map(|((start, end, page_offset), path)| MappedFile {
+                start,
+                end,
+                page_offset,
+                path,
+            })
+            .collect();
+
+        Ok(Self { page_size, files })
+    }
+}
+
+/// The type of an entry of the auxiliary vector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u64)]
+pub enum AuxvType {
+    /// `AT_NULL`: The end of the vector
+    Null = 0,
+    /// `AT_PHDR`: The address of the program headers of the executable
+    Phdr = 3,
+    /// `AT_PAGESZ`: The page size of the system
+    PageSize = 6,
+    /// `AT_BASE`: The base address of the program interpreter
+    Base = 7,
+    /// `AT_ENTRY`: The entry point of the executable
+    Entry = 9,
+    /// `AT_PLATFORM`: The address of the string identifying the platform
+    Platform = 15,
+    /// `AT_HWCAP`: The hardware capabilities of the processor
+    HwCap = 16,
+    /// `AT_RANDOM`: The address of `16` random bytes
+    Random = 25,
+    /// `AT_HWCAP2`: The extended hardware capabilities of the processor
+    HwCap2 = 26,
+    /// `AT_EXECFN`: The address of the path the executable was executed with
+    ExecFn = 31,
+    /// Any other entry type
+    Other(u64),
+}
+
+impl AuxvType {
+    /// Returns the raw value of this entry type
+    pub fn value(&self) -> u64 {
+        match self {
+            Self::Null => 0,
+            Self::Phdr => 3,
+            Self::PageSize => 6,
+            Self::Base => 7,
+            Self::Entry => 9,
+            Self::Platform => 15,
+            Self::HwCap => 16,
+            Self::Random => 25,
+            Self::HwCap2 => 26,
+            Self::ExecFn => 31,
+            Self::Other(ty) => *ty,
+        }
+    }
+}
+
+impl From<u64> for AuxvType {
+    fn from(value: u64) -> Self {
+        match value {
+            0 => Self::Null,
+            3 => Self::Phdr,
+            6 => Self::PageSize,
+            7 => Self::Base,
+            9 => Self::Entry,
+            15 => Self::Platform,
+            16 => Self::HwCap,
+            25 => Self::Random,
+            26 => Self::HwCap2,
+            31 => Self::ExecFn,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// An entry of the auxiliary vector the kernel passes to a process
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuxvEntry {
+    /// The type of the entry
+    pub ty: AuxvType,
+    /// The value of the entry, an address for some types
+    pub value: u64,
+}
+
+impl AuxvEntry {
+    /// Parses the auxiliary vector in the descriptor of a `NT_AUXV` note
+    ///
+    /// The vector consists of class-sized type and value pairs and
+    /// ends with `AT_NULL`, which is not part of the result
+    /// # Arguments
+    /// * `desc` - The descriptor of the note
+    /// * `big_endian` - Whether the file is big endian
+    /// * `class` - The class of the file
+    pub fn parse_vector(
+        desc: &[u8],
+        big_endian: bool,
+        class: Class,
+    ) -> Result<Vec<AuxvEntry>, UnpackError> {
+        let mut r = Cursor::new(desc);
+        let mut res = Vec::new();
+
+        while r.position() < desc.len() as u64 {
+            let ty = AuxvType::from(u64::unpack_class(&mut r, big_endian, class)?);
+            let value = u64::unpack_class(&mut r, big_endian, class)?;
+
+            if ty == AuxvType::Null {
+                break;
+            }
+
+            res.push(Self { ty, value });
+        }
+
+        Ok(res)
+    }
+}
+
+/// The information about the crashed process in a core dump
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CoreInfo {
+    /// The status of every thread in note order, the first one caused the dump
+    pub threads: Vec<PrStatus>,
+    /// Information about the process
+    pub process: Option<PrPsInfo>,
+    /// The files mapped into the process
+    pub mapped_files: Option<MappedFiles>,
+    /// The auxiliary vector of the process
+    auxv: Vec<AuxvEntry>,
+}
+
+impl CoreInfo {
+    /// Returns the auxiliary vector of the process, empty if there is no `NT_AUXV` note
+    pub fn auxv(&self) -> &[AuxvEntry] {
+        &self.auxv
+    }
+
+    /// Returns the value of the first auxiliary vector entry of type `ty`
+    /// # Arguments
+    /// * `ty` - The type of the entry (e.g. [AuxvType::Entry] for the entry point)
+    pub fn auxv_value(&self, ty: AuxvType) -> Option<u64> {
+        self.auxv.iter().find(|e| e.ty == ty).map(|e| e.value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ELFFile {
+    /// Returns the information stored in the notes of a core dump
+    ///
+    /// The data of the `PT_NOTE` segments must be loaded using
+    /// [ELFFile::load_fully], unknown notes are skipped
+    /// # Returns
+    /// `None` if this is not a core dump
+    pub fn core_info(&self) -> Result<Option<CoreInfo>, UnpackError> {
+        if self.header.ty != ELFType::Core {
+            return Ok(None);
+        }
+
+        let big_endian = self.header.ident.is_big_endian();
+        let class = self.header.ident.class;
+        let mut info = CoreInfo::default();
+
+        for note in self.notes()? {
+            if note.name != b"CORE" {
+                continue;
+            }
+
+            match note.ty {
+                NT_PRSTATUS => info.threads.push(PrStatus::from_desc(
+                    &note.desc,
+                    big_endian,
+                    class,
+                    self.header.machine,
+                )?),
+                NT_PRPSINFO => {
+                    info.process = Some(PrPsInfo::from_desc(&note.desc, big_endian, class)?)
+                }
+                NT_AUXV => info.auxv = AuxvEntry::parse_vector(&note.desc, big_endian, class)?,
+                NT_FILE => {
+                    info.mapped_files = Some(MappedFiles::from_desc(&note.desc, big_endian, class)?)
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Some(info))
+    }
+}
+
+/// Converts a fixed size, null padded string to a string
+fn fixed_string(data: &[u8]) -> String {
+    let len = data.iter().position(|c| *c == 0).unwrap_or(data.len());
+    String::from_utf8_lossy(&data[..len]).trim_end().to_owned()
+}
@@ -0,0 +1,89 @@
+use alloc::{borrow::ToOwned, string::String};
+
+use crate::align_up;
+#[cfg(feature = "std")]
+use crate::ELFFile;
+
+/// The contents of a `.gnu_debuglink` section, which refers to the
+/// file holding the debug information of a stripped file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugLink {
+    /// The name of the debug file
+    pub filename: String,
+    /// The CRC32 checksum of the debug file, see [debuglink_crc32]
+    pub crc32: u32,
+}
+
+impl DebugLink {
+    /// Parses the contents of a `.gnu_debuglink` section
+    ///
+    /// The null terminated name is padded to `4` bytes and followed by the checksum
+    /// # Arguments
+    /// * `data` - The data of the section
+    /// * `big_endian` - Whether the file is big endian
+    pub fn parse(data: &[u8], big_endian: bool) -> Option<Self> {
+        let len = data.iter().position(|c| *c == 0)?;
+        let filename = core::str::from_utf8(&data[..len]).ok()?.to_owned();
+
+        let offset = align_up(len as u64 + 1, 4)? as usize;
+        let crc32: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+
+        Some(Self {
+            filename,
+            crc32: match big_endian {
+                true => u32::from_be_bytes(crc32),
+                false => u32::from_le_bytes(crc32),
+            },
+        })
+    }
+}
+
+/// The lookup table of the CRC32 checksum for the reflected polynomial `0xedb88320`
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xedb88320,
+                _ => crc >> 1,
+            };
+            bit += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+};
+
+/// Computes the CRC32 checksum GDB uses to verify the file named by a `.gnu_debuglink`
+/// # Arguments
+/// * `data` - The contents of the debug file
+pub fn debuglink_crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, c| {
+        CRC32_TABLE[((crc ^ *c as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(feature = "std")]
+impl ELFFile {
+    /// Returns the debug link of this file (`.gnu_debuglink`)
+    ///
+    /// The data of the section must be loaded using [ELFFile::load_fully]
+    /// # Returns
+    /// `None` if there is no valid debug link
+    pub fn debuglink(&self) -> Option<DebugLink> {
+        let index = self.section_index_by_name(".gnu_debuglink")?;
+
+        DebugLink::parse(
+            &self.section_headers[index].data,
+            self.header.ident.is_big_endian(),
+        )
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt::Display;
+
+use crate::{ELFFile, HeaderField, SectionHeader};
+
+/// The differences between two ELF files, see [ELFFile::diff]
+///
+/// Sections are matched by their name and segments by their type, each in order of appearance.
+/// Added entries are indices into the new file, removed and modified ones into the old file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ELFDiff<'a> {
+    /// The fields of the header that differ
+    pub header_changes: Vec<HeaderField>,
+    /// The sections only present in the new file
+    pub added_sections: Vec<usize>,
+    /// The sections only present in the old file
+    pub removed_sections: Vec<usize>,
+    /// The sections whose header or data differ
+    pub modified_sections: Vec<usize>,
+    /// The segments only present in the new file
+    pub added_programs: Vec<usize>,
+    /// The segments only present in the old file
+    pub removed_programs: Vec<usize>,
+    /// The segments whose header or data differ
+    pub modified_programs: Vec<usize>,
+    /// The old file
+    old: &'a ELFFile,
+    /// The new file
+    new: &'a ELFFile,
+}
+
+impl ELFDiff<'_> {
+    /// Returns whether the files do not differ
+    pub fn is_empty(&self) -> bool {
+        self.header_changes.is_empty()
+            && self.added_sections.is_empty()
+            && self.removed_sections.is_empty()
+            && self.modified_sections.is_empty()
+            && self.added_programs.is_empty()
+            && self.removed_programs.is_empty()
+            && self.modified_programs.is_empty()
+    }
+}
+
+/// Displays a summary with one line per change, prefixed by `+`, `-` or `~`
+impl Display for ELFDiff<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No differences");
+        }
+
+        if !self.header_changes.is_empty() {
+            writeln!(f, "Header:")?;
+            for field in &self.header_changes {
+                writeln!(f, "  ~ {}", field)?;
+            }
+        }
+
+        let section_name = |elf: &ELFFile, index: usize| {
+            elf.section_name(index)
+                .map_or_else(|_| "<unknown>".to_owned(), |n| n.to_owned())
+        };
+        let sections = [
+            ('+', self.new, &self.added_sections),
+            ('-', self.old, &self.removed_sections),
+            ('~', self.old, &self.modified_sections),
+        ];
+        if sections.iter().any(|(_, _, s)| !s.is_empty()) {
+            writeln!(f, "Sections:")?;
+            for (sign, elf, indices) in sections {
+                for index in indices {
+                    writeln!(f, "  {} [{:>2}] {}", sign, index, section_name(elf, *index))?;
+                }
+            }
+        }
+
+        let programs = [
+            ('+', self.new, &self.added_programs),
+            ('-', self.old, &self.removed_programs),
+            ('~', self.old, &self.modified_programs),
+        ];
+        if programs.iter().any(|(_, _, p)| !p.is_empty()) {
+            writeln!(f, "Segments:")?;
+            for (sign, elf, indices) in programs {
+                for index in indices {
+                    writeln!(
+                        f,
+                        "  {} [{:>2}] {}",
+                        sign, index, elf.program_headers[*index].ty
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The result of matching two lists of entries by their keys
+struct Matching {
+    /// The pairs of matching old and new indices
+    pairs: Vec<(usize, usize)>,
+    /// The old indices without a match
+    removed: Vec<usize>,
+    /// The new indices without a match
+    added: Vec<usize>,
+}
+
+/// Matches the entries of `old` and `new` by their keys, in order of appearance
+///
+/// Keys that appear multiple times are paired by their occurrence
+fn match_keys<K: PartialEq>(old: &[K], new: &[K]) -> Matching {
+    let mut matched = vec![false; new.len()];
+    let mut pairs = Vec::new();
+    let mut removed = Vec::new();
+
+    for (i, key) in old.iter().enumerate() {
+        match (0..new.len()).find(|j| !matched[*j] && new[*j] == *key) {
+            Some(j) => {
+                matched[j] = true;
+                pairs.push((i, j));
+            }
+            None => removed.push(i),
+        }
+    }
+
+    Matching {
+        pairs,
+        removed,
+        added: (0..new.len()).filter(|j| !matched[*j]).collect(),
+    }
+}
+
+/// Returns whether two sections differ in anything but the offset of their name
+fn section_differs(old: &SectionHeader, new: &SectionHeader) -> bool {
+    old.ty != new.ty
+        || old.flags != new.flags
+        || old.address != new.address
+        || old.offset != new.offset
+        || old.size != new.size
+        || old.link != new.link
+        || old.info != new.info
+        || old.addr_align != new.addr_align
+        || old.entry_size != new.entry_size
+        || old.data != new.data
+}
+
+impl ELFFile {
+    /// Compares this file to `other`
+    ///
+    /// Section data is only compared as far as it is loaded, use [ELFFile::load_fully]
+    /// on both files to compare the contents
+    /// # Arguments
+    /// * `other` - The new file to compare against
+    pub fn diff<'a>(&'a self, other: &'a ELFFile) -> ELFDiff<'a> {
+        let section_keys = |elf: &'a ELFFile| -> Vec<Option<&'a str>> {
+            (0..elf.section_headers.len())
+                .map(|i| elf.section_name(i).ok())
+                .collect()
+        };
+        let sections = match_keys(&section_keys(self), &section_keys(other));
+
+        let program_keys =
+            |elf: &ELFFile| elf.program_headers.iter().map(|p| p.ty).collect::<Vec<_>>();
+        let programs = match_keys(&program_keys(self), &program_keys(other));
+
+        ELFDiff {
+            header_changes: self.header.changed_fields(&other.header),
+            added_sections: sections.added,
+            removed_sections: sections.removed,
+            modified_sections: sections
+                .pairs
+                .into_iter()
+                .filter(|(i, j)| {
+                    section_differs(&self.section_headers[*i], &other.section_headers[*j])
+                })
+                .map(|(i, _)| i)
+                .collect(),
+            added_programs: programs.added,
+            removed_programs: programs.removed,
+            modified_programs: programs
+                .pairs
+                .into_iter()
+                .filter(|(i, j)| self.program_headers[*i] != other.program_headers[*j])
+                .map(|(i, _)| i)
+                .collect(),
+            old: self,
+            new: other,
+        }
+    }
+}
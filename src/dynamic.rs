@@ -0,0 +1,654 @@
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use crate::{
+    io::{self, Cursor},
+    Blob, Class, Machine, PackableClass, ProgramHeader, SectionHeader, UnpackError,
+    UnpackableClass,
+};
+
+/// An entry in the dynamic section (`.dynamic` / `PT_DYNAMIC`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynamicEntry {
+    /// The tag describing the meaning of `value`
+    pub tag: DynamicTag,
+    /// The value or address of this entry
+    pub value: u64,
+}
+
+/// The tag of a dynamic section entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i64)]
+pub enum DynamicTag {
+    /// Marks the end of the dynamic section
+    Null = 0,
+    /// The string table offset of a needed library
+    Needed = 1,
+    /// The size of the PLT relocations
+    PltRelSize = 2,
+    /// The address of the PLT or GOT (processor specific)
+    PltGot = 3,
+    /// The address of the SysV symbol hash table
+    Hash = 4,
+    /// The address of the dynamic string table
+    StrTab = 5,
+    /// The address of the dynamic symbol table
+    SymTab = 6,
+    /// The address of the relocations with addends
+    Rela = 7,
+    /// The total size of the relocations with addends
+    RelaSize = 8,
+    /// The size of a single relocation entry with addend
+    RelaEnt = 9,
+    /// The size of the dynamic string table
+    StrSize = 10,
+    /// The size of a single symbol table entry
+    SymEnt = 11,
+    /// The address of the initialization function
+    Init = 12,
+    /// The address of the termination function
+    Fini = 13,
+    /// The string table offset of the shared object name
+    SoName = 14,
+    /// The string table offset of the library search path (deprecated)
+    RPath = 15,
+    /// The address of the relocations without addends
+    Rel = 17,
+    /// The total size of the relocations without addends
+    RelSize = 18,
+    /// The size of a single relocation entry without addend
+    RelEnt = 19,
+    /// The type of the PLT relocations, [DynamicTag::Rel] or [DynamicTag::Rela]
+    PltRel = 20,
+    /// The address of the PLT relocations
+    JmpRel = 23,
+    /// All relocations must be processed before control is transferred
+    BindNow = 24,
+    /// The string table offset of the library search path
+    RunPath = 29,
+    /// Flags for the object being loaded
+    Flags = 30,
+    /// The address of the GNU symbol hash table
+    GnuHash = 0x6ffffef5,
+    /// State flags for the object being loaded
+    Flags1 = 0x6ffffffb,
+    /// A MIPS specific tag, only decoded by [DynamicTag::for_machine]
+    Mips(MipsDynamicTag),
+    /// Any other unknown tag
+    Other(i64),
+}
+
+impl DynamicTag {
+    /// Returns the raw value of this tag
+    pub fn value(&self) -> i64 {
+        match self {
+            Self::Null => 0,
+            Self::Needed => 1,
+            Self::PltRelSize => 2,
+            Self::PltGot => 3,
+            Self::Hash => 4,
+            Self::StrTab => 5,
+            Self::SymTab => 6,
+            Self::Rela => 7,
+            Self::RelaSize => 8,
+            Self::RelaEnt => 9,
+            Self::StrSize => 10,
+            Self::SymEnt => 11,
+            Self::Init => 12,
+            Self::Fini => 13,
+            Self::SoName => 14,
+            Self::RPath => 15,
+            Self::Rel => 17,
+            Self::RelSize => 18,
+            Self::RelEnt => 19,
+            Self::PltRel => 20,
+            Self::JmpRel => 23,
+            Self::BindNow => 24,
+            Self::RunPath => 29,
+            Self::Flags => 30,
+            Self::GnuHash => 0x6ffffef5,
+            Self::Flags1 => 0x6ffffffb,
+            Self::Mips(tag) => tag.value(),
+            Self::Other(tag) => *tag,
+        }
+    }
+
+    /// Decodes the processor specific tags of `machine`
+    ///
+    /// Processor specific tags overlap between machines,
+    /// so they are kept as [DynamicTag::Other] until decoded here
+    /// # Arguments
+    /// * `machine` - The machine of the file
+    pub fn for_machine(self, machine: Machine) -> Self {
+        match (machine, self) {
+            (Machine::Mips, Self::Other(tag)) => match MipsDynamicTag::from_value(tag) {
+                Some(tag) => Self::Mips(tag),
+                None => self,
+            },
+            _ => self,
+        }
+    }
+}
+
+impl From<i64> for DynamicTag {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => Self::Null,
+            1 => Self::Needed,
+            2 => Self::PltRelSize,
+            3 => Self::PltGot,
+            4 => Self::Hash,
+            5 => Self::StrTab,
+            6 => Self::SymTab,
+            7 => Self::Rela,
+            8 => Self::RelaSize,
+            9 => Self::RelaEnt,
+            10 => Self::StrSize,
+            11 => Self::SymEnt,
+            12 => Self::Init,
+            13 => Self::Fini,
+            14 => Self::SoName,
+            15 => Self::RPath,
+            17 => Self::Rel,
+            18 => Self::RelSize,
+            19 => Self::RelEnt,
+            20 => Self::PltRel,
+            23 => Self::JmpRel,
+            24 => Self::BindNow,
+            29 => Self::RunPath,
+            30 => Self::Flags,
+            0x6ffffef5 => Self::GnuHash,
+            0x6ffffffb => Self::Flags1,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// The processor specific dynamic tags of MIPS files (`DT_MIPS_*`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i64)]
+pub enum MipsDynamicTag {
+    /// `DT_MIPS_RLD_VERSION`: The version of the runtime linker interface
+    RldVersion = 0x70000001,
+    /// `DT_MIPS_TIME_STAMP`: The timestamp
+    TimeStamp = 0x70000002,
+    /// `DT_MIPS_ICHECKSUM`: The checksum
+    IChecksum = 0x70000003,
+    /// `DT_MIPS_IVERSION`: The string table offset of the version string
+    IVersion = 0x70000004,
+    /// `DT_MIPS_FLAGS`: Flags
+    Flags = 0x70000005,
+    /// `DT_MIPS_BASE_ADDRESS`: The base address of the segment
+    BaseAddress = 0x70000006,
+    /// `DT_MIPS_MSYM`: The address of the `.msym` section
+    MSym = 0x70000007,
+    /// `DT_MIPS_CONFLICT`: The address of the `.conflict` section
+    Conflict = 0x70000008,
+    /// `DT_MIPS_LIBLIST`: The address of the `.liblist` section
+    LibList = 0x70000009,
+    /// `DT_MIPS_LOCAL_GOTNO`: The number of local GOT entries
+    LocalGotNo = 0x7000000a,
+    /// `DT_MIPS_CONFLICTNO`: The number of `.conflict` entries
+    ConflictNo = 0x7000000b,
+    /// `DT_MIPS_LIBLISTNO`: The number of `.liblist` entries
+    LibListNo = 0x70000010,
+    /// `DT_MIPS_SYMTABNO`: The number of dynamic symbol table entries
+    SymTabNo = 0x70000011,
+    /// `DT_MIPS_UNREFEXTNO`: The index of the first external dynamic symbol not referenced locally
+    UnrefExtNo = 0x70000012,
+    /// `DT_MIPS_GOTSYM`: The index of the first dynamic symbol with a GOT entry
+    GotSym = 0x70000013,
+    /// `DT_MIPS_HIPAGENO`: The number of GOT page table entries
+    HiPageNo = 0x70000014,
+    /// `DT_MIPS_RLD_MAP`: The address of the runtime linker map
+    RldMap = 0x70000016,
+    /// `DT_MIPS_PLTGOT`: The address of the `.got.plt` section
+    PltGot = 0x70000032,
+    /// `DT_MIPS_RWPLT`: The address of the writable PLT
+    RwPlt = 0x70000034,
+    /// `DT_MIPS_RLD_MAP_REL`: The offset of the runtime linker map relative to this entry
+    RldMapRel = 0x70000035,
+    /// `DT_MIPS_XHASH`: The address of the GNU style hash table with xlat
+    XHash = 0x70000036,
+}
+
+impl MipsDynamicTag {
+    /// Returns the raw value of this tag
+    pub fn value(&self) -> i64 {
+        *self as i64
+    }
+
+    /// Returns the name of this tag as displayed by `readelf`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::RldVersion => "MIPS_RLD_VERSION",
+            Self::TimeStamp => "MIPS_TIME_STAMP",
+            Self::IChecksum => "MIPS_ICHECKSUM",
+            Self::IVersion => "MIPS_IVERSION",
+            Self::Flags => "MIPS_FLAGS",
+            Self::BaseAddress => "MIPS_BASE_ADDRESS",
+            Self::MSym => "MIPS_MSYM",
+            Self::Conflict => "MIPS_CONFLICT",
+            Self::LibList => "MIPS_LIBLIST",
+            Self::LocalGotNo => "MIPS_LOCAL_GOTNO",
+            Self::ConflictNo => "MIPS_CONFLICTNO",
+            Self::LibListNo => "MIPS_LIBLISTNO",
+            Self::SymTabNo => "MIPS_SYMTABNO",
+            Self::UnrefExtNo => "MIPS_UNREFEXTNO",
+            Self::GotSym => "MIPS_GOTSYM",
+            Self::HiPageNo => "MIPS_HIPAGENO",
+            Self::RldMap => "MIPS_RLD_MAP",
+            Self::PltGot => "MIPS_PLTGOT",
+            Self::RwPlt => "MIPS_RWPLT",
+            Self::RldMapRel => "MIPS_RLD_MAP_REL",
+            Self::XHash => "MIPS_XHASH",
+        }
+    }
+
+    /// Decodes a raw tag value
+    /// # Arguments
+    /// * `value` - The raw tag value
+    /// # Returns
+    /// `None` if `value` is not a known MIPS tag
+    pub fn from_value(value: i64) -> Option<Self> {
+        Some(match value {
+            0x70000001 => Self::RldVersion,
+            0x70000002 => Self::TimeStamp,
+            0x70000003 => Self::IChecksum,
+            0x70000004 => Self::IVersion,
+            0x70000005 => Self::Flags,
+            0x70000006 => Self::BaseAddress,
+            0x70000007 => Self::MSym,
+            0x70000008 => Self::Conflict,
+            0x70000009 => Self::LibList,
+            0x7000000a => Self::LocalGotNo,
+            0x7000000b => Self::ConflictNo,
+            0x70000010 => Self::LibListNo,
+            0x70000011 => Self::SymTabNo,
+            0x70000012 => Self::UnrefExtNo,
+            0x70000013 => Self::GotSym,
+            0x70000014 => Self::HiPageNo,
+            0x70000016 => Self::RldMap,
+            0x70000032 => Self::PltGot,
+            0x70000034 => Self::RwPlt,
+            0x70000035 => Self::RldMapRel,
+            0x70000036 => Self::XHash,
+            _ => return None,
+        })
+    }
+}
+
+/// The flags stored in a [DynamicTag::Flags] entry
+///
+/// Displays the flag names like `readelf -d` does (e.g. `BIND_NOW`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DynamicFlags(pub u64);
+
+impl DynamicFlags {
+    /// The object may reference `$ORIGIN`
+    pub const ORIGIN: Self = Self(0x1);
+    /// Symbol resolution starts at this object
+    pub const SYMBOLIC: Self = Self(0x2);
+    /// The object contains text relocations
+    pub const TEXTREL: Self = Self(0x4);
+    /// All relocations must be processed before control is transferred
+    pub const BIND_NOW: Self = Self(0x8);
+    /// The object uses the static thread-local storage model
+    pub const STATIC_TLS: Self = Self(0x10);
+
+    const NAMES: [(Self, &'static str); 5] = [
+        (Self::ORIGIN, "ORIGIN"),
+        (Self::SYMBOLIC, "SYMBOLIC"),
+        (Self::TEXTREL, "TEXTREL"),
+        (Self::BIND_NOW, "BIND_NOW"),
+        (Self::STATIC_TLS, "STATIC_TLS"),
+    ];
+
+    /// Returns whether all the bits in `other` are set in `self`
+    /// # Arguments
+    /// * `other` - The flags to check for
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether immediate binding is requested
+    pub fn bind_now(&self) -> bool {
+        self.contains(Self::BIND_NOW)
+    }
+}
+
+impl Display for DynamicFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_flag_names(
+            f,
+            self.0,
+            Self::NAMES.iter().map(|(flag, name)| (flag.0, *name)),
+        )
+    }
+}
+
+/// The flags stored in a [DynamicTag::Flags1] entry
+///
+/// Displays the flag names like `readelf -d` does (e.g. `NOW PIE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DynamicFlags1(pub u64);
+
+impl DynamicFlags1 {
+    /// All relocations must be processed before control is transferred
+    pub const NOW: Self = Self(0x1);
+    /// The symbols of this object are made available globally
+    pub const GLOBAL: Self = Self(0x2);
+    /// The object is a member of a group
+    pub const GROUP: Self = Self(0x4);
+    /// The object can not be deleted from a process
+    pub const NODELETE: Self = Self(0x8);
+    /// Filtees are loaded immediately
+    pub const LOADFLTR: Self = Self(0x10);
+    /// The object is initialized first
+    pub const INITFIRST: Self = Self(0x20);
+    /// The object can not be loaded with `dlopen()`
+    pub const NOOPEN: Self = Self(0x40);
+    /// The object requires `$ORIGIN` processing
+    pub const ORIGIN: Self = Self(0x80);
+    /// Direct binding is enabled
+    pub const DIRECT: Self = Self(0x100);
+    /// Unused
+    pub const TRANS: Self = Self(0x200);
+    /// The object is an interposer
+    pub const INTERPOSE: Self = Self(0x400);
+    /// The default library search path is ignored
+    pub const NODEFLIB: Self = Self(0x800);
+    /// The object can not be dumped with `dldump()`
+    pub const NODUMP: Self = Self(0x1000);
+    /// The object is a configuration alternative
+    pub const CONFALT: Self = Self(0x2000);
+    /// The filtee terminates the filter's search
+    pub const ENDFILTEE: Self = Self(0x4000);
+    /// Displacement relocations have been performed
+    pub const DISPRELDNE: Self = Self(0x8000);
+    /// Displacement relocations are pending
+    pub const DISPRELPND: Self = Self(0x10000);
+    /// The object has non-direct bindings
+    pub const NODIRECT: Self = Self(0x20000);
+    /// Multiple definitions are ignored
+    pub const IGNMULDEF: Self = Self(0x40000);
+    /// Kernel symbols are not stored
+    pub const NOKSYMS: Self = Self(0x80000);
+    /// The ELF header is not stored
+    pub const NOHDR: Self = Self(0x100000);
+    /// The object has been modified after being built
+    pub const EDITED: Self = Self(0x200000);
+    /// The object has no relocations
+    pub const NORELOC: Self = Self(0x400000);
+    /// The object contains individual symbol interposers
+    pub const SYMINTPOSE: Self = Self(0x800000);
+    /// Global auditing is requested
+    pub const GLOBAUDIT: Self = Self(0x1000000);
+    /// The object defines singleton symbols
+    pub const SINGLETON: Self = Self(0x2000000);
+    /// The object is a stub
+    pub const STUB: Self = Self(0x4000000);
+    /// The object is a position independent executable
+    pub const PIE: Self = Self(0x8000000);
+
+    const NAMES: [(Self, &'static str); 28] = [
+        (Self::NOW, "NOW"),
+        (Self::GLOBAL, "GLOBAL"),
+        (Self::GROUP, "GROUP"),
+        (Self::NODELETE, "NODELETE"),
+        (Self::LOADFLTR, "LOADFLTR"),
+        (Self::INITFIRST, "INITFIRST"),
+        (Self::NOOPEN, "NOOPEN"),
+        (Self::ORIGIN, "ORIGIN"),
+        (Self::DIRECT, "DIRECT"),
+        (Self::TRANS, "TRANS"),
+        (Self::INTERPOSE, "INTERPOSE"),
+        (Self::NODEFLIB, "NODEFLIB"),
+        (Self::NODUMP, "NODUMP"),
+        (Self::CONFALT, "CONFALT"),
+        (Self::ENDFILTEE, "ENDFILTEE"),
+        (Self::DISPRELDNE, "DISPRELDNE"),
+        (Self::DISPRELPND, "DISPRELPND"),
+        (Self::NODIRECT, "NODIRECT"),
+        (Self::IGNMULDEF, "IGNMULDEF"),
+        (Self::NOKSYMS, "NOKSYMS"),
+        (Self::NOHDR, "NOHDR"),
+        (Self::EDITED, "EDITED"),
+        (Self::NORELOC, "NORELOC"),
+        (Self::SYMINTPOSE, "SYMINTPOSE"),
+        (Self::GLOBAUDIT, "GLOBAUDIT"),
+        (Self::SINGLETON, "SINGLETON"),
+        (Self::STUB, "STUB"),
+        (Self::PIE, "PIE"),
+    ];
+
+    /// Returns whether all the bits in `other` are set in `self`
+    /// # Arguments
+    /// * `other` - The flags to check for
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether immediate binding is requested
+    pub fn bind_now(&self) -> bool {
+        self.contains(Self::NOW)
+    }
+
+    /// Returns whether the object is flagged as a position independent executable
+    pub fn is_pie_flagged(&self) -> bool {
+        self.contains(Self::PIE)
+    }
+}
+
+impl Display for DynamicFlags1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_flag_names(
+            f,
+            self.0,
+            Self::NAMES.iter().map(|(flag, name)| (flag.0, *name)),
+        )
+    }
+}
+
+/// Writes the names of the flags set in `value` separated by spaces,
+/// followed by any unknown remaining bits in hex
+fn fmt_flag_names<'a, I: Iterator<Item = (u64, &'a str)>>(
+    f: &mut core::fmt::Formatter<'_>,
+    value: u64,
+    names: I,
+) -> core::fmt::Result {
+    let mut remaining = value;
+    let mut first = true;
+
+    for (flag, name) in names {
+        if value & flag == flag {
+            if !first {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", name)?;
+            remaining &= !flag;
+            first = false;
+        }
+    }
+
+    if remaining != 0 {
+        if !first {
+            write!(f, " ")?;
+        }
+        write!(f, "{:#x}", remaining)?;
+    }
+
+    Ok(())
+}
+
+impl DynamicEntry {
+    /// Returns the size a dynamic entry takes in a dynamic section of `class`
+    /// # Arguments
+    /// * `class` - The ELF class of the dynamic section
+    pub fn get_size(class: Class) -> u64 {
+        match class {
+            Class::ELF32 => 0x8,
+            Class::ELF64 => 0x10,
+        }
+    }
+}
+
+impl PackableClass for DynamicEntry {
+    fn pack_class<W: io::Write + io::Seek>(
+        self,
+        w: &mut W,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<(), io::Error> {
+        (self.tag.value() as u64).pack_class(w, big_endian, class)?;
+        self.value.pack_class(w, big_endian, class)?;
+
+        Ok(())
+    }
+}
+
+impl UnpackableClass for DynamicEntry {
+    fn unpack_class<R: io::Read + io::Seek>(
+        r: &mut R,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<Self, UnpackError> {
+        let tag = u64::unpack_class(r, big_endian, class)?;
+
+        // The tag is a signed value, so it needs sign extension in 32 bit files
+        let tag = match class {
+            Class::ELF32 => tag as u32 as i32 as i64,
+            Class::ELF64 => tag as i64,
+        };
+
+        Ok(Self {
+            tag: DynamicTag::from(tag),
+            value: u64::unpack_class(r, big_endian, class)?,
+        })
+    }
+}
+
+/// A parsed dynamic section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicSection {
+    /// The entries in file order, without the terminating [DynamicTag::Null] entry
+    pub entries: Vec<DynamicEntry>,
+}
+
+impl DynamicSection {
+    /// Parses the dynamic entries contained in `blob`
+    /// # Arguments
+    /// * `blob` - The contents of the dynamic section or segment
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn from_blob(blob: &Blob, class: Class, big_endian: bool) -> Result<Self, UnpackError> {
+        Ok(Self {
+            entries: DynamicIter::new(blob, class, big_endian).collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Finds the first entry with `tag`
+    /// # Arguments
+    /// * `tag` - The tag to search for
+    pub fn find(&self, tag: DynamicTag) -> Option<&DynamicEntry> {
+        self.entries.iter().find(|e| e.tag == tag)
+    }
+}
+
+/// An iterator over the entries of a dynamic section,
+/// stopping at the terminating [DynamicTag::Null] entry
+pub struct DynamicIter<'a> {
+    cursor: Cursor<&'a [u8]>,
+    big_endian: bool,
+    class: Class,
+    machine: Option<Machine>,
+    done: bool,
+}
+
+impl<'a> DynamicIter<'a> {
+    /// Creates an iterator over the dynamic entries in `data`
+    /// # Arguments
+    /// * `data` - The contents of the dynamic section or segment
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn new(data: &'a Blob, class: Class, big_endian: bool) -> Self {
+        Self {
+            cursor: data.as_cursor(),
+            big_endian,
+            class,
+            machine: None,
+            done: false,
+        }
+    }
+
+    /// Decodes the processor specific tags of `machine` while iterating
+    /// # Arguments
+    /// * `machine` - The machine of the file
+    pub fn with_machine(mut self, machine: Machine) -> Self {
+        self.machine = Some(machine);
+        self
+    }
+
+    /// Consumes the iterator to find the value of the first entry with `tag`
+    /// # Arguments
+    /// * `tag` - The tag to search for
+    pub fn find_tag(mut self, tag: DynamicTag) -> Result<Option<u64>, UnpackError> {
+        for entry in &mut self {
+            let entry = entry?;
+
+            if entry.tag == tag {
+                return Ok(Some(entry.value));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl Iterator for DynamicIter<'_> {
+    type Item = Result<DynamicEntry, UnpackError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.cursor.position() >= self.cursor.get_ref().len() as u64 {
+            return None;
+        }
+
+        match DynamicEntry::unpack_class(&mut self.cursor, self.big_endian, self.class) {
+            Ok(entry) if entry.tag == DynamicTag::Null => {
+                self.done = true;
+                None
+            }
+            Ok(mut entry) => {
+                if let Some(machine) = self.machine {
+                    entry.tag = entry.tag.for_machine(machine);
+                }
+
+                Some(Ok(entry))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl SectionHeader {
+    /// Iterates over the dynamic entries contained in this section's data
+    /// # Arguments
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn iter_dynamic(&self, class: Class, big_endian: bool) -> DynamicIter<'_> {
+        DynamicIter::new(&self.data, class, big_endian)
+    }
+}
+
+impl ProgramHeader {
+    /// Iterates over the dynamic entries contained in this segment's data
+    /// # Arguments
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn iter_dynamic(&self, class: Class, big_endian: bool) -> DynamicIter<'_> {
+        DynamicIter::new(&self.data, class, big_endian)
+    }
+}
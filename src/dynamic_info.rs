@@ -0,0 +1,221 @@
+use std::io::{Cursor, Seek, SeekFrom};
+
+use crate::{
+    Blob, Class, DynamicEntry, DynamicTag, ELFFile, GnuHashTable, ProgramHeaderType, RelEntry,
+    RelaEntry, RelocationTable, SectionType, StringTable, Symbol, SysvHashTable, UnpackError,
+    UnpackableClass,
+};
+
+/// The dynamic linking information of a file, as seen by the dynamic linker
+///
+/// All tables are located through the addresses in the dynamic table,
+/// so this works for files whose section headers were stripped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicInfo {
+    /// The entries of the dynamic table, excluding the terminating `DT_NULL`
+    pub entries: Vec<DynamicEntry>,
+    /// The names of the needed shared libraries (`DT_NEEDED`)
+    pub needed: Vec<String>,
+    /// The name of this shared object (`DT_SONAME`)
+    pub soname: Option<String>,
+    /// The dynamic symbols with resolved names, sized by `DT_HASH` or `DT_GNU_HASH`
+    pub symbols: Vec<Symbol>,
+    /// The relocations of `DT_RELA` or `DT_REL`
+    pub relocations: Option<RelocationTable>,
+    /// The PLT relocations of `DT_JMPREL`
+    pub plt_relocations: Option<RelocationTable>,
+}
+
+impl DynamicInfo {
+    /// Builds the dynamic linking information from the program headers of `elf` alone
+    ///
+    /// The `PT_DYNAMIC` segment provides the dynamic table and all addresses are
+    /// mapped through the data of the `PT_LOAD` segments, which must be loaded
+    /// using [ELFFile::load_fully]
+    /// # Arguments
+    /// * `elf` - The file to inspect
+    /// # Returns
+    /// `None` if the file has no `PT_DYNAMIC` segment
+    pub fn from_segments(elf: &ELFFile) -> Result<Option<Self>, UnpackError> {
+        let class = elf.header.ident.class;
+        let big_endian = elf.header.ident.is_big_endian();
+
+        let Some((_, segment)) = elf.programs_of_type(ProgramHeaderType::Dynamic).next() else {
+            return Ok(None);
+        };
+
+        let entries = segment
+            .iter_dynamic(class, big_endian)
+            .with_machine(elf.header.machine)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::build(elf, entries, |address, _| {
+            elf.programs_of_type(ProgramHeaderType::Loadable)
+                .find_map(|(_, p)| {
+                    let start = address.checked_sub(p.virtual_addr)?;
+                    p.data.get(usize::try_from(start).ok()?..)
+                })
+        })
+        .map(Some)
+    }
+
+    /// Builds the dynamic linking information from `entries`
+    /// # Arguments
+    /// * `elf` - The file the entries belong to
+    /// * `entries` - The entries of the dynamic table
+    /// * `data` - Returns the data at an address, given the type of the section expected there
+    fn build<'a, F: Fn(u64, SectionType) -> Option<&'a [u8]>>(
+        elf: &ELFFile,
+        entries: Vec<DynamicEntry>,
+        data: F,
+    ) -> Result<Self, UnpackError> {
+        let class = elf.header.ident.class;
+        let big_endian = elf.header.ident.is_big_endian();
+
+        let find = |tag: DynamicTag| entries.iter().find(|e| e.tag == tag).map(|e| e.value);
+        let lookup = |address: u64, ty: SectionType| {
+            data(address, ty).ok_or(UnpackError::UnmappedAddress { address })
+        };
+        let bounded = |address: u64, ty: SectionType, size: u64| -> Result<&[u8], UnpackError> {
+            let data = lookup(address, ty)?;
+            Ok(&data[..data.len().min(usize::try_from(size).unwrap_or(usize::MAX))])
+        };
+
+        let strings = match find(DynamicTag::StrTab) {
+            Some(address) => {
+                let size = find(DynamicTag::StrSize).unwrap_or(u64::MAX);
+                StringTable::new(bounded(address, SectionType::StringTable, size)?)
+            }
+            None => StringTable::new(&[]),
+        };
+        let resolve = |offset: u64| -> Result<String, UnpackError> {
+            let offset =
+                u32::try_from(offset).map_err(|_| UnpackError::InvalidString { offset })?;
+            strings.get(offset).map(|s| s.to_owned())
+        };
+
+        let needed = entries
+            .iter()
+            .filter(|e| e.tag == DynamicTag::Needed)
+            .map(|e| resolve(e.value))
+            .collect::<Result<_, _>>()?;
+
+        let soname = find(DynamicTag::SoName).map(resolve).transpose()?;
+
+        let symbol_count = if let Some(address) = find(DynamicTag::Hash) {
+            let table = lookup(address, SectionType::Hash)?;
+            Some(SysvHashTable::from_blob(&Blob::from(table), big_endian)?.symbol_count())
+        } else if let Some(address) = find(DynamicTag::GnuHash) {
+            let table = lookup(address, SectionType::GnuHash)?;
+            Some(GnuHashTable::from_blob(&Blob::from(table), class, big_endian)?.symbol_count())
+        } else {
+            None
+        };
+
+        let mut symbols = Vec::new();
+        if let (Some(address), Some(count)) = (find(DynamicTag::SymTab), symbol_count) {
+            let entry_size = find(DynamicTag::SymEnt).unwrap_or(Symbol::get_size(class));
+            let table = lookup(address, SectionType::DynSymbol)?;
+
+            symbols = read_entries::<Symbol>(table, count as u64, entry_size, class, big_endian)?;
+            for symbol in &mut symbols {
+                symbol.name = Some(strings.get(symbol.name_offset)?.to_owned());
+            }
+        }
+
+        let relocations = |address: u64, size: u64, rela: bool, entry_size: Option<u64>| {
+            let (ty, default_size) = match rela {
+                true => (SectionType::Rela, RelaEntry::get_size(class)),
+                false => (SectionType::Rel, RelEntry::get_size(class)),
+            };
+            let entry_size = entry_size.unwrap_or(default_size);
+            let table = bounded(address, ty, size)?;
+            let count = size / entry_size.max(1);
+
+            Ok::<_, UnpackError>(match rela {
+                true => RelocationTable::Rela(read_entries(
+                    table, count, entry_size, class, big_endian,
+                )?),
+                false => {
+                    RelocationTable::Rel(read_entries(table, count, entry_size, class, big_endian)?)
+                }
+            })
+        };
+
+        let dynamic_relocations = match (
+            find(DynamicTag::Rela).zip(find(DynamicTag::RelaSize)),
+            find(DynamicTag::Rel).zip(find(DynamicTag::RelSize)),
+        ) {
+            (Some((address, size)), _) => {
+                Some(relocations(address, size, true, find(DynamicTag::RelaEnt))?)
+            }
+            (None, Some((address, size))) => {
+                Some(relocations(address, size, false, find(DynamicTag::RelEnt))?)
+            }
+            (None, None) => None,
+        };
+
+        let plt_relocations = match find(DynamicTag::JmpRel).zip(find(DynamicTag::PltRelSize)) {
+            Some((address, size)) => {
+                let rela = find(DynamicTag::PltRel) == Some(DynamicTag::Rela.value() as u64);
+                Some(relocations(address, size, rela, None)?)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            entries,
+            needed,
+            soname,
+            symbols,
+            relocations: dynamic_relocations,
+            plt_relocations,
+        })
+    }
+}
+
+/// Reads `count` entries of `entry_size` bytes from `data`
+/// # Arguments
+/// * `data` - The data of the table
+/// * `count` - The amount of entries
+/// * `entry_size` - The size of a single entry
+/// * `class` - The ELF class of the file
+/// * `big_endian` - Whether the file is big endian
+fn read_entries<T: UnpackableClass>(
+    data: &[u8],
+    count: u64,
+    entry_size: u64,
+    class: Class,
+    big_endian: bool,
+) -> Result<Vec<T>, UnpackError> {
+    let mut cursor = Cursor::new(data);
+
+    (0..count)
+        .map(|index| {
+            cursor.seek(SeekFrom::Start(index * entry_size))?;
+            T::unpack_class(&mut cursor, big_endian, class)
+        })
+        .collect()
+}
+
+impl ELFFile {
+    /// Returns the dynamic linking information of this file
+    ///
+    /// Files without section headers are handled by [DynamicInfo::from_segments].
+    /// Otherwise, the sections at the addresses in the dynamic table are preferred
+    /// over the segment data. The data must be loaded using [ELFFile::load_fully]
+    /// # Returns
+    /// `None` if the file has no dynamic table
+    pub fn dynamic_info(&self) -> Result<Option<DynamicInfo>, UnpackError> {
+        if self.section_headers.is_empty() {
+            return DynamicInfo::from_segments(self);
+        }
+
+        let Some(iter) = self.iter_dynamic() else {
+            return Ok(None);
+        };
+        let entries = iter.collect::<Result<Vec<_>, _>>()?;
+
+        DynamicInfo::build(self, entries, |address, ty| self.dynamic_data(address, ty)).map(Some)
+    }
+}
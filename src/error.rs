@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use crate::{DecompressError, ELFValidationError, PatchError, UnpackError};
+
+/// Any error that can happen while working with an ELF file
+#[derive(Debug)]
+pub enum ELFError {
+    /// The file could not be unpacked
+    Unpack(UnpackError),
+    /// The file could not be patched
+    Patch(PatchError),
+    /// The data of a section could not be decompressed
+    Decompress(DecompressError),
+    /// The file is structurally invalid
+    Validation(ELFValidationError),
+    /// An IO error happened
+    IO(std::io::Error),
+}
+
+impl From<UnpackError> for ELFError {
+    fn from(value: UnpackError) -> Self {
+        Self::Unpack(value)
+    }
+}
+
+impl From<PatchError> for ELFError {
+    fn from(value: PatchError) -> Self {
+        Self::Patch(value)
+    }
+}
+
+impl From<DecompressError> for ELFError {
+    fn from(value: DecompressError) -> Self {
+        Self::Decompress(value)
+    }
+}
+
+impl From<ELFValidationError> for ELFError {
+    fn from(value: ELFValidationError) -> Self {
+        Self::Validation(value)
+    }
+}
+
+impl From<std::io::Error> for ELFError {
+    fn from(value: std::io::Error) -> Self {
+        Self::IO(value)
+    }
+}
+
+impl Display for ELFError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unpack(e) => write!(f, "{}", e),
+            Self::Patch(e) => write!(f, "{}", e),
+            Self::Decompress(e) => write!(f, "{}", e),
+            Self::Validation(e) => write!(f, "{}", e),
+            Self::IO(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ELFError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Unpack(e) => Some(e),
+            Self::Patch(e) => Some(e),
+            Self::Decompress(e) => Some(e),
+            Self::Validation(e) => Some(e),
+            Self::IO(e) => Some(e),
+        }
+    }
+}
@@ -1,12 +1,23 @@
-use std::io::{Read, Seek};
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Display},
+    fs::File,
+    io::{self, BufReader, BufWriter, Cursor, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
 
 use crate::{
-    str_from_null_terminated, Header, Packable, ProgramHeader, SectionHeader, UnpackError,
-    Unpackable,
+    AbiTag, Blob, DynamicFlags, DynamicFlags1, DynamicIter, DynamicTag, ELFType, GnuHashTable,
+    GnuProperty, Header, Note, NoteIter, NoteType, Packable, PackableClass, ProgramHeader,
+    ProgramHeaderType, SectionHeader, SectionIndex, SectionType, StringTable, Symbol,
+    SymbolSection, SymbolTable, SysvHashTable, UnpackError, Unpackable, UnpackableClass,
 };
 
 /// A representation of a ELF file
-#[derive(Debug)]
+///
+/// The debug output lists the sections by their names if they can be resolved
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ELFFile {
     /// The header of the ELF file
     pub header: Header,
@@ -19,7 +30,8 @@ pub struct ELFFile {
 impl ELFFile {
     /// Loads a ELF file from the provided stream
     ///
-    /// This will **not** load the binary blobs, only headers
+    /// This will **not** load the binary blobs, only headers and
+    /// the section name string table to resolve section names
     /// # Arguments
     /// * `r` - The stream to read from
     pub fn load<R: Read + Seek>(r: &mut R) -> Result<Self, UnpackError> {
@@ -28,13 +40,384 @@ impl ELFFile {
         let program_headers = header.read_program_headers(r)?;
         let section_headers = header.read_section_headers(r)?;
 
-        Ok(Self {
+        let mut file = Self {
             header,
             program_headers,
             section_headers,
+        };
+
+        if let Some(index) = file.section_names_index() {
+            if let Some(section) = file.section_headers.get_mut(index) {
+                if section.has_file_data() {
+                    section.data = Blob::load(r, section.offset, section.size as usize)?;
+                }
+            }
+        }
+
+        Ok(file)
+    }
+
+    /// Loads a ELF file including the data of all segments and sections
+    ///
+    /// This is required by all methods that inspect the contents of
+    /// segments or sections. `SHT_NULL` and `SHT_NOBITS` sections occupy
+    /// no space in the file and stay empty
+    /// # Arguments
+    /// * `r` - The stream to read from
+    pub fn load_fully<R: Read + Seek>(r: &mut R) -> Result<Self, UnpackError> {
+        let mut file = Self::load(r)?;
+
+        for program_header in &mut file.program_headers {
+            program_header.data =
+                Blob::load(r, program_header.offset, program_header.file_size as usize)?;
+        }
+
+        for section in &mut file.section_headers {
+            if section.has_file_data() {
+                section.data = Blob::load(r, section.offset, section.size as usize)?;
+            }
+        }
+
+        Ok(file)
+    }
+
+    /// Loads the data of the section at `index` after a headers-only load, see [ELFFile::load]
+    /// # Arguments
+    /// * `index` - The index of the section
+    /// * `r` - The stream the file was loaded from
+    pub fn load_section<R: Read + Seek>(
+        &mut self,
+        index: usize,
+        r: &mut R,
+    ) -> Result<(), UnpackError> {
+        self.section_headers
+            .get_mut(index)
+            .ok_or(UnpackError::InvalidSectionIndex { index })?
+            .load_data(r)?;
+
+        Ok(())
+    }
+
+    /// Loads an ELF file that has been mapped into memory by a loader,
+    /// e.g. read from the memory of a process or a memory dump
+    ///
+    /// The contents are laid out by virtual address instead of file offset:
+    /// The header is read at `base_addr` and the data of all segments is read at
+    /// their virtual address, shifted by the load bias. The section headers are
+    /// not mapped into memory and are therefore dropped, use [ELFFile::dynamic_info]
+    /// to inspect the dynamic linking information
+    /// # Arguments
+    /// * `r` - The stream to read from, positioned by address
+    /// * `base_addr` - The position of the ELF header in `r`
+    pub fn load_from_memory_image<R: Read + Seek>(
+        r: &mut R,
+        base_addr: u64,
+    ) -> Result<Self, UnpackError> {
+        r.seek(SeekFrom::Start(base_addr))?;
+        let mut header = Header::unpack(r, false)?;
+
+        let big_endian = header.ident.is_big_endian();
+        let class = header.ident.class;
+
+        r.seek(SeekFrom::Start(base_addr.wrapping_add(header.ph_offset)))?;
+        let mut program_headers = Vec::new();
+        for _ in 0..header.program_header_count() {
+            program_headers.push(ProgramHeader::unpack_class(r, big_endian, class)?);
+        }
+
+        // The header is mapped at the address of file offset 0
+        let image_base = program_headers
+            .iter()
+            .filter(|p| p.ty == ProgramHeaderType::Loadable)
+            .min_by_key(|p| p.virtual_addr)
+            .map_or(0, |p| p.virtual_addr.wrapping_sub(p.offset));
+        let bias = base_addr.wrapping_sub(image_base);
+
+        for program_header in &mut program_headers {
+            program_header.data = Blob::load(
+                r,
+                program_header.virtual_addr.wrapping_add(bias),
+                program_header.file_size as usize,
+            )?;
+        }
+
+        header.set_section_header_table(0, 0, 0);
+
+        Ok(Self {
+            header,
+            program_headers,
+            section_headers: Vec::new(),
         })
     }
 
+    /// Loads the headers of the ELF file at `path`, see [ELFFile::load]
+    /// # Arguments
+    /// * `path` - The path of the file to load
+    pub fn load_from_path(path: impl AsRef<Path>) -> Result<ELFFile, UnpackError> {
+        Self::load(&mut BufReader::new(File::open(path)?))
+    }
+
+    /// Loads the ELF file at `path` including all data, see [ELFFile::load_fully]
+    /// # Arguments
+    /// * `path` - The path of the file to load
+    pub fn load_fully_from_path(path: impl AsRef<Path>) -> Result<ELFFile, UnpackError> {
+        Self::load_fully(&mut BufReader::new(File::open(path)?))
+    }
+
+    /// Writes this ELF file to `path`, replacing any existing file
+    /// # Arguments
+    /// * `path` - The path of the file to write
+    pub fn save_to_path(&self, path: impl AsRef<Path>) -> Result<(), io::Error> {
+        let mut w = BufWriter::new(File::create(path)?);
+
+        self.write(&mut w)?;
+        w.flush()
+    }
+
+    /// Loads the headers of an ELF file from memory, see [ELFFile::load]
+    /// # Arguments
+    /// * `data` - The contents of the ELF file
+    pub fn from_bytes(data: &[u8]) -> Result<ELFFile, UnpackError> {
+        Self::load(&mut Cursor::new(data))
+    }
+
+    /// Loads an ELF file from memory including all data, see [ELFFile::load_fully]
+    /// # Arguments
+    /// * `data` - The contents of the ELF file
+    pub fn from_bytes_fully(data: &[u8]) -> Result<ELFFile, UnpackError> {
+        Self::load_fully(&mut Cursor::new(data))
+    }
+
+    /// Writes this ELF file to a new buffer, see [ELFFile::write]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, io::Error> {
+        let mut w = Cursor::new(Vec::new());
+
+        self.write(&mut w)?;
+
+        Ok(w.into_inner())
+    }
+
+    /// Writes this ELF file to the provided stream
+    ///
+    /// All headers and blobs are written at their recorded offsets. Section
+    /// data is written after segment data, so modified sections take
+    /// precedence over the segments containing them. The header table
+    /// counts are taken from the amount of headers in this file, using the
+    /// extended numbering (`PN_XNUM`, `SHN_XINDEX`) if there are too many headers
+    /// # Arguments
+    /// * `w` - The stream to write to
+    /// # Returns
+    /// An error of kind [io::ErrorKind::InvalidData] if the data of two sections overlaps
+    /// or there are too many program headers and no null section to record their count
+    pub fn write<W: Write + Seek>(&self, w: &mut W) -> Result<(), io::Error> {
+        self.check_section_overlaps()?;
+
+        let big_endian = self.header.ident.is_big_endian();
+        let class = self.header.ident.class;
+
+        let mut header = self.header.clone();
+        let null_info =
+            header.set_program_header_table(header.ph_offset, self.program_headers.len());
+        if null_info != 0 && self.section_headers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "too many program headers to record without a section header table",
+            ));
+        }
+
+        let (null_size, null_link) = header.set_section_header_table(
+            header.sh_offset,
+            self.section_headers.len(),
+            self.section_names_index().unwrap_or(0),
+        );
+
+        for program_header in &self.program_headers {
+            program_header.data.write(w, program_header.offset)?;
+        }
+
+        for section in &self.section_headers {
+            if section.ty != SectionType::NoBits {
+                section.data.write(w, section.offset)?;
+            }
+        }
+
+        // The header goes after the data, as the first segment usually covers it
+        w.seek(SeekFrom::Start(0))?;
+        header.pack(w, big_endian)?;
+
+        w.seek(SeekFrom::Start(header.sh_offset))?;
+        for (index, section) in self.section_headers.iter().enumerate() {
+            let mut section = section.clone();
+            if index == 0 {
+                section.size = null_size;
+                section.link = null_link;
+                section.info = null_info;
+            }
+            section.pack_class(w, big_endian, class)?;
+        }
+
+        w.seek(SeekFrom::Start(header.ph_offset))?;
+        for program_header in &self.program_headers {
+            program_header.clone().pack_class(w, big_endian, class)?;
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that the data of no two sections overlaps in the file
+    fn check_section_overlaps(&self) -> Result<(), io::Error> {
+        let mut ranges: Vec<(u64, u64, usize)> = self
+            .section_headers
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.ty != SectionType::NoBits && !s.data.blob.is_empty())
+            .map(
+                |(i, s)| match s.offset.checked_add(s.data.blob.len() as u64) {
+                    Some(end) => Ok((s.offset, end, i)),
+                    None => Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("the data of section {} exceeds the address space", i),
+                    )),
+                },
+            )
+            .collect::<Result<_, _>>()?;
+        ranges.sort();
+
+        for pair in ranges.windows(2) {
+            let (_, end, a) = pair[0];
+            let (start, _, b) = pair[1];
+
+            if start < end {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("the data of sections {} and {} overlaps", a, b),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Formats the headers of this file in the form of `readelf -h -l -S`, see [Display]
+    pub fn dump_headers(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the type of this ELF file
+    pub fn elf_type(&self) -> ELFType {
+        self.header.ty
+    }
+
+    /// Returns the path of the program interpreter (`PT_INTERP`), normally the dynamic linker
+    ///
+    /// The `.interp` section is used if there is no interpreter segment
+    /// # Returns
+    /// `None` if there is neither an interpreter segment nor section (e.g. static
+    /// executables and shared libraries), an error if the path is not a
+    /// null terminated UTF-8 string
+    pub fn interpreter(&self) -> Result<Option<String>, UnpackError> {
+        Ok(self.interpreter_str()?.map(str::to_owned))
+    }
+
+    /// Returns the path of the program interpreter (`PT_INTERP`), normally the dynamic linker
+    /// # Returns
+    /// `None` if there is no interpreter or it is not a null terminated UTF-8 string
+    #[deprecated(note = "use interpreter")]
+    pub fn get_interpreter(&self) -> Option<&str> {
+        self.interpreter_str().ok().flatten()
+    }
+
+    /// Returns the path of the program interpreter, borrowed from its segment or section
+    fn interpreter_str(&self) -> Result<Option<&str>, UnpackError> {
+        let data = match self.programs_of_type(ProgramHeaderType::Interpreter).next() {
+            Some((_, segment)) => &segment.data,
+            None => match self.find_section(".interp") {
+                Some(section) => &section.data,
+                None => return Ok(None),
+            },
+        };
+
+        Ok(Some(StringTable::from(data).get(0)?))
+    }
+
+    /// Returns all notes of this file
+    ///
+    /// The notes are read from all `SHT_NOTE` sections, or from all
+    /// `PT_NOTE` segments if there are none (e.g. in stripped or core files)
+    pub fn notes(&self) -> Result<Vec<Note>, UnpackError> {
+        let big_endian = self.header.ident.is_big_endian();
+
+        let sections: Vec<NoteIter> = self
+            .sections_of_type(SectionType::Note)
+            .map(|(_, s)| s.iter_notes(big_endian))
+            .collect();
+
+        if !sections.is_empty() {
+            return sections.into_iter().flatten().collect();
+        }
+
+        self.programs_of_type(ProgramHeaderType::Note)
+            .flat_map(|(_, p)| p.iter_notes(big_endian))
+            .collect()
+    }
+
+    /// Returns the GNU build id (`NT_GNU_BUILD_ID`) of this file
+    ///
+    /// Note sections are preferred over `PT_NOTE` segments, see [ELFFile::notes]
+    /// # Returns
+    /// `None` if there is no build id note or the notes are malformed
+    pub fn build_id(&self) -> Option<Vec<u8>> {
+        self.notes()
+            .ok()?
+            .into_iter()
+            .find(|n| n.gnu_type() == Some(NoteType::GnuBuildId))
+            .map(|n| n.desc)
+    }
+
+    /// Returns the GNU build id of this file as a lowercase hex string,
+    /// as used in `/usr/lib/debug/.build-id/` paths
+    ///
+    /// Build ids of any length are supported (e.g. `20` byte SHA1 or `16` byte MD5/UUID)
+    /// # Returns
+    /// `None` if there is no build id note, see [ELFFile::build_id]
+    pub fn build_id_hex(&self) -> Option<String> {
+        let id = self.build_id()?;
+        Some(id.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+
+    /// Returns the operating system and minimum kernel version
+    /// from the `NT_GNU_ABI_TAG` note of this file
+    /// # Returns
+    /// `None` if there is no ABI tag note or the notes are malformed
+    pub fn abi_tag(&self) -> Option<AbiTag> {
+        let note = self
+            .notes()
+            .ok()?
+            .into_iter()
+            .find(|n| n.gnu_type() == Some(NoteType::GnuAbiTag))?;
+
+        AbiTag::from_desc(&note.desc, self.header.ident.is_big_endian()).ok()
+    }
+
+    /// Returns the program properties from all `NT_GNU_PROPERTY_TYPE_0` notes
+    /// (`.note.gnu.property`) of this file, decoded for its machine
+    pub fn gnu_properties(&self) -> Result<Vec<GnuProperty>, UnpackError> {
+        let mut res = Vec::new();
+
+        for note in self.notes()? {
+            if note.gnu_type() == Some(NoteType::GnuProperty) {
+                res.extend(GnuProperty::parse(
+                    &note.desc,
+                    self.header.ident.is_big_endian(),
+                    self.header.ident.class,
+                    self.header.machine,
+                )?);
+            }
+        }
+
+        Ok(res)
+    }
+
     /// Gets a string by offset from the `.shstrtab` section
     /// # Arguments
     /// * `offset` - The offset into the binary data of the section
@@ -42,18 +425,741 @@ impl ELFFile {
     /// `None` if the section table is not found or invalid or `offset`
     /// is out of bounds
     pub fn get_sh_string(&self, offset: usize) -> Option<String> {
-        let section_names = &self
+        self.section_names()
+            .ok()?
+            .get(u32::try_from(offset).ok()?)
+            .ok()
+            .map(|s| s.to_owned())
+    }
+
+    /// Iterates over the entries of the dynamic table
+    ///
+    /// The `PT_DYNAMIC` segment is used if its data is loaded, the `SHT_DYNAMIC` section otherwise.
+    /// Processor specific tags are decoded for the machine of this file
+    /// # Returns
+    /// `None` if the file has no dynamic table
+    pub fn iter_dynamic(&self) -> Option<DynamicIter<'_>> {
+        let class = self.header.ident.class;
+        let big_endian = self.header.ident.is_big_endian();
+
+        if let Some(segment) = self
+            .program_headers
+            .iter()
+            .find(|p| p.ty == ProgramHeaderType::Dynamic)
+            .filter(|p| {
+                p.data.len() as u64 == p.file_size
+                    || !self
+                        .section_headers
+                        .iter()
+                        .any(|s| s.ty == SectionType::Dynamic && s.data.len() as u64 == s.size)
+            })
+        {
+            return Some(
+                segment
+                    .iter_dynamic(class, big_endian)
+                    .with_machine(self.header.machine),
+            );
+        }
+
+        self.section_headers
+            .iter()
+            .find(|s| s.ty == SectionType::Dynamic)
+            .map(|s| {
+                s.iter_dynamic(class, big_endian)
+                    .with_machine(self.header.machine)
+            })
+    }
+
+    /// Returns the value of the first dynamic entry with `tag`
+    /// # Arguments
+    /// * `tag` - The tag to search for
+    pub fn find_dynamic(&self, tag: DynamicTag) -> Result<Option<u64>, UnpackError> {
+        match self.iter_dynamic() {
+            Some(iter) => iter.find_tag(tag),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the `DT_FLAGS` of this file, empty if there are none
+    pub fn dynamic_flags(&self) -> Result<DynamicFlags, UnpackError> {
+        Ok(DynamicFlags(
+            self.find_dynamic(DynamicTag::Flags)?.unwrap_or_default(),
+        ))
+    }
+
+    /// Returns the `DT_FLAGS_1` of this file, empty if there are none
+    pub fn dynamic_flags_1(&self) -> Result<DynamicFlags1, UnpackError> {
+        Ok(DynamicFlags1(
+            self.find_dynamic(DynamicTag::Flags1)?.unwrap_or_default(),
+        ))
+    }
+
+    /// Returns whether the dynamic linker is asked to bind all symbols at load time
+    ///
+    /// This checks `DT_BIND_NOW`, `DF_BIND_NOW` and `DF_1_NOW`
+    pub fn bind_now(&self) -> Result<bool, UnpackError> {
+        Ok(self.find_dynamic(DynamicTag::BindNow)?.is_some()
+            || self.dynamic_flags()?.bind_now()
+            || self.dynamic_flags_1()?.bind_now())
+    }
+
+    /// Returns whether the file is flagged as a position independent executable (`DF_1_PIE`)
+    pub fn is_pie_flagged(&self) -> Result<bool, UnpackError> {
+        Ok(self.dynamic_flags_1()?.is_pie_flagged())
+    }
+
+    /// Returns the names of the shared libraries this file depends on (`DT_NEEDED`)
+    ///
+    /// The names are resolved through the `DT_STRTAB` string table and
+    /// returned in the order they appear in the dynamic table
+    /// # Returns
+    /// An empty list for files without a dynamic table (e.g. static executables)
+    pub fn needed_libraries(&self) -> Result<Vec<String>, UnpackError> {
+        let Some(iter) = self.iter_dynamic() else {
+            return Ok(Vec::new());
+        };
+
+        let mut offsets = Vec::new();
+        for entry in iter {
+            let entry = entry?;
+            if entry.tag == DynamicTag::Needed {
+                offsets.push(entry.value);
+            }
+        }
+
+        if offsets.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let strings = self.dynamic_string_table()?;
+
+        offsets
+            .into_iter()
+            .map(|offset| Self::resolve_string(&strings, offset))
+            .collect()
+    }
+
+    /// Returns the name of this shared object (`DT_SONAME`)
+    ///
+    /// Only shared objects (`ET_DYN`) are searched. Position independent
+    /// executables are `ET_DYN` as well and report a `DT_SONAME` if they have one
+    /// # Returns
+    /// `None` if the file is not a shared object or has no `DT_SONAME` entry,
+    /// an error if the dynamic table or its string table is corrupt
+    pub fn soname(&self) -> Result<Option<String>, UnpackError> {
+        if self.elf_type() != ELFType::SharedObject {
+            return Ok(None);
+        }
+
+        self.find_dynamic_string(DynamicTag::SoName)
+    }
+
+    /// Returns the deprecated library search path (`DT_RPATH`)
+    /// # Returns
+    /// `None` if the file has no `DT_RPATH` entry
+    pub fn rpath(&self) -> Result<Option<String>, UnpackError> {
+        self.find_dynamic_string(DynamicTag::RPath)
+    }
+
+    /// Returns the library search path (`DT_RUNPATH`)
+    /// # Returns
+    /// `None` if the file has no `DT_RUNPATH` entry
+    pub fn runpath(&self) -> Result<Option<String>, UnpackError> {
+        self.find_dynamic_string(DynamicTag::RunPath)
+    }
+
+    /// Returns the directories of the `DT_RPATH` search path
+    /// # Returns
+    /// An empty list if the file has no `DT_RPATH` entry
+    pub fn rpath_entries(&self) -> Result<Vec<PathBuf>, UnpackError> {
+        Ok(Self::split_search_path(self.rpath()?))
+    }
+
+    /// Returns the directories of the `DT_RUNPATH` search path
+    /// # Returns
+    /// An empty list if the file has no `DT_RUNPATH` entry
+    pub fn runpath_entries(&self) -> Result<Vec<PathBuf>, UnpackError> {
+        Ok(Self::split_search_path(self.runpath()?))
+    }
+
+    /// Returns the directories the dynamic loader searches for the dependencies of this file
+    ///
+    /// `DT_RUNPATH` takes precedence, `DT_RPATH` is only used if there is none.
+    /// Dynamic string tokens such as `$ORIGIN` are left untouched,
+    /// see [ELFFile::effective_library_search_path_with]
+    pub fn effective_library_search_path(&self) -> Result<Vec<PathBuf>, UnpackError> {
+        self.effective_library_search_path_with(|_| None)
+    }
+
+    /// Returns the directories the dynamic loader searches for the dependencies
+    /// of this file, expanding dynamic string tokens through `expand`
+    ///
+    /// Tokens are written as `$NAME` or `${NAME}` (e.g. `$ORIGIN`, `${LIB}`)
+    /// # Arguments
+    /// * `expand` - Returns the value of the token named by its argument, `None` to keep the token
+    pub fn effective_library_search_path_with<F: FnMut(&str) -> Option<String>>(
+        &self,
+        mut expand: F,
+    ) -> Result<Vec<PathBuf>, UnpackError> {
+        let path = match self.runpath()? {
+            Some(runpath) => Some(runpath),
+            None => self.rpath()?,
+        };
+
+        Ok(Self::split_search_path(path)
+            .into_iter()
+            .map(|entry| match entry.to_str() {
+                Some(entry) => PathBuf::from(Self::expand_tokens(entry, &mut expand)),
+                None => entry,
+            })
+            .collect())
+    }
+
+    /// Expands the `$NAME` and `${NAME}` tokens in `entry` through `expand`
+    fn expand_tokens<F: FnMut(&str) -> Option<String>>(entry: &str, expand: &mut F) -> String {
+        let mut res = String::new();
+        let mut rest = entry;
+
+        while let Some(start) = rest.find('$') {
+            res.push_str(&rest[..start]);
+            let token = &rest[start + 1..];
+
+            let (name, len) = match token.strip_prefix('{') {
+                Some(braced) => match braced.find('}') {
+                    Some(end) => (&braced[..end], end + 2),
+                    None => ("", 0),
+                },
+                None => {
+                    let end = token
+                        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .unwrap_or(token.len());
+                    (&token[..end], end)
+                }
+            };
+
+            match (!name.is_empty()).then(|| expand(name)).flatten() {
+                Some(value) => res.push_str(&value),
+                None => res.push_str(&rest[start..start + 1 + len]),
+            }
+
+            rest = &token[len..];
+        }
+
+        res.push_str(rest);
+        res
+    }
+
+    /// Splits a colon separated search path into its directories
+    fn split_search_path(path: Option<String>) -> Vec<PathBuf> {
+        match path {
+            Some(path) => path.split(':').map(PathBuf::from).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the string of the first dynamic entry with `tag` from the `DT_STRTAB` string table
+    /// # Arguments
+    /// * `tag` - The tag to search for
+    fn find_dynamic_string(&self, tag: DynamicTag) -> Result<Option<String>, UnpackError> {
+        let Some(offset) = self.find_dynamic(tag)? else {
+            return Ok(None);
+        };
+
+        let strings = self.dynamic_string_table()?;
+
+        Self::resolve_string(&strings, offset).map(Some)
+    }
+
+    /// Returns the string table referenced by `DT_STRTAB`
+    ///
+    /// The table is located through [ELFFile::dynamic_data] and bounded
+    /// by `DT_STRSZ` if there is no section for it
+    fn dynamic_string_table(&self) -> Result<StringTable<'_>, UnpackError> {
+        let address = self
+            .find_dynamic(DynamicTag::StrTab)?
+            .ok_or(UnpackError::MissingStringTable)?;
+
+        let data = self
+            .dynamic_data(address, SectionType::StringTable)
+            .ok_or(UnpackError::MissingStringTable)?;
+
+        let data = match self.find_dynamic(DynamicTag::StrSize)? {
+            Some(size) => &data[..data.len().min(size as usize)],
+            None => data,
+        };
+
+        Ok(StringTable::new(data))
+    }
+
+    /// Returns the data at `address` referenced by a dynamic entry
+    ///
+    /// The section of type `ty` at `address` is used if there is one and its data
+    /// is loaded. Otherwise (e.g. the section headers were stripped), the address is
+    /// mapped through the `PT_LOAD` segments and the data extends to the end of the segment
+    /// # Arguments
+    /// * `address` - The virtual address of the data
+    /// * `ty` - The type of the section holding the data
+    pub(crate) fn dynamic_data(&self, address: u64, ty: SectionType) -> Option<&[u8]> {
+        if let Some(section) = self
+            .section_headers
+            .iter()
+            .find(|s| s.ty == ty && s.address == address)
+            .filter(|s| s.data.len() as u64 == s.size)
+        {
+            return Some(&section.data);
+        }
+
+        self.programs_of_type(ProgramHeaderType::Loadable)
+            .find_map(|(_, p)| {
+                let start = address.checked_sub(p.virtual_addr)?;
+                p.data.get(usize::try_from(start).ok()?..)
+            })
+    }
+
+    /// Returns the number of symbols in the dynamic symbol table
+    ///
+    /// The count is taken from the `DT_HASH` or `DT_GNU_HASH` hash table,
+    /// which is the only way to size the table without section headers
+    /// # Returns
+    /// `None` if the file has no hash table
+    pub fn dynamic_symbol_count(&self) -> Result<Option<usize>, UnpackError> {
+        let class = self.header.ident.class;
+        let big_endian = self.header.ident.is_big_endian();
+
+        if let Some(address) = self.find_dynamic(DynamicTag::Hash)? {
+            let data = self
+                .dynamic_data(address, SectionType::Hash)
+                .ok_or(UnpackError::UnmappedAddress { address })?;
+            let table = SysvHashTable::from_blob(&Blob::from(data), big_endian)?;
+
+            return Ok(Some(table.symbol_count()));
+        }
+
+        if let Some(address) = self.find_dynamic(DynamicTag::GnuHash)? {
+            let data = self
+                .dynamic_data(address, SectionType::GnuHash)
+                .ok_or(UnpackError::UnmappedAddress { address })?;
+            let table = GnuHashTable::from_blob(&Blob::from(data), class, big_endian)?;
+
+            return Ok(Some(table.symbol_count()));
+        }
+
+        Ok(None)
+    }
+
+    /// Resolves the string at `offset` in `strings` to an owned string
+    fn resolve_string(strings: &StringTable, offset: u64) -> Result<String, UnpackError> {
+        match u32::try_from(offset) {
+            Ok(offset) => strings.get(offset).map(|s| s.to_owned()),
+            Err(_) => Err(UnpackError::InvalidString { offset }),
+        }
+    }
+
+    /// Returns the name of the section at `index` from the section name string table
+    ///
+    /// This requires the data of the section name string table to be loaded
+    /// # Arguments
+    /// * `index` - The index of the section
+    pub fn section_name(&self, index: usize) -> Result<&str, UnpackError> {
+        let section = self
+            .section_headers
+            .get(index)
+            .ok_or(UnpackError::InvalidSectionIndex { index })?;
+
+        self.section_names()?.get(section.name)
+    }
+
+    /// Returns all sections along with their names, see [ELFFile::section_name]
+    pub fn named_sections(&self) -> Result<Vec<(&str, &SectionHeader)>, UnpackError> {
+        let names = self.section_names()?;
+
+        self.section_headers
+            .iter()
+            .map(|s| Ok((names.get(s.name)?, s)))
+            .collect()
+    }
+
+    /// Returns the section name string table (`.shstrtab`)
+    ///
+    /// Its index is stored in the link of the first section if it is `SHN_XINDEX`
+    fn section_names(&self) -> Result<StringTable<'_>, UnpackError> {
+        let index = self
+            .section_names_index()
+            .ok_or(UnpackError::MissingStringTable)?;
+
+        let section = self
             .section_headers
-            .get(self.header.sh_str_index as usize)?;
+            .get(index)
+            .ok_or(UnpackError::MissingStringTable)?;
 
-        if offset > section_names.size as usize {
-            return None;
+        if section.data.len() as u64 != section.size {
+            return Err(UnpackError::SectionNotLoaded { index });
         }
 
-        let ptr = section_names.data.blob.as_ptr();
+        Ok(StringTable::from(&section.data))
+    }
+
+    /// Returns the index of the section name string table, if there is one
+    pub(crate) fn section_names_index(&self) -> Option<usize> {
+        match SectionIndex::from(self.header.sh_str_index) {
+            SectionIndex::Undefined => None,
+            SectionIndex::XIndex => Some(self.section_headers.first()?.link as usize),
+            _ => Some(self.header.sh_str_index as usize),
+        }
+    }
+
+    /// Finds the index of the first section named `name`
+    ///
+    /// The empty name never matches, so the null section is not found
+    /// # Arguments
+    /// * `name` - The name of the section to search for (e.g. `.text`)
+    /// # Returns
+    /// `None` if there is no such section or the section names can not be resolved
+    pub fn section_index_by_name(&self, name: &str) -> Option<usize> {
+        self.find_section_all(name).first().map(|(index, _)| *index)
+    }
+
+    /// Finds the first section named `name`, see [ELFFile::section_index_by_name]
+    /// # Arguments
+    /// * `name` - The name of the section to search for (e.g. `.text`)
+    pub fn find_section<'a>(&'a self, name: &str) -> Option<&'a SectionHeader> {
+        let index = self.section_index_by_name(name)?;
+        self.section_headers.get(index)
+    }
+
+    /// Finds the first section named `name` for modification, see [ELFFile::section_index_by_name]
+    /// # Arguments
+    /// * `name` - The name of the section to search for (e.g. `.text`)
+    pub fn find_section_mut<'a>(&'a mut self, name: &str) -> Option<&'a mut SectionHeader> {
+        let index = self.section_index_by_name(name)?;
+        self.section_headers.get_mut(index)
+    }
+
+    /// Finds the index of the first section named `name`, see [ELFFile::section_index_by_name]
+    /// # Arguments
+    /// * `name` - The name of the section to search for (e.g. `.text`)
+    pub fn find_section_index_by_name(&self, name: &str) -> Option<usize> {
+        self.section_index_by_name(name)
+    }
+
+    /// Finds the first section named `name`, see [ELFFile::find_section]
+    /// # Arguments
+    /// * `name` - The name of the section to search for (e.g. `.text`)
+    pub fn find_section_by_name<'a>(&'a self, name: &str) -> Option<&'a SectionHeader> {
+        self.find_section(name)
+    }
+
+    /// Finds the first section named `name` for modification, see [ELFFile::find_section_mut]
+    /// # Arguments
+    /// * `name` - The name of the section to search for (e.g. `.text`)
+    pub fn find_section_by_name_mut<'a>(&'a mut self, name: &str) -> Option<&'a mut SectionHeader> {
+        self.find_section_mut(name)
+    }
+
+    /// Finds all sections named `name` along with their indices
+    ///
+    /// The empty name never matches, so the null section is not found
+    /// # Arguments
+    /// * `name` - The name of the sections to search for (e.g. `.text`)
+    /// # Returns
+    /// An empty list if there is no such section or the section names can not be resolved
+    pub fn find_section_all<'a>(&'a self, name: &str) -> Vec<(usize, &'a SectionHeader)> {
+        let Ok(names) = self.section_names() else {
+            return Vec::new();
+        };
+
+        if name.is_empty() {
+            return Vec::new();
+        }
+
+        self.section_headers
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| names.get(s.name).ok() == Some(name))
+            .collect()
+    }
+
+    /// Converts a virtual address to the file offset it is loaded from
+    /// # Arguments
+    /// * `vaddr` - The virtual address to convert
+    /// # Returns
+    /// `None` if no `PT_LOAD` segment maps `vaddr` from the file,
+    /// which includes the zero-filled memory behind the file data
+    pub fn virtual_address_to_offset(&self, vaddr: u64) -> Option<u64> {
+        self.programs_of_type(ProgramHeaderType::Loadable)
+            .find_map(|(_, p)| {
+                let delta = vaddr.checked_sub(p.virtual_addr)?;
+                (delta < p.file_size)
+                    .then_some(delta)?
+                    .checked_add(p.offset)
+            })
+    }
+
+    /// Returns the sum of the memory sizes of all `PT_LOAD` segments
+    pub fn total_load_size(&self) -> u64 {
+        self.programs_of_type(ProgramHeaderType::Loadable)
+            .fold(0u64, |sum, (_, p)| sum.saturating_add(p.mem_size))
+    }
+
+    /// Returns the lowest and the end of the highest virtual address spanned by
+    /// the `PT_LOAD` segments, as the range `start..end`
+    /// # Returns
+    /// `None` if there are no loadable segments
+    pub fn virtual_address_range(&self) -> Option<(u64, u64)> {
+        self.programs_of_type(ProgramHeaderType::Loadable)
+            .map(|(_, p)| (p.virtual_addr, p.virtual_addr.saturating_add(p.mem_size)))
+            .reduce(|(start, end), (s, e)| (start.min(s), end.max(e)))
+    }
+
+    /// Returns the lowest virtual address of the `PT_LOAD` segments
+    ///
+    /// This is the address the file is loaded to for non-PIE executables,
+    /// position independent files are relocated to a base chosen at load time
+    /// # Returns
+    /// `None` if there are no loadable segments
+    pub fn static_base_address(&self) -> Option<u64> {
+        self.virtual_address_range().map(|(start, _)| start)
+    }
+
+    /// Finds the first `PT_LOAD` segment whose memory contains `vaddr`
+    /// # Arguments
+    /// * `vaddr` - The virtual address to search for
+    pub fn segment_containing_address(&self, vaddr: u64) -> Option<(usize, &ProgramHeader)> {
+        self.programs_of_type(ProgramHeaderType::Loadable)
+            .find(|(_, p)| {
+                vaddr
+                    .checked_sub(p.virtual_addr)
+                    .is_some_and(|delta| delta < p.mem_size)
+            })
+    }
+
+    /// Finds the first allocated section (`SHF_ALLOC`) whose memory contains `vaddr`
+    ///
+    /// Thread-local `SHT_NOBITS` sections (`.tbss`) are skipped, as they
+    /// occupy no memory at their address
+    /// # Arguments
+    /// * `vaddr` - The virtual address to search for
+    pub fn section_containing_address(&self, vaddr: u64) -> Option<(usize, &SectionHeader)> {
+        self.section_headers
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.flags.is_allocated())
+            .filter(|(_, s)| !(s.flags.is_tls() && s.ty == SectionType::NoBits))
+            .find(|(_, s)| {
+                vaddr
+                    .checked_sub(s.address)
+                    .is_some_and(|delta| delta < s.size)
+            })
+    }
+
+    /// Converts a file offset to the virtual address it is loaded to
+    /// # Arguments
+    /// * `offset` - The file offset to convert
+    /// # Returns
+    /// `None` if no `PT_LOAD` segment maps `offset` into memory
+    pub fn offset_to_virtual_address(&self, offset: u64) -> Option<u64> {
+        self.programs_of_type(ProgramHeaderType::Loadable)
+            .find_map(|(_, p)| {
+                let delta = offset.checked_sub(p.offset)?;
+                (delta < p.file_size)
+                    .then_some(delta)?
+                    .checked_add(p.virtual_addr)
+            })
+    }
+
+    /// Returns the section at `index` with its data, reading it from `r` if it is not loaded
+    pub(crate) fn section_with_data<R: Read + Seek>(
+        &self,
+        r: &mut R,
+        index: usize,
+    ) -> Result<Cow<'_, SectionHeader>, UnpackError> {
+        let section = self
+            .section_headers
+            .get(index)
+            .ok_or(UnpackError::InvalidSectionIndex { index })?;
+
+        if !section.has_file_data() || section.data.len() as u64 == section.size {
+            return Ok(Cow::Borrowed(section));
+        }
+
+        let mut section = section.clone();
+        section.data = Blob::load(r, section.offset, section.size as usize)?;
+
+        Ok(Cow::Owned(section))
+    }
+
+    /// Returns whether this file has neither a symbol table (`SHT_SYMTAB`)
+    /// nor debug sections (`.debug_*`)
+    ///
+    /// This works on headers-only loads, see [ELFFile::load]
+    pub fn is_stripped(&self) -> bool {
+        if self
+            .sections_of_type(SectionType::SymbolTable)
+            .next()
+            .is_some()
+        {
+            return false;
+        }
+
+        match self.named_sections() {
+            Ok(sections) => !sections.iter().any(|(n, _)| n.starts_with(".debug_")),
+            Err(_) => true,
+        }
+    }
+
+    /// Returns whether this file contains DWARF debug information (`.debug_info`)
+    ///
+    /// This works on headers-only loads, see [ELFFile::load]
+    pub fn has_debug_info(&self) -> bool {
+        self.find_section(".debug_info").is_some()
+    }
+
+    /// Iterates over all sections of type `ty` along with their indices
+    /// # Arguments
+    /// * `ty` - The type of section to search for
+    pub fn sections_of_type(
+        &self,
+        ty: SectionType,
+    ) -> impl Iterator<Item = (usize, &SectionHeader)> + '_ {
+        self.section_headers
+            .iter()
+            .enumerate()
+            .filter(move |(_, s)| s.ty == ty)
+    }
+
+    /// Iterates over all program headers of type `ty` along with their indices
+    /// # Arguments
+    /// * `ty` - The type of program header to search for
+    pub fn programs_of_type(
+        &self,
+        ty: ProgramHeaderType,
+    ) -> impl Iterator<Item = (usize, &ProgramHeader)> + '_ {
+        self.program_headers
+            .iter()
+            .enumerate()
+            .filter(move |(_, p)| p.ty == ty)
+    }
+
+    /// Gets a string by offset from a string table section
+    /// # Arguments
+    /// * `section` - The index of the string table section
+    /// * `offset` - The offset into the binary data of the section
+    /// # Returns
+    /// `None` if the section is not found or `offset` is out of bounds
+    pub fn get_string(&self, section: usize, offset: usize) -> Option<String> {
+        let strings = StringTable::from(&self.section_headers.get(section)?.data);
+
+        strings
+            .get(u32::try_from(offset).ok()?)
+            .ok()
+            .map(|s| s.to_owned())
+    }
+
+    /// Resolves the section `symbol` is defined in
+    /// # Arguments
+    /// * `symbol` - The symbol to resolve the section of
+    pub fn section_for_symbol(&self, symbol: &Symbol) -> SymbolSection<'_> {
+        match symbol.section() {
+            SectionIndex::Undefined => SymbolSection::Undefined,
+            SectionIndex::Abs => SymbolSection::Abs,
+            SectionIndex::Common => SymbolSection::Common,
+            SectionIndex::Normal(index) => match self.section_headers.get(index as usize) {
+                Some(section) => SymbolSection::Section(section),
+                None => SymbolSection::Unresolved(SectionIndex::Normal(index)),
+            },
+            index => SymbolSection::Unresolved(index),
+        }
+    }
+
+    /// Resolves the section the symbol at `index` in `table` is defined in
+    ///
+    /// Unlike [ELFFile::section_for_symbol], this resolves [SectionIndex::XIndex]
+    /// through the extended section indices of the table
+    /// # Arguments
+    /// * `table` - The symbol table, see [ELFFile::read_symbol_table]
+    /// * `index` - The index of the symbol in the table
+    pub fn section_for_table_symbol(&self, table: &SymbolTable, index: usize) -> SymbolSection<'_> {
+        let Some(symbol) = table.get(index) else {
+            return SymbolSection::Unresolved(SectionIndex::Undefined);
+        };
+
+        if symbol.section() != SectionIndex::XIndex {
+            return self.section_for_symbol(symbol);
+        }
+
+        match table
+            .section_index(index)
+            .and_then(|i| self.section_headers.get(i as usize))
+        {
+            Some(section) => SymbolSection::Section(section),
+            None => SymbolSection::Unresolved(SectionIndex::XIndex),
+        }
+    }
+
+    /// Resolves the name of a symbol through the string table linked to its symbol table
+    /// # Arguments
+    /// * `table` - The symbol table section `symbol` was read from
+    /// * `symbol` - The symbol to resolve the name of
+    pub fn symbol_name(&self, table: &SectionHeader, symbol: &Symbol) -> Option<String> {
+        self.get_string(table.link as usize, symbol.name_offset as usize)
+    }
+}
+
+/// Displays the ELF header, the program headers and the section headers
+/// in the form of `readelf -h -l -S`
+impl Display for ELFFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.header)?;
+
+        writeln!(f, "\nProgram Headers:")?;
+        writeln!(
+            f,
+            "  {:<14} {:<8} {:<18} {:<18} {:<8} {:<8} Flg Align",
+            "Type", "Offset", "VirtAddr", "PhysAddr", "FileSiz", "MemSiz"
+        )?;
+        for program in &self.program_headers {
+            writeln!(f, "  {}", program)?;
+        }
+
+        let names = self.section_names().ok();
+
+        writeln!(f, "\nSection Headers:")?;
+        writeln!(
+            f,
+            "  [Nr] {:<17} {:<15} {:<16} {:<6} {:<6} ES Flg Lk Inf Al",
+            "Name", "Type", "Address", "Off", "Size"
+        )?;
+        for (i, section) in self.section_headers.iter().enumerate() {
+            let name = names.and_then(|n| n.get(section.name).ok()).unwrap_or("?");
+            writeln!(f, "  [{:>2}] {:<17} {}", i, name, section)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for ELFFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ELFFile")
+            .field("header", &self.header)
+            .field("program_headers", &self.program_headers)
+            .field("section_headers", &NamedSectionsDebug(self))
+            .finish()
+    }
+}
+
+/// Lists the sections of an [ELFFile] keyed by their names for debug output
+struct NamedSectionsDebug<'a>(&'a ELFFile);
 
-        let s = unsafe { str_from_null_terminated(ptr.byte_add(offset)) };
+impl Debug for NamedSectionsDebug<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = self.0.section_names().ok();
 
-        Some(s.to_owned())
+        f.debug_map()
+            .entries(self.0.section_headers.iter().enumerate().map(|(i, s)| {
+                let name = names.and_then(|n| n.get(s.name).ok()).unwrap_or("?");
+                (format!("[{}] {}", i, name), s)
+            }))
+            .finish()
     }
 }
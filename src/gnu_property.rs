@@ -0,0 +1,209 @@
+use alloc::{vec, vec::Vec};
+use core::fmt::Display;
+
+use crate::{
+    align_up,
+    io::{Cursor, Read, Seek, SeekFrom},
+    Class, Machine, UnpackError, Unpackable, UnpackableClass,
+};
+
+/// A single program property of a `NT_GNU_PROPERTY_TYPE_0` note (`.note.gnu.property`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GnuProperty {
+    /// `GNU_PROPERTY_STACK_SIZE`: The stack size the program requires
+    StackSize(u64),
+    /// `GNU_PROPERTY_NO_COPY_ON_PROTECTED`: Protected data symbols must not be copy relocated
+    NoCopyOnProtected,
+    /// `GNU_PROPERTY_X86_FEATURE_1_AND`: The x86 features all objects support
+    X86Features(X86FeatureFlags),
+    /// `GNU_PROPERTY_X86_ISA_1_NEEDED`: The x86 ISA levels required to run the program
+    X86IsaNeeded(u32),
+    /// `GNU_PROPERTY_X86_ISA_1_USED`: The x86 ISA levels used by the program
+    X86IsaUsed(u32),
+    /// `GNU_PROPERTY_AARCH64_FEATURE_1_AND`: The AArch64 features all objects support
+    Aarch64Features(Aarch64FeatureFlags),
+    /// Any other property, with its raw data
+    Other {
+        /// The raw type of the property (`pr_type`)
+        ty: u32,
+        /// The data of the property, without padding
+        data: Vec<u8>,
+    },
+}
+
+impl GnuProperty {
+    /// Parses the property array in the descriptor of a `NT_GNU_PROPERTY_TYPE_0` note
+    ///
+    /// The processor specific properties are decoded depending on `machine`,
+    /// the properties are aligned to `8` bytes for ELF64 and `4` for ELF32
+    /// # Arguments
+    /// * `desc` - The descriptor of the note
+    /// * `big_endian` - Whether the file is big endian
+    /// * `class` - The class of the file
+    /// * `machine` - The machine of the file
+    pub fn parse(
+        desc: &[u8],
+        big_endian: bool,
+        class: Class,
+        machine: Machine,
+    ) -> Result<Vec<GnuProperty>, UnpackError> {
+        let align = match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+
+        let mut r = Cursor::new(desc);
+        let mut res = Vec::new();
+
+        while r.position() < desc.len() as u64 {
+            let ty = u32::unpack(&mut r, big_endian)?;
+            let size = u32::unpack(&mut r, big_endian)?;
+
+            let remaining = (desc.len() as u64).saturating_sub(r.position());
+            if size as u64 > remaining {
+                return Err(UnpackError::Truncated {
+                    size: size as u64,
+                    remaining,
+                });
+            }
+
+            let mut data = vec![0u8; size as usize];
+            r.read_exact(&mut data)?;
+
+            let position = align_up(r.position(), align).unwrap_or(u64::MAX);
+            r.seek(SeekFrom::Start(position))?;
+
+            res.push(Self::decode(ty, data, big_endian, class, machine));
+        }
+
+        Ok(res)
+    }
+
+    /// Decodes a single property, falling back to [GnuProperty::Other]
+    /// if the data does not match the type
+    fn decode(ty: u32, data: Vec<u8>, big_endian: bool, class: Class, machine: Machine) -> Self {
+        let word = || match data.len() {
+            4 => u32::unpack(&mut Cursor::new(&data), big_endian).ok(),
+            _ => None,
+        };
+
+        let property = match (ty, machine) {
+            (1, _) => u64::unpack_class(&mut Cursor::new(&data), big_endian, class)
+                .ok()
+                .map(Self::StackSize),
+            (2, _) if data.is_empty() => Some(Self::NoCopyOnProtected),
+            (0xc0000002, Machine::X86 | Machine::X86_64) => {
+                word().map(|w| Self::X86Features(X86FeatureFlags(w)))
+            }
+            (0xc0008002, Machine::X86 | Machine::X86_64) => word().map(Self::X86IsaNeeded),
+            (0xc0010002, Machine::X86 | Machine::X86_64) => word().map(Self::X86IsaUsed),
+            (0xc0000000, Machine::AArch64) => {
+                word().map(|w| Self::Aarch64Features(Aarch64FeatureFlags(w)))
+            }
+            _ => None,
+        };
+
+        property.unwrap_or(Self::Other { ty, data })
+    }
+}
+
+/// The x86 feature bits of `GNU_PROPERTY_X86_FEATURE_1_AND`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct X86FeatureFlags(pub u32);
+
+impl X86FeatureFlags {
+    /// `GNU_PROPERTY_X86_FEATURE_1_IBT`: Indirect branch tracking
+    pub const IBT: Self = Self(0x1);
+    /// `GNU_PROPERTY_X86_FEATURE_1_SHSTK`: Shadow stack
+    pub const SHSTK: Self = Self(0x2);
+    /// `GNU_PROPERTY_X86_FEATURE_1_LAM_U48`: Linear address masking with 48 bit addresses
+    pub const LAM_U48: Self = Self(0x4);
+    /// `GNU_PROPERTY_X86_FEATURE_1_LAM_U57`: Linear address masking with 57 bit addresses
+    pub const LAM_U57: Self = Self(0x8);
+
+    /// Returns whether all bits of `other` are set in `self`
+    /// # Arguments
+    /// * `other` - The flags to check for
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether indirect branch tracking is supported
+    pub fn ibt(&self) -> bool {
+        self.contains(Self::IBT)
+    }
+
+    /// Returns whether shadow stacks are supported
+    pub fn shstk(&self) -> bool {
+        self.contains(Self::SHSTK)
+    }
+}
+
+impl Display for X86FeatureFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let names = [
+            (Self::IBT, "IBT"),
+            (Self::SHSTK, "SHSTK"),
+            (Self::LAM_U48, "LAM_U48"),
+            (Self::LAM_U57, "LAM_U57"),
+        ];
+
+        let set: Vec<&str> = names
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| *name)
+            .collect();
+
+        match set.is_empty() {
+            true => write!(f, "<None>"),
+            false => write!(f, "{}", set.join(", ")),
+        }
+    }
+}
+
+/// The AArch64 feature bits of `GNU_PROPERTY_AARCH64_FEATURE_1_AND`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Aarch64FeatureFlags(pub u32);
+
+impl Aarch64FeatureFlags {
+    /// `GNU_PROPERTY_AARCH64_FEATURE_1_BTI`: Branch target identification
+    pub const BTI: Self = Self(0x1);
+    /// `GNU_PROPERTY_AARCH64_FEATURE_1_PAC`: Pointer authentication
+    pub const PAC: Self = Self(0x2);
+    /// `GNU_PROPERTY_AARCH64_FEATURE_1_GCS`: Guarded control stack
+    pub const GCS: Self = Self(0x4);
+
+    /// Returns whether all bits of `other` are set in `self`
+    /// # Arguments
+    /// * `other` - The flags to check for
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether branch target identification is supported
+    pub fn bti(&self) -> bool {
+        self.contains(Self::BTI)
+    }
+
+    /// Returns whether pointer authentication is supported
+    pub fn pac(&self) -> bool {
+        self.contains(Self::PAC)
+    }
+}
+
+impl Display for Aarch64FeatureFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let names = [(Self::BTI, "BTI"), (Self::PAC, "PAC"), (Self::GCS, "GCS")];
+
+        let set: Vec<&str> = names
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| *name)
+            .collect();
+
+        match set.is_empty() {
+            true => write!(f, "<None>"),
+            false => write!(f, "{}", set.join(", ")),
+        }
+    }
+}
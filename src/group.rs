@@ -0,0 +1,120 @@
+use alloc::vec::Vec;
+
+use crate::Symbol;
+#[cfg(feature = "std")]
+use crate::{
+    io::{Read, Seek},
+    ELFFile, SectionType, SymbolTable, UnpackError, Unpackable,
+};
+
+/// The flags of a section group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GroupFlags(pub u32);
+
+impl GroupFlags {
+    /// The group is a COMDAT group, only one group with the same signature is kept by the linker
+    pub const COMDAT: Self = Self(0x1);
+    /// The mask for operating system specific flags
+    pub const MASKOS: Self = Self(0x0ff00000);
+    /// The mask for processor specific flags
+    pub const MASKPROC: Self = Self(0xf0000000);
+
+    /// Returns whether all the bits in `other` are set in `self`
+    /// # Arguments
+    /// * `other` - The flags to check for
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether the group is a COMDAT group
+    pub fn is_comdat(&self) -> bool {
+        self.contains(Self::COMDAT)
+    }
+}
+
+/// A section group (`SHT_GROUP`), a set of sections the linker keeps or discards as a whole
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionGroup {
+    /// The index of the group section
+    pub section_index: usize,
+    /// The flags of the group
+    pub flags: GroupFlags,
+    /// The symbol whose name identifies the group, along with its name
+    pub signature_symbol: Symbol,
+    /// The indices of the member sections
+    pub members: Vec<u32>,
+}
+
+impl SectionGroup {
+    /// Returns the name of the signature symbol, which identifies the group
+    pub fn signature(&self) -> Option<&str> {
+        self.signature_symbol.name.as_deref()
+    }
+
+    /// Returns whether the section at `index` is a member of this group
+    /// # Arguments
+    /// * `index` - The index of the section
+    pub fn contains(&self, index: usize) -> bool {
+        self.members.iter().any(|m| *m as usize == index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ELFFile {
+    /// Parses all section groups (`SHT_GROUP`)
+    ///
+    /// The group data is a flag word followed by the indices of the member sections.
+    /// The signature symbol is found in the symbol table linked by `sh_link` at the
+    /// index in `sh_info`. The data is read from `r` if not loaded
+    /// # Arguments
+    /// * `r` - The stream the file was loaded from
+    pub fn section_groups<R: Read + Seek>(
+        &self,
+        r: &mut R,
+    ) -> Result<Vec<SectionGroup>, UnpackError> {
+        let big_endian = self.header.ident.is_big_endian();
+
+        let indices: Vec<usize> = self
+            .sections_of_type(SectionType::Group)
+            .map(|(i, _)| i)
+            .collect();
+
+        let mut symbols: Option<(u32, SymbolTable)> = None;
+        let mut groups = Vec::new();
+        for index in indices {
+            let section = self.section_with_data(r, index)?;
+
+            let mut cursor = section.data.as_cursor();
+            let flags = GroupFlags(u32::unpack(&mut cursor, big_endian)?);
+
+            let mut members = Vec::new();
+            while cursor.position() + 4 <= section.data.len() as u64 {
+                members.push(u32::unpack(&mut cursor, big_endian)?);
+            }
+
+            // All groups usually share the same symbol table
+            let table = match symbols {
+                Some((link, ref table)) if link == section.link => table,
+                _ => {
+                    let table = self.read_symbol_table(r, section.link as usize)?;
+                    &symbols.insert((section.link, table)).1
+                }
+            };
+
+            let signature_symbol = table.get(section.info as usize).cloned().ok_or(
+                UnpackError::InvalidSymbolIndex {
+                    index: section.info as usize,
+                },
+            )?;
+
+            groups.push(SectionGroup {
+                section_index: index,
+                flags,
+                signature_symbol,
+                members,
+            });
+        }
+
+        Ok(groups)
+    }
+}
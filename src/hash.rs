@@ -0,0 +1,262 @@
+use alloc::vec::Vec;
+
+use crate::{Blob, Class, StringTable, SymbolTable, UnpackError, Unpackable, UnpackableClass};
+
+/// Computes the GNU hash (`dl_new_hash`) of a symbol name
+/// # Arguments
+/// * `name` - The name of the symbol
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter()
+        .fold(5381u32, |h, c| h.wrapping_mul(33).wrapping_add(*c as u32))
+}
+
+/// Computes the SysV hash (`elf_hash`) of a symbol name
+/// # Arguments
+/// * `name` - The name of the symbol
+pub fn elf_hash(name: &[u8]) -> u32 {
+    name.iter().fold(0u32, |h, c| {
+        let h = (h << 4).wrapping_add(*c as u32);
+        let g = h & 0xf0000000;
+        (h ^ (g >> 24)) & !g
+    })
+}
+
+/// A SysV symbol hash table (`SHT_HASH`, `DT_HASH`)
+///
+/// Each bucket holds the index of the first symbol with the bucket's hash,
+/// the chain links each symbol to the next one, ending with `0`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SysvHashTable {
+    /// The index of the first symbol of each bucket
+    pub buckets: Vec<u32>,
+    /// The index of the next symbol of the same bucket for each symbol
+    pub chains: Vec<u32>,
+}
+
+impl SysvHashTable {
+    /// Parses a SysV hash table
+    /// # Arguments
+    /// * `blob` - The data of the hash table section
+    /// * `big_endian` - Whether the file is big endian
+    pub fn from_blob(blob: &Blob, big_endian: bool) -> Result<Self, UnpackError> {
+        let mut r = blob.as_cursor();
+
+        let bucket_count = u32::unpack(&mut r, big_endian)?;
+        let chain_count = u32::unpack(&mut r, big_endian)?;
+
+        let mut buckets = Vec::new();
+        for _ in 0..bucket_count {
+            buckets.push(u32::unpack(&mut r, big_endian)?);
+        }
+
+        let mut chains = Vec::new();
+        for _ in 0..chain_count {
+            chains.push(u32::unpack(&mut r, big_endian)?);
+        }
+
+        Ok(Self { buckets, chains })
+    }
+
+    /// Returns the number of symbols in the symbol table, which equals the chain length
+    pub fn symbol_count(&self) -> usize {
+        self.chains.len()
+    }
+
+    /// Looks up the symbol named `name`
+    /// # Arguments
+    /// * `name` - The name of the symbol
+    /// * `symbols` - The dynamic symbol table the hash table belongs to
+    /// * `strings` - The string table of the dynamic symbol table
+    /// # Returns
+    /// The index of the symbol in the symbol table, `None` if there is no such symbol
+    pub fn lookup(&self, name: &str, symbols: &SymbolTable, strings: &StringTable) -> Option<u32> {
+        if self.buckets.is_empty() {
+            return None;
+        }
+
+        let hash = elf_hash(name.as_bytes());
+        let mut index = self.buckets[hash as usize % self.buckets.len()];
+
+        // Bounding the walk by the chain length stops on cyclic chains
+        for _ in 0..self.chains.len() {
+            if index == 0 {
+                return None;
+            }
+
+            if strings.get(symbols.get(index as usize)?.name_offset).ok() == Some(name) {
+                return Some(index);
+            }
+
+            index = *self.chains.get(index as usize)?;
+        }
+
+        None
+    }
+}
+
+/// A GNU symbol hash table (`SHT_GNU_HASH`, `DT_GNU_HASH`)
+///
+/// The table only covers the symbols from `symbol_offset` on, which are
+/// sorted by their bucket. Each bucket holds the index of its first symbol,
+/// the chain holds the hashes of the symbols with the lowest bit marking
+/// the last symbol of a bucket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GnuHashTable {
+    /// The index of the first symbol covered by the table
+    pub symbol_offset: u32,
+    /// The shift of the second hash for the bloom filter
+    pub bloom_shift: u32,
+    /// The bloom filter words, `32` or `64` bits depending on the class
+    pub bloom: Vec<u64>,
+    /// The index of the first symbol of each bucket, `0` for empty buckets
+    pub buckets: Vec<u32>,
+    /// The hashes of the covered symbols
+    pub chains: Vec<u32>,
+    /// The class of the file, which decides the size of the bloom words
+    class: Class,
+}
+
+impl GnuHashTable {
+    /// Parses a GNU hash table
+    ///
+    /// The chain extends to the end of the data, as its length is not recorded
+    /// # Arguments
+    /// * `blob` - The data of the hash table section
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn from_blob(blob: &Blob, class: Class, big_endian: bool) -> Result<Self, UnpackError> {
+        let mut r = blob.as_cursor();
+
+        let bucket_count = u32::unpack(&mut r, big_endian)?;
+        let symbol_offset = u32::unpack(&mut r, big_endian)?;
+        let bloom_size = u32::unpack(&mut r, big_endian)?;
+        let bloom_shift = u32::unpack(&mut r, big_endian)?;
+
+        let mut bloom = Vec::new();
+        for _ in 0..bloom_size {
+            bloom.push(u64::unpack_class(&mut r, big_endian, class)?);
+        }
+
+        let mut buckets = Vec::new();
+        for _ in 0..bucket_count {
+            buckets.push(u32::unpack(&mut r, big_endian)?);
+        }
+
+        let mut chains = Vec::new();
+        while r.position() + 4 <= blob.len() as u64 {
+            chains.push(u32::unpack(&mut r, big_endian)?);
+        }
+
+        Ok(Self {
+            symbol_offset,
+            bloom_shift,
+            bloom,
+            buckets,
+            chains,
+            class,
+        })
+    }
+
+    /// Returns the number of symbols in the symbol table
+    ///
+    /// This walks the chain of the last non-empty bucket up to its end marker
+    pub fn symbol_count(&self) -> usize {
+        let Some(last) = self.buckets.iter().copied().max() else {
+            return self.symbol_offset as usize;
+        };
+
+        if last < self.symbol_offset {
+            return self.symbol_offset as usize;
+        }
+
+        let mut index = last as usize;
+        while let Some(chain) = self.chains.get(index - self.symbol_offset as usize) {
+            if chain & 1 != 0 {
+                return index + 1;
+            }
+
+            index += 1;
+        }
+
+        index
+    }
+
+    /// Returns whether the bloom filter allows a symbol with `hash` to be in the table
+    ///
+    /// A `false` result guarantees that there is no such symbol
+    /// # Arguments
+    /// * `hash` - The GNU hash of the symbol name, see [gnu_hash]
+    pub fn may_contain(&self, hash: u32) -> bool {
+        if self.bloom.is_empty() {
+            return false;
+        }
+
+        let bits = match self.class {
+            Class::ELF32 => 32,
+            Class::ELF64 => 64,
+        };
+
+        let word = self.bloom[(hash / bits) as usize % self.bloom.len()];
+        let mask = (1u64 << (hash % bits)) | (1u64 << ((hash >> (self.bloom_shift % 32)) % bits));
+
+        word & mask == mask
+    }
+
+    /// Looks up the first symbol whose hash matches the one of `name`
+    ///
+    /// As only the hashes are compared, the name of the
+    /// symbol has to be checked, see [GnuHashTable::lookup_symbol]
+    /// # Arguments
+    /// * `name` - The name of the symbol
+    /// # Returns
+    /// The index of the symbol in the symbol table, `None` if there is no such symbol
+    pub fn lookup(&self, name: &str) -> Option<u32> {
+        self.candidates(gnu_hash(name.as_bytes())).next()
+    }
+
+    /// Looks up the symbol named `name`, comparing the names of all symbols with its hash
+    /// # Arguments
+    /// * `name` - The name of the symbol
+    /// * `symbols` - The dynamic symbol table the hash table belongs to
+    /// * `strings` - The string table of the dynamic symbol table
+    /// # Returns
+    /// The index of the symbol in the symbol table, `None` if there is no such symbol
+    pub fn lookup_symbol(
+        &self,
+        name: &str,
+        symbols: &SymbolTable,
+        strings: &StringTable,
+    ) -> Option<u32> {
+        self.candidates(gnu_hash(name.as_bytes())).find(|index| {
+            symbols
+                .get(*index as usize)
+                .is_some_and(|s| strings.get(s.name_offset).ok() == Some(name))
+        })
+    }
+
+    /// Returns the indices of the symbols whose hash matches `hash`
+    fn candidates(&self, hash: u32) -> impl Iterator<Item = u32> + '_ {
+        let start = match self.buckets.is_empty() || !self.may_contain(hash) {
+            true => 0,
+            false => self.buckets[hash as usize % self.buckets.len()],
+        };
+
+        // Empty buckets hold `0`, which is below the offset and yields nothing
+        let chain = match start.checked_sub(self.symbol_offset) {
+            Some(skip) if start != 0 => self.chains.get(skip as usize..).unwrap_or_default(),
+            _ => &[],
+        };
+
+        // The chain of the bucket ends with the first entry that has the lowest bit set
+        let end = chain
+            .iter()
+            .position(|c| c & 1 != 0)
+            .map_or(chain.len(), |i| i + 1);
+
+        chain[..end]
+            .iter()
+            .zip(start..)
+            .filter(move |(c, _)| *c | 1 == hash | 1)
+            .map(|(_, index)| index)
+    }
+}
@@ -1,9 +1,23 @@
-use std::io::{Read, Seek, SeekFrom};
-
-use crate::{Packable, PackableClass, UnpackError, Unpackable, UnpackableClass};
-
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+
+use crate::{
+    io::{self, Read, Seek, SeekFrom},
+    Address, Class, Endianness, Machine, OsAbi, Packable, PackableClass, UnpackError, Unpackable,
+    UnpackableClass, ELF_FILE_MAGIC,
+};
+
+#[cfg(feature = "std")]
+use super::{section::SHN_LORESERVE, SectionIndex};
 use super::{Ident, ProgramHeader, SectionHeader};
 
+/// The program header count marking the real count to be stored in the null section (`PN_XNUM`)
+const PN_XNUM: u16 = 0xffff;
+
 const ELF_HEADER_SIZE_32: u64 = 0x34;
 const ELF_HEADER_SIZE_64: u64 = 0x40;
 
@@ -14,14 +28,15 @@ const SECTION_HEADER_SIZE_32: u64 = 0x28;
 const SECTION_HEADER_SIZE_64: u64 = 0x40;
 
 /// The ELF header
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Header {
     /// The ident sequence
     pub ident: Ident,
     /// The type of ELF file
-    pub ty: u16,
+    pub ty: ELFType,
     /// The machine type
-    pub machine: u16,
+    pub machine: Machine,
     /// The file version (`1`)
     pub version: u32,
     /// The entry point of the file, if existing
@@ -47,18 +62,208 @@ pub struct Header {
 }
 
 impl Header {
+    /// Creates a header for a file without program and section headers
+    /// # Arguments
+    /// * `class` - The ELF class of the file
+    /// * `endianness` - The endianness of the file
+    /// * `ty` - The type of ELF file
+    /// * `machine` - The machine the file targets
+    pub fn new(class: Class, endianness: Endianness, ty: ELFType, machine: Machine) -> Self {
+        let mut header = Self {
+            ident: Ident {
+                magic: ELF_FILE_MAGIC,
+                class,
+                endianness,
+                version: 1,
+                os_abi: OsAbi::SysV,
+                abi_version: 0,
+            },
+            ty,
+            machine,
+            version: 1,
+            entry_point: 0,
+            ph_offset: 0,
+            sh_offset: 0,
+            flags: 0,
+            header_size: 0,
+            ph_entry_size: 0,
+            ph_entry_count: 0,
+            sh_entry_size: 0,
+            sh_entry_count: 0,
+            sh_str_index: 0,
+        };
+
+        header.header_size = header.get_header_size() as u16;
+        header.ph_entry_size = header.get_program_header_size() as u16;
+        header.sh_entry_size = header.get_section_header_size() as u16;
+
+        header
+    }
+
+    /// Returns the entry point of the file as an [Address]
+    pub fn entry_point_address(&self) -> Address {
+        Address(self.entry_point)
+    }
+
+    /// Returns the fields that differ between `self` and `other`
+    /// # Arguments
+    /// * `other` - The header to compare against
+    pub fn changed_fields(&self, other: &Header) -> Vec<HeaderField> {
+        let fields = [
+            (HeaderField::Ident, self.ident != other.ident),
+            (HeaderField::Type, self.ty != other.ty),
+            (HeaderField::Machine, self.machine != other.machine),
+            (HeaderField::Version, self.version != other.version),
+            (
+                HeaderField::EntryPoint,
+                self.entry_point != other.entry_point,
+            ),
+            (
+                HeaderField::ProgramHeaderOffset,
+                self.ph_offset != other.ph_offset,
+            ),
+            (
+                HeaderField::SectionHeaderOffset,
+                self.sh_offset != other.sh_offset,
+            ),
+            (HeaderField::Flags, self.flags != other.flags),
+            (
+                HeaderField::HeaderSize,
+                self.header_size != other.header_size,
+            ),
+            (
+                HeaderField::ProgramHeaderSize,
+                self.ph_entry_size != other.ph_entry_size,
+            ),
+            (
+                HeaderField::ProgramHeaderCount,
+                self.ph_entry_count != other.ph_entry_count,
+            ),
+            (
+                HeaderField::SectionHeaderSize,
+                self.sh_entry_size != other.sh_entry_size,
+            ),
+            (
+                HeaderField::SectionHeaderCount,
+                self.sh_entry_count != other.sh_entry_count,
+            ),
+            (
+                HeaderField::SectionNamesIndex,
+                self.sh_str_index != other.sh_str_index,
+            ),
+        ];
+
+        fields
+            .into_iter()
+            .filter(|(_, changed)| *changed)
+            .map(|(field, _)| field)
+            .collect()
+    }
+
+    /// Returns the amount of program headers recorded in this header, `0xffff` (`PN_XNUM`)
+    /// if the real count is stored in the null section
+    pub fn program_header_count(&self) -> u16 {
+        self.ph_entry_count
+    }
+
+    /// Sets the location and size of the program header table
+    ///
+    /// The entry size is set for the class if there are headers. A count that
+    /// does not fit the header is recorded as `PN_XNUM`, the real count moves
+    /// to the `info` of the null section at index `0`
+    /// # Arguments
+    /// * `offset` - The offset of the table in the file
+    /// * `count` - The amount of program headers in the table
+    /// # Returns
+    /// The `info` of the null section
+    #[cfg(feature = "std")]
+    pub(crate) fn set_program_header_table(&mut self, offset: u64, count: usize) -> u32 {
+        self.ph_offset = offset;
+        if count != 0 {
+            self.ph_entry_size = self.get_program_header_size() as u16;
+        }
+
+        match count >= PN_XNUM as usize {
+            true => {
+                self.ph_entry_count = PN_XNUM;
+                count as u32
+            }
+            false => {
+                self.ph_entry_count = count as u16;
+                0
+            }
+        }
+    }
+
+    /// Sets the location and size of the section header table
+    ///
+    /// The entry size is set for the class if there are headers. Values that do not
+    /// fit the header use the extended section numbering: The count is recorded as
+    /// `0` and the string table index as `SHN_XINDEX`, their real values move to the
+    /// null section at index `0`
+    /// # Arguments
+    /// * `offset` - The offset of the table in the file
+    /// * `count` - The amount of section headers in the table
+    /// * `str_index` - The index of the section name string table
+    /// # Returns
+    /// The `size` and `link` of the null section
+    #[cfg(feature = "std")]
+    pub(crate) fn set_section_header_table(
+        &mut self,
+        offset: u64,
+        count: usize,
+        str_index: usize,
+    ) -> (u64, u32) {
+        self.sh_offset = offset;
+        if count != 0 {
+            self.sh_entry_size = self.get_section_header_size() as u16;
+        }
+
+        let extended = |value: usize| match value >= SHN_LORESERVE as usize {
+            true => value,
+            false => 0,
+        };
+        let (size, link) = (extended(count) as u64, extended(str_index) as u32);
+
+        self.sh_entry_count = match size {
+            0 => count as u16,
+            _ => 0,
+        };
+        self.sh_str_index = match link {
+            0 => str_index as u16,
+            _ => SectionIndex::XIndex.value(),
+        };
+
+        (size, link)
+    }
+
     /// Reads the program headers
+    ///
+    /// If the count is `PN_XNUM`, the real count is taken from the `info`
+    /// of the null section at index `0`, which must exist in that case
     /// # Arguments
     /// * `r` - The reader to read the headers from
     pub fn read_program_headers<R: Read + Seek>(
         &self,
         r: &mut R,
     ) -> Result<Vec<ProgramHeader>, UnpackError> {
+        let count = match self.ph_entry_count {
+            PN_XNUM => {
+                if self.sh_offset == 0 {
+                    return Err(UnpackError::InvalidSectionIndex { index: 0 });
+                }
+
+                r.seek(SeekFrom::Start(self.sh_offset))?;
+                SectionHeader::unpack_class(r, self.ident.is_big_endian(), self.ident.class)?.info
+            }
+            count => count as u32,
+        };
+
         r.seek(SeekFrom::Start(self.ph_offset))?;
 
         let mut res = Vec::new();
 
-        for _ in 0..self.ph_entry_count {
+        for _ in 0..count {
             res.push(ProgramHeader::unpack_class(
                 r,
                 self.ident.is_big_endian(),
@@ -70,6 +275,9 @@ impl Header {
     }
 
     /// Reads the section headers
+    ///
+    /// With the extended section numbering (a count of `0` with a table present),
+    /// the count is taken from the `size` of the null section at index `0`
     /// # Arguments
     /// * `r` - The reader to read the headers from
     pub fn read_section_headers<R: Read + Seek>(
@@ -78,12 +286,26 @@ impl Header {
     ) -> Result<Vec<SectionHeader>, UnpackError> {
         r.seek(SeekFrom::Start(self.sh_offset))?;
 
+        let big_endian = self.ident.is_big_endian();
         let mut res = Vec::new();
 
-        for _ in 0..self.sh_entry_count {
+        let count = match (self.sh_entry_count, self.sh_offset) {
+            (0, 0) => 0,
+            (0, _) => {
+                let null = SectionHeader::unpack_class(r, big_endian, self.ident.class)?;
+                let count = null.size;
+                if count != 0 {
+                    res.push(null);
+                }
+                count
+            }
+            (count, _) => count as u64,
+        };
+
+        while (res.len() as u64) < count {
             res.push(SectionHeader::unpack_class(
                 r,
-                self.ident.is_big_endian(),
+                big_endian,
                 self.ident.class,
             )?)
         }
@@ -101,6 +323,18 @@ impl Header {
         }
     }
 
+    /// Returns whether the header, program header and section header sizes
+    /// recorded in this header match the sizes of the ELF class
+    ///
+    /// The entry sizes are only checked if there are entries in the tables
+    pub fn has_valid_sizes(&self) -> bool {
+        self.header_size as u64 == self.get_header_size()
+            && (self.ph_entry_count == 0
+                || self.ph_entry_size as u64 == self.get_program_header_size())
+            && (self.sh_entry_count == 0
+                || self.sh_entry_size as u64 == self.get_section_header_size())
+    }
+
     /// Returns the size a program header takes as described by this header
     ///
     /// This is derived from the ELF class (32 / 64 bit)
@@ -122,12 +356,137 @@ impl Header {
     }
 }
 
+/// A field of the ELF header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderField {
+    /// The ident structure
+    Ident,
+    /// The object file type
+    Type,
+    /// The target machine
+    Machine,
+    /// The object file version
+    Version,
+    /// The entry point address
+    EntryPoint,
+    /// The offset of the program header table
+    ProgramHeaderOffset,
+    /// The offset of the section header table
+    SectionHeaderOffset,
+    /// The processor specific flags
+    Flags,
+    /// The size of the header
+    HeaderSize,
+    /// The size of a program header
+    ProgramHeaderSize,
+    /// The count of program headers
+    ProgramHeaderCount,
+    /// The size of a section header
+    SectionHeaderSize,
+    /// The count of section headers
+    SectionHeaderCount,
+    /// The section header index of the section name string table
+    SectionNamesIndex,
+}
+
+/// Displays the name `readelf -h` uses for the field
+impl Display for HeaderField {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            Self::Ident => "Ident",
+            Self::Type => "Type",
+            Self::Machine => "Machine",
+            Self::Version => "Version",
+            Self::EntryPoint => "Entry point address",
+            Self::ProgramHeaderOffset => "Start of program headers",
+            Self::SectionHeaderOffset => "Start of section headers",
+            Self::Flags => "Flags",
+            Self::HeaderSize => "Size of this header",
+            Self::ProgramHeaderSize => "Size of program headers",
+            Self::ProgramHeaderCount => "Number of program headers",
+            Self::SectionHeaderSize => "Size of section headers",
+            Self::SectionHeaderCount => "Number of section headers",
+            Self::SectionNamesIndex => "Section header string table index",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Displays in the form of `readelf -h`
+impl Display for Header {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let ident = &self.ident;
+        let version = match ident.version {
+            1 => " (current)",
+            _ => "",
+        };
+
+        writeln!(f, "ELF Header:")?;
+        write!(f, "  Magic:  ")?;
+        for byte in ident.magic {
+            write!(f, " {:02x}", byte)?;
+        }
+        write!(
+            f,
+            " {:02x} {:02x} {:02x} {:02x} {:02x}",
+            ident.class as u8,
+            ident.endianness as u8,
+            ident.version,
+            ident.os_abi.value(),
+            ident.abi_version
+        )?;
+        writeln!(f, "{}", " 00".repeat(7))?;
+
+        let fields: [(&str, String); 18] = [
+            ("Class", ident.class.to_string()),
+            ("Data", ident.endianness.to_string()),
+            ("Version", format!("{}{}", ident.version, version)),
+            ("OS/ABI", ident.os_abi.to_string()),
+            ("ABI Version", ident.abi_version.to_string()),
+            ("Type", self.ty.to_string()),
+            ("Machine", self.machine.to_string()),
+            ("Version", format!("{:#x}", self.version)),
+            ("Entry point address", format!("{:#x}", self.entry_point)),
+            (
+                "Start of program headers",
+                format!("{} (bytes into file)", self.ph_offset),
+            ),
+            (
+                "Start of section headers",
+                format!("{} (bytes into file)", self.sh_offset),
+            ),
+            ("Flags", format!("{:#x}", self.flags)),
+            (
+                "Size of this header",
+                format!("{} (bytes)", self.header_size),
+            ),
+            (
+                "Size of program headers",
+                format!("{} (bytes)", self.ph_entry_size),
+            ),
+            ("Number of program headers", self.ph_entry_count.to_string()),
+            (
+                "Size of section headers",
+                format!("{} (bytes)", self.sh_entry_size),
+            ),
+            ("Number of section headers", self.sh_entry_count.to_string()),
+            (
+                "Section header string table index",
+                self.sh_str_index.to_string(),
+            ),
+        ];
+
+        for (name, value) in fields {
+            writeln!(f, "  {:<34} {}", format!("{}:", name), value)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Packable for Header {
-    fn pack<W: std::io::Write + std::io::Seek>(
-        &self,
-        w: &mut W,
-        _: bool,
-    ) -> Result<(), std::io::Error> {
+    fn pack<W: io::Write + io::Seek>(&self, w: &mut W, _: bool) -> Result<(), io::Error> {
         let big_endian = self.ident.is_big_endian();
         let class = self.ident.class;
 
@@ -137,9 +496,9 @@ impl Packable for Header {
         self.machine.pack(w, big_endian)?;
         self.version.pack(w, big_endian)?;
 
-        self.entry_point.pack_class(w, big_endian, class)?;
-        self.ph_offset.pack_class(w, big_endian, class)?;
-        self.sh_offset.pack_class(w, big_endian, class)?;
+        Address(self.entry_point).pack_class(w, big_endian, class)?;
+        Address(self.ph_offset).pack_class(w, big_endian, class)?;
+        Address(self.sh_offset).pack_class(w, big_endian, class)?;
 
         self.flags.pack(w, big_endian)?;
         self.header_size.pack(w, big_endian)?;
@@ -156,17 +515,14 @@ impl Packable for Header {
 }
 
 impl Unpackable for Header {
-    fn unpack<R: std::io::Read + std::io::Seek>(
-        r: &mut R,
-        _: bool,
-    ) -> Result<Self, crate::UnpackError> {
+    fn unpack<R: io::Read + io::Seek>(r: &mut R, _: bool) -> Result<Self, crate::UnpackError> {
         let ident = Ident::unpack(r, false)?;
 
         let big_endian = ident.is_big_endian();
         let class = ident.class;
 
-        let ty = u16::unpack(r, big_endian)?;
-        let machine = u16::unpack(r, big_endian)?;
+        let ty = ELFType::unpack(r, big_endian)?;
+        let machine = Machine::unpack(r, big_endian)?;
         let version = u32::unpack(r, big_endian)?;
 
         let entry_point = u64::unpack_class(r, big_endian, class)?;
@@ -201,3 +557,99 @@ impl Unpackable for Header {
         })
     }
 }
+
+/// The type of object an ELF file contains
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum ELFType {
+    /// No file type
+    None = 0x0,
+    /// A relocatable object file
+    Relocatable = 0x1,
+    /// An executable file
+    Executable = 0x2,
+    /// A shared object file
+    SharedObject = 0x3,
+    /// A core file
+    Core = 0x4,
+    /// An operating system specific type (`0xfe00` - `0xfeff`)
+    OsSpecific(u16),
+    /// A processor specific type (`0xff00` - `0xffff`)
+    ProcessorSpecific(u16),
+    /// Any other unknown object type
+    Other(u16),
+}
+
+impl ELFType {
+    /// Returns whether this is an executable file
+    ///
+    /// Position independent executables are shared objects and are not included
+    pub fn is_executable(&self) -> bool {
+        *self == Self::Executable
+    }
+
+    /// Returns whether this is a shared object file
+    pub fn is_shared_library(&self) -> bool {
+        *self == Self::SharedObject
+    }
+
+    /// Returns whether this is a relocatable object file
+    pub fn is_relocatable(&self) -> bool {
+        *self == Self::Relocatable
+    }
+
+    /// Returns whether this is a core file
+    pub fn is_core(&self) -> bool {
+        *self == Self::Core
+    }
+}
+
+impl Display for ELFType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::None => write!(f, "NONE (None)"),
+            Self::Relocatable => write!(f, "REL (Relocatable file)"),
+            Self::Executable => write!(f, "EXEC (Executable file)"),
+            Self::SharedObject => write!(f, "DYN (Shared object file)"),
+            Self::Core => write!(f, "CORE (Core file)"),
+            Self::OsSpecific(ty) => write!(f, "OS Specific: ({:x})", ty),
+            Self::ProcessorSpecific(ty) => write!(f, "Processor Specific: ({:x})", ty),
+            Self::Other(ty) => write!(f, "<unknown>: {:x}", ty),
+        }
+    }
+}
+
+impl Packable for ELFType {
+    fn pack<W: io::Write + io::Seek>(&self, w: &mut W, big_endian: bool) -> Result<(), io::Error> {
+        let ty: u16 = match self {
+            ELFType::None => 0x0,
+            ELFType::Relocatable => 0x1,
+            ELFType::Executable => 0x2,
+            ELFType::SharedObject => 0x3,
+            ELFType::Core => 0x4,
+            ELFType::OsSpecific(ty) => *ty,
+            ELFType::ProcessorSpecific(ty) => *ty,
+            ELFType::Other(ty) => *ty,
+        };
+
+        ty.pack(w, big_endian)
+    }
+}
+
+impl Unpackable for ELFType {
+    fn unpack<R: io::Read + io::Seek>(r: &mut R, big_endian: bool) -> Result<Self, UnpackError> {
+        let ty = u16::unpack(r, big_endian)?;
+
+        Ok(match ty {
+            0x0 => Self::None,
+            0x1 => Self::Relocatable,
+            0x2 => Self::Executable,
+            0x3 => Self::SharedObject,
+            0x4 => Self::Core,
+            0xfe00..=0xfeff => Self::OsSpecific(ty),
+            0xff00..=0xffff => Self::ProcessorSpecific(ty),
+            x => Self::Other(x),
+        })
+    }
+}
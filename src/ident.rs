@@ -1,6 +1,6 @@
-use std::io;
+use core::fmt::Display;
 
-use crate::{Packable, UnpackError, Unpackable};
+use crate::{io, Packable, UnpackError, Unpackable};
 
 /// The ident padding sequence, filled with `0`
 const ELF_IDENT_PADDING: [u8; 7] = [0u8; 7];
@@ -10,7 +10,8 @@ pub const ELF_FILE_MAGIC: [u8; 4] = [0x7f, 0x45, 0x4c, 0x46];
 
 /// The ELF ident structure to identify further
 /// parsing of an ELF file
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ident {
     /// The `ELF` file magic [ELF_FILE_MAGIC]
     pub magic: [u8; 4],
@@ -21,13 +22,14 @@ pub struct Ident {
     /// The file version (normally `1`)
     pub version: u8,
     /// The operating system ABI
-    pub os_abi: u8,
+    pub os_abi: OsAbi,
     /// The abi version
     pub abi_version: u8,
 }
 
 /// The class of the ELF file at hand
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Class {
     /// A `32` bit ELF file
     ELF32 = 1,
@@ -37,6 +39,7 @@ pub enum Class {
 
 /// The endianness of this file
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Endianness {
     /// Little endian
     Little = 1,
@@ -44,6 +47,40 @@ pub enum Endianness {
     Big = 2,
 }
 
+/// The operating system ABI of this file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OsAbi {
+    /// UNIX System V
+    SysV,
+    /// HP-UX
+    HPUX,
+    /// NetBSD
+    NetBSD,
+    /// GNU / Linux
+    Gnu,
+    /// Sun Solaris
+    Solaris,
+    /// IBM AIX
+    Aix,
+    /// SGI Irix
+    Irix,
+    /// FreeBSD
+    FreeBSD,
+    /// Compaq TRU64 UNIX
+    Tru64,
+    /// OpenBSD
+    OpenBSD,
+    /// ARM EABI
+    ArmEabi,
+    /// ARM
+    Arm,
+    /// A standalone (embedded) application
+    Standalone,
+    /// Any other unknown ABI
+    Other(u8),
+}
+
 impl Ident {
     /// Returns whether the ident describes
     /// the file to be big endian
@@ -52,6 +89,24 @@ impl Ident {
     }
 }
 
+impl Display for Class {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ELF32 => write!(f, "ELF32"),
+            Self::ELF64 => write!(f, "ELF64"),
+        }
+    }
+}
+
+impl Display for Endianness {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Little => write!(f, "2's complement, little endian"),
+            Self::Big => write!(f, "2's complement, big endian"),
+        }
+    }
+}
+
 impl Packable for Class {
     fn pack<W: io::Write + io::Seek>(&self, w: &mut W, _: bool) -> Result<(), io::Error> {
         w.write_all(&[*self as u8])
@@ -98,17 +153,89 @@ impl Unpackable for Endianness {
     }
 }
 
+impl OsAbi {
+    /// Returns the raw value of this ABI as stored in the ident
+    pub fn value(&self) -> u8 {
+        match self {
+            Self::SysV => 0,
+            Self::HPUX => 1,
+            Self::NetBSD => 2,
+            Self::Gnu => 3,
+            Self::Solaris => 6,
+            Self::Aix => 7,
+            Self::Irix => 8,
+            Self::FreeBSD => 9,
+            Self::Tru64 => 10,
+            Self::OpenBSD => 12,
+            Self::ArmEabi => 64,
+            Self::Arm => 97,
+            Self::Standalone => 255,
+            Self::Other(abi) => *abi,
+        }
+    }
+}
+
+impl Display for OsAbi {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::SysV => write!(f, "UNIX - System V"),
+            Self::HPUX => write!(f, "UNIX - HP-UX"),
+            Self::NetBSD => write!(f, "UNIX - NetBSD"),
+            Self::Gnu => write!(f, "UNIX - GNU"),
+            Self::Solaris => write!(f, "UNIX - Solaris"),
+            Self::Aix => write!(f, "UNIX - AIX"),
+            Self::Irix => write!(f, "UNIX - IRIX"),
+            Self::FreeBSD => write!(f, "UNIX - FreeBSD"),
+            Self::Tru64 => write!(f, "UNIX - TRU64"),
+            Self::OpenBSD => write!(f, "UNIX - OpenBSD"),
+            Self::ArmEabi => write!(f, "ARM EABI"),
+            Self::Arm => write!(f, "ARM"),
+            Self::Standalone => write!(f, "Standalone App"),
+            Self::Other(abi) => write!(f, "<unknown: {:x}>", abi),
+        }
+    }
+}
+
+impl Packable for OsAbi {
+    fn pack<W: io::Write + io::Seek>(&self, w: &mut W, _: bool) -> Result<(), io::Error> {
+        w.write_all(&[self.value()])
+    }
+}
+
+impl Unpackable for OsAbi {
+    fn unpack<R: io::Read>(r: &mut R, _: bool) -> Result<Self, UnpackError> {
+        let mut data = [0u8];
+
+        r.read_exact(&mut data)?;
+
+        Ok(match data[0] {
+            0 => Self::SysV,
+            1 => Self::HPUX,
+            2 => Self::NetBSD,
+            3 => Self::Gnu,
+            6 => Self::Solaris,
+            7 => Self::Aix,
+            8 => Self::Irix,
+            9 => Self::FreeBSD,
+            10 => Self::Tru64,
+            12 => Self::OpenBSD,
+            64 => Self::ArmEabi,
+            97 => Self::Arm,
+            255 => Self::Standalone,
+            x => Self::Other(x),
+        })
+    }
+}
+
 impl Packable for Ident {
     fn pack<W: io::Write + io::Seek>(&self, w: &mut W, _: bool) -> Result<(), io::Error> {
         w.write_all(&self.magic)?;
 
-        w.write_all(&[
-            self.class as u8,
-            self.endianness as u8,
-            self.version,
-            self.os_abi,
-            self.abi_version,
-        ])?;
+        self.class.pack(w, false)?;
+        self.endianness.pack(w, false)?;
+        self.version.pack(w, false)?;
+        self.os_abi.pack(w, false)?;
+        self.abi_version.pack(w, false)?;
 
         w.write_all(&ELF_IDENT_PADDING)
     }
@@ -131,7 +258,7 @@ impl Unpackable for Ident {
             class: Class::unpack(r, false)?,
             endianness: Endianness::unpack(r, false)?,
             version: u8::unpack(r, false)?,
-            os_abi: u8::unpack(r, false)?,
+            os_abi: OsAbi::unpack(r, false)?,
             abi_version: u8::unpack(r, false)?,
         };
 
@@ -0,0 +1,287 @@
+//! The stream abstraction all packing and unpacking is built on
+//!
+//! With the `std` feature, these are the types of [std::io]. Without it, this
+//! module provides a minimal replacement with the same names and signatures,
+//! so [crate::Unpackable] and friends work in `no_std` environments by
+//! implementing [Read], [Write] and [Seek] for the stream in use or by
+//! wrapping a byte slice in a [Cursor]
+
+#[cfg(feature = "std")]
+pub use std::io::{Cursor, Error, ErrorKind, Read, Seek, SeekFrom, Write};
+
+#[cfg(not(feature = "std"))]
+pub use self::no_std::*;
+
+#[cfg(not(feature = "std"))]
+mod no_std {
+    use alloc::{borrow::Cow, vec::Vec};
+    use core::fmt::Display;
+
+    /// The kind of an [Error], a subset of `std::io::ErrorKind`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ErrorKind {
+        /// The data is not valid for the operation
+        InvalidData,
+        /// A parameter is not valid for the operation
+        InvalidInput,
+        /// The stream ended before all data was read
+        UnexpectedEof,
+        /// The stream ended before all data was written
+        WriteZero,
+        /// Any other error
+        Other,
+    }
+
+    /// An error while reading, writing or seeking
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Error {
+        kind: ErrorKind,
+        message: Cow<'static, str>,
+    }
+
+    impl Error {
+        /// Creates a new error
+        /// # Arguments
+        /// * `kind` - The kind of the error
+        /// * `message` - A description of the error
+        pub fn new<M: Into<Cow<'static, str>>>(kind: ErrorKind, message: M) -> Self {
+            Self {
+                kind,
+                message: message.into(),
+            }
+        }
+
+        /// Returns the kind of this error
+        pub fn kind(&self) -> ErrorKind {
+            self.kind
+        }
+    }
+
+    impl From<ErrorKind> for Error {
+        fn from(value: ErrorKind) -> Self {
+            Self::new(value, "")
+        }
+    }
+
+    impl Display for Error {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.message.as_ref() {
+                "" => write!(f, "{:?}", self.kind),
+                message => write!(f, "{}", message),
+            }
+        }
+    }
+
+    impl core::error::Error for Error {}
+
+    /// A position to seek to
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum SeekFrom {
+        /// An offset from the start of the stream
+        Start(u64),
+        /// An offset from the end of the stream
+        End(i64),
+        /// An offset from the current position
+        Current(i64),
+    }
+
+    /// A stream of bytes that can be read from
+    pub trait Read {
+        /// Reads some bytes into `buf`
+        /// # Arguments
+        /// * `buf` - The buffer to read into
+        /// # Returns
+        /// The amount of bytes read, `0` at the end of the stream
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error>;
+
+        /// Reads exactly enough bytes to fill `buf`
+        /// # Arguments
+        /// * `buf` - The buffer to read into
+        fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<(), Error> {
+            while !buf.is_empty() {
+                match self.read(buf)? {
+                    0 => return Err(ErrorKind::UnexpectedEof.into()),
+                    n => buf = &mut buf[n..],
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    /// A stream of bytes that can be written to
+    pub trait Write {
+        /// Writes some bytes from `buf`
+        /// # Arguments
+        /// * `buf` - The bytes to write
+        /// # Returns
+        /// The amount of bytes written
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Error>;
+
+        /// Writes all bytes from `buf`
+        /// # Arguments
+        /// * `buf` - The bytes to write
+        fn write_all(&mut self, mut buf: &[u8]) -> Result<(), Error> {
+            while !buf.is_empty() {
+                match self.write(buf)? {
+                    0 => return Err(ErrorKind::WriteZero.into()),
+                    n => buf = &buf[n..],
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    /// A stream with a position that can be moved
+    pub trait Seek {
+        /// Moves the position of the stream
+        /// # Arguments
+        /// * `pos` - The position to move to
+        /// # Returns
+        /// The new position from the start of the stream
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64, Error>;
+
+        /// Returns the current position from the start of the stream
+        fn stream_position(&mut self) -> Result<u64, Error> {
+            self.seek(SeekFrom::Current(0))
+        }
+    }
+
+    impl<R: Read + ?Sized> Read for &mut R {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+            (**self).read(buf)
+        }
+    }
+
+    impl<W: Write + ?Sized> Write for &mut W {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+            (**self).write(buf)
+        }
+    }
+
+    impl<S: Seek + ?Sized> Seek for &mut S {
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64, Error> {
+            (**self).seek(pos)
+        }
+    }
+
+    /// A stream over bytes in memory
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct Cursor<T> {
+        inner: T,
+        position: u64,
+    }
+
+    impl<T> Cursor<T> {
+        /// Creates a cursor at the start of `inner`
+        /// # Arguments
+        /// * `inner` - The bytes to wrap
+        pub fn new(inner: T) -> Self {
+            Self { inner, position: 0 }
+        }
+
+        /// Returns the current position of this cursor
+        pub fn position(&self) -> u64 {
+            self.position
+        }
+
+        /// Sets the position of this cursor
+        /// # Arguments
+        /// * `position` - The new position
+        pub fn set_position(&mut self, position: u64) {
+            self.position = position;
+        }
+
+        /// Returns a reference to the wrapped bytes
+        pub fn get_ref(&self) -> &T {
+            &self.inner
+        }
+
+        /// Returns a mutable reference to the wrapped bytes
+        pub fn get_mut(&mut self) -> &mut T {
+            &mut self.inner
+        }
+
+        /// Returns the wrapped bytes
+        pub fn into_inner(self) -> T {
+            self.inner
+        }
+    }
+
+    impl<T: AsRef<[u8]>> Cursor<T> {
+        /// Returns the bytes after the current position
+        fn remaining(&self) -> &[u8] {
+            let data = self.inner.as_ref();
+            let start = usize::try_from(self.position).map_or(data.len(), |p| p.min(data.len()));
+            &data[start..]
+        }
+    }
+
+    impl<T: AsRef<[u8]>> Read for Cursor<T> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+            let remaining = self.remaining();
+            let len = remaining.len().min(buf.len());
+
+            buf[..len].copy_from_slice(&remaining[..len]);
+            self.position += len as u64;
+
+            Ok(len)
+        }
+    }
+
+    impl<T: AsRef<[u8]>> Seek for Cursor<T> {
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64, Error> {
+            let (base, offset) = match pos {
+                SeekFrom::Start(offset) => {
+                    self.position = offset;
+                    return Ok(offset);
+                }
+                SeekFrom::End(offset) => (self.inner.as_ref().len() as u64, offset),
+                SeekFrom::Current(offset) => (self.position, offset),
+            };
+
+            match base.checked_add_signed(offset) {
+                Some(position) => {
+                    self.position = position;
+                    Ok(position)
+                }
+                None => Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                )),
+            }
+        }
+    }
+
+    impl Write for Cursor<&mut [u8]> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+            let data = &mut *self.inner;
+            let start = usize::try_from(self.position).map_or(data.len(), |p| p.min(data.len()));
+            let len = (data.len() - start).min(buf.len());
+
+            data[start..start + len].copy_from_slice(&buf[..len]);
+            self.position += len as u64;
+
+            Ok(len)
+        }
+    }
+
+    impl Write for Cursor<Vec<u8>> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+            let start = usize::try_from(self.position)
+                .map_err(|_| Error::new(ErrorKind::InvalidInput, "position out of range"))?;
+            let end = start
+                .checked_add(buf.len())
+                .ok_or(Error::new(ErrorKind::InvalidInput, "position out of range"))?;
+
+            if self.inner.len() < end {
+                self.inner.resize(end, 0);
+            }
+            self.inner[start..end].copy_from_slice(buf);
+            self.position = end as u64;
+
+            Ok(buf.len())
+        }
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! # Usage
 //! ```no_run
+//! # #[cfg(feature = "std")]
+//! # fn main() {
 //! use std::fs::File;
 //! use elfsmith::ELFFile;
 //!
@@ -10,49 +12,140 @@
 //! let elf = ELFFile::load(&mut file).unwrap();
 //!
 //! println!("{:#x?}", elf);
+//! # }
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
 //! ```
+//!
+//! # Features
+//! * `std` (default) - [ELFFile] and everything built on it, like patching,
+//!   building and validating whole files. Without it, the crate is `no_std`
+//!   and needs only `alloc`: The headers, symbols, notes, relocations and the
+//!   other tables can still be unpacked from any stream implementing the
+//!   traits of [io], e.g. an [io::Cursor] over the bytes of the file
+//! * `flate2` / `zstd` - The decompression of compressed sections, implies `std`
+//! * `serde` - Serialization of the file and header types
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod io;
 
 mod packable;
 pub use packable::*;
 
+#[cfg(feature = "std")]
+mod error;
+#[cfg(feature = "std")]
+pub use error::*;
+
+mod align;
+pub use align::*;
+
+mod address;
+pub use address::*;
+
 mod ident;
 pub use ident::*;
 
 mod header;
 pub use header::*;
 
+mod machine;
+pub use machine::*;
+
+mod machine_flags;
+pub use machine_flags::*;
+
 mod program;
 pub use program::*;
 
 mod section;
 pub use section::*;
 
+mod group;
+pub use group::*;
+
+mod string_table;
+pub use string_table::*;
+
+mod symbol;
+pub use symbol::*;
+
+mod hash;
+pub use hash::*;
+
+mod version;
+pub use version::*;
+
+mod dynamic;
+pub use dynamic::*;
+
+#[cfg(feature = "std")]
+mod dynamic_info;
+#[cfg(feature = "std")]
+pub use dynamic_info::*;
+
+mod relocation;
+pub use relocation::*;
+
+mod relocation_x86_64;
+pub use relocation_x86_64::*;
+
+mod relocation_aarch64;
+pub use relocation_aarch64::*;
+
+mod relocation_riscv;
+pub use relocation_riscv::*;
+
+mod note;
+pub use note::*;
+
+mod gnu_property;
+pub use gnu_property::*;
+
+mod compression;
+pub use compression::*;
+
+mod core_dump;
+pub use core_dump::*;
+
+mod debuglink;
+pub use debuglink::*;
+
 mod blob;
 pub use blob::*;
 
+mod lint;
+pub use lint::*;
+
+#[cfg(feature = "std")]
+mod validate;
+#[cfg(feature = "std")]
+pub use validate::*;
+
+#[cfg(feature = "std")]
+mod security;
+#[cfg(feature = "std")]
+pub use security::*;
+
+#[cfg(feature = "std")]
+mod patch;
+#[cfg(feature = "std")]
+pub use patch::*;
+
+#[cfg(feature = "std")]
+mod diff;
+#[cfg(feature = "std")]
+pub use diff::*;
+
+#[cfg(feature = "std")]
+mod builder;
+#[cfg(feature = "std")]
+pub use builder::*;
+
+#[cfg(feature = "std")]
 mod file;
+#[cfg(feature = "std")]
 pub use file::*;
-
-use core::str;
-
-/// Creates a string slice from a pointer to a null terminated
-/// C-style string
-/// # Arguments
-/// * `ptr` - The pointer to traverse for the string slice
-///
-/// Finds the length of the string by iterating over it until
-/// the 0 byte and assumes a UTF-8 string from that
-unsafe fn str_from_null_terminated(ptr: *const u8) -> &'static str {
-    let mut len = 0usize;
-
-    loop {
-        if *(ptr.wrapping_add(len)) == 0 {
-            break;
-        } else {
-            len += 1;
-        }
-    }
-
-    let slice = core::slice::from_raw_parts(ptr, len);
-    str::from_utf8_unchecked(slice)
-}
@@ -0,0 +1,144 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::ELFFile;
+use crate::{
+    Class, DynamicEntry, RelEntry, RelaEntry, SectionFlags, SectionHeader, SectionType, Symbol,
+};
+
+/// A suspicious combination of section header fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionWarning {
+    /// The entry size does not match the size of the entries of the section type
+    EntrySizeMismatch {
+        /// The entry size required by the section type and class
+        expected: u64,
+        /// The entry size of the section
+        got: u64,
+    },
+    /// A string table is marked as executable
+    ExecutableStringTable,
+    /// A `SHT_NOBITS` section carries file data
+    NoBitsWithData,
+    /// The alignment is not a power of two
+    InvalidAlignment {
+        /// The alignment of the section
+        align: u64,
+    },
+    /// The address is not aligned to the alignment of the section
+    MisalignedAddress {
+        /// The address of the section
+        address: u64,
+        /// The alignment of the section
+        align: u64,
+    },
+    /// The linked section does not exist
+    InvalidLink {
+        /// The index of the linked section
+        link: u32,
+    },
+    /// The linked section has a type that is not valid for this section
+    UnexpectedLinkType {
+        /// The index of the linked section
+        link: u32,
+        /// The type of the linked section
+        ty: SectionType,
+    },
+}
+
+impl SectionHeader {
+    /// Checks the fields of this section header for inconsistencies
+    ///
+    /// This does not check references to other sections, see [ELFFile::lint]
+    /// # Arguments
+    /// * `class` - The ELF class of the file
+    pub fn validate(&self, class: Class) -> Vec<SectionWarning> {
+        let mut warnings = Vec::new();
+
+        let entry_size = match self.ty {
+            SectionType::SymbolTable | SectionType::DynSymbol => Some(Symbol::get_size(class)),
+            SectionType::Rela => Some(RelaEntry::get_size(class)),
+            SectionType::Rel => Some(RelEntry::get_size(class)),
+            SectionType::Dynamic => Some(DynamicEntry::get_size(class)),
+            _ => None,
+        };
+
+        if let Some(expected) = entry_size {
+            if self.entry_size != expected {
+                warnings.push(SectionWarning::EntrySizeMismatch {
+                    expected,
+                    got: self.entry_size,
+                });
+            }
+        }
+
+        if self.ty == SectionType::StringTable && self.flags.contains(SectionFlags::EXECINSTR) {
+            warnings.push(SectionWarning::ExecutableStringTable);
+        }
+
+        if self.ty == SectionType::NoBits && !self.data.blob.is_empty() {
+            warnings.push(SectionWarning::NoBitsWithData);
+        }
+
+        if self.addr_align > 1 {
+            if !self.addr_align.is_power_of_two() {
+                warnings.push(SectionWarning::InvalidAlignment {
+                    align: self.addr_align,
+                });
+            } else if !self.address.is_multiple_of(self.addr_align) {
+                warnings.push(SectionWarning::MisalignedAddress {
+                    address: self.address,
+                    align: self.addr_align,
+                });
+            }
+        }
+
+        warnings
+    }
+}
+
+#[cfg(feature = "std")]
+impl ELFFile {
+    /// Checks all section headers for inconsistencies, including their links
+    /// # Returns
+    /// The warnings along with the index of the section they apply to
+    pub fn lint(&self) -> Vec<(usize, SectionWarning)> {
+        let class = self.header.ident.class;
+        let mut warnings = Vec::new();
+
+        for (index, section) in self.section_headers.iter().enumerate() {
+            warnings.extend(section.validate(class).into_iter().map(|w| (index, w)));
+
+            if section.link == 0 {
+                continue;
+            }
+
+            let Some(linked) = self.section_headers.get(section.link as usize) else {
+                warnings.push((index, SectionWarning::InvalidLink { link: section.link }));
+                continue;
+            };
+
+            let expected = match section.ty {
+                SectionType::SymbolTable | SectionType::DynSymbol | SectionType::Dynamic => {
+                    &[SectionType::StringTable][..]
+                }
+                SectionType::Rel | SectionType::Rela | SectionType::Hash | SectionType::GnuHash => {
+                    &[SectionType::SymbolTable, SectionType::DynSymbol][..]
+                }
+                _ => &[][..],
+            };
+
+            if !expected.is_empty() && !expected.contains(&linked.ty) {
+                warnings.push((
+                    index,
+                    SectionWarning::UnexpectedLinkType {
+                        link: section.link,
+                        ty: linked.ty,
+                    },
+                ));
+            }
+        }
+
+        warnings
+    }
+}
@@ -0,0 +1,203 @@
+use core::fmt::Display;
+
+use crate::{io, Class, Packable, UnpackError, Unpackable};
+
+/// The machine (instruction set architecture) an ELF file targets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum Machine {
+    /// No machine
+    None = 0,
+    /// SPARC
+    Sparc = 2,
+    /// Intel 80386
+    X86 = 3,
+    /// MIPS
+    ///
+    /// 32 and 64 bit MIPS share this machine type and
+    /// are distinguished by the ELF class
+    Mips = 8,
+    /// 32 bit PowerPC
+    PowerPC = 20,
+    /// 64 bit PowerPC
+    PowerPC64 = 21,
+    /// IBM S/390 and z/Architecture (s390x)
+    S390 = 22,
+    /// 32 bit ARM
+    Arm = 40,
+    /// SPARC Version 9 (64 bit)
+    Sparc64 = 43,
+    /// AMD x86-64
+    X86_64 = 62,
+    /// 64 bit ARM
+    AArch64 = 183,
+    /// RISC-V
+    RiscV = 243,
+    /// Linux BPF
+    Bpf = 247,
+    /// LoongArch
+    LoongArch = 258,
+    /// Any other unknown machine
+    Other(u16),
+}
+
+impl Machine {
+    /// Returns the name of the machine as displayed by `readelf`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Sparc => "Sparc",
+            Self::X86 => "Intel 80386",
+            Self::Mips => "MIPS R3000",
+            Self::PowerPC => "PowerPC",
+            Self::PowerPC64 => "PowerPC64",
+            Self::S390 => "IBM S/390",
+            Self::Arm => "ARM",
+            Self::Sparc64 => "Sparc v9",
+            Self::X86_64 => "Advanced Micro Devices X86-64",
+            Self::AArch64 => "AArch64",
+            Self::RiscV => "RISC-V",
+            Self::Bpf => "Linux BPF",
+            Self::LoongArch => "LoongArch",
+            Self::Other(_) => "<unknown>",
+        }
+    }
+
+    /// Returns the ELF class typically used for this machine
+    ///
+    /// Machines that are commonly used with both classes report
+    /// the more common one, unknown machines return `None`
+    pub fn pointer_width_hint(&self) -> Option<Class> {
+        match self {
+            Self::Sparc | Self::X86 | Self::Mips | Self::PowerPC | Self::Arm => Some(Class::ELF32),
+            Self::PowerPC64
+            | Self::S390
+            | Self::Sparc64
+            | Self::X86_64
+            | Self::AArch64
+            | Self::RiscV
+            | Self::Bpf
+            | Self::LoongArch => Some(Class::ELF64),
+            Self::None | Self::Other(_) => None,
+        }
+    }
+
+    /// Returns whether this machine is exclusively a `64` bit architecture
+    ///
+    /// Machines that exist in both `32` and `64` bit variants
+    /// under the same machine type return `false`
+    pub fn is_64bit(&self) -> bool {
+        matches!(
+            self,
+            Self::PowerPC64 | Self::Sparc64 | Self::X86_64 | Self::AArch64 | Self::Bpf
+        )
+    }
+
+    /// Returns the page size commonly used for this machine, as assumed by the GNU linker
+    ///
+    /// Unknown machines return `None`
+    pub fn common_page_size(&self) -> Option<u64> {
+        match self {
+            Self::Sparc64 => Some(0x2000),
+            Self::LoongArch => Some(0x4000),
+            Self::Sparc
+            | Self::X86
+            | Self::Mips
+            | Self::PowerPC
+            | Self::PowerPC64
+            | Self::S390
+            | Self::Arm
+            | Self::X86_64
+            | Self::AArch64
+            | Self::RiscV => Some(0x1000),
+            Self::None | Self::Bpf | Self::Other(_) => None,
+        }
+    }
+
+    /// Returns the largest page size supported by this machine, as assumed by the GNU linker
+    ///
+    /// Loadable segments need to be aligned to this to run on every
+    /// configuration of the machine, unknown machines return `None`
+    pub fn max_page_size(&self) -> Option<u64> {
+        match self {
+            Self::Sparc64 => Some(0x100000),
+            Self::Sparc
+            | Self::Mips
+            | Self::PowerPC
+            | Self::PowerPC64
+            | Self::Arm
+            | Self::AArch64
+            | Self::LoongArch => Some(0x10000),
+            Self::X86 | Self::S390 | Self::X86_64 | Self::RiscV => Some(0x1000),
+            Self::None | Self::Bpf | Self::Other(_) => None,
+        }
+    }
+
+    /// Returns whether this machine is natively big endian
+    ///
+    /// Bi-endian architectures report their traditional byte order
+    pub fn is_big_endian_native(&self) -> bool {
+        matches!(
+            self,
+            Self::Sparc | Self::Mips | Self::PowerPC | Self::PowerPC64 | Self::S390 | Self::Sparc64
+        )
+    }
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Other(machine) => write!(f, "<unknown>: {:#x}", machine),
+            machine => write!(f, "{}", machine.name()),
+        }
+    }
+}
+
+impl Packable for Machine {
+    fn pack<W: io::Write + io::Seek>(&self, w: &mut W, big_endian: bool) -> Result<(), io::Error> {
+        let machine: u16 = match self {
+            Machine::None => 0,
+            Machine::Sparc => 2,
+            Machine::X86 => 3,
+            Machine::Mips => 8,
+            Machine::PowerPC => 20,
+            Machine::PowerPC64 => 21,
+            Machine::S390 => 22,
+            Machine::Arm => 40,
+            Machine::Sparc64 => 43,
+            Machine::X86_64 => 62,
+            Machine::AArch64 => 183,
+            Machine::RiscV => 243,
+            Machine::Bpf => 247,
+            Machine::LoongArch => 258,
+            Machine::Other(machine) => *machine,
+        };
+
+        machine.pack(w, big_endian)
+    }
+}
+
+impl Unpackable for Machine {
+    fn unpack<R: io::Read + io::Seek>(r: &mut R, big_endian: bool) -> Result<Self, UnpackError> {
+        let machine = u16::unpack(r, big_endian)?;
+
+        Ok(match machine {
+            0 => Self::None,
+            2 => Self::Sparc,
+            3 => Self::X86,
+            8 => Self::Mips,
+            20 => Self::PowerPC,
+            21 => Self::PowerPC64,
+            22 => Self::S390,
+            40 => Self::Arm,
+            43 => Self::Sparc64,
+            62 => Self::X86_64,
+            183 => Self::AArch64,
+            243 => Self::RiscV,
+            247 => Self::Bpf,
+            258 => Self::LoongArch,
+            x => Self::Other(x),
+        })
+    }
+}
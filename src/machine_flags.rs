@@ -0,0 +1,314 @@
+use core::fmt::Display;
+
+use crate::{Header, Machine};
+
+/// The target architecture dependent flags of the ELF header,
+/// decoded for the machine of the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MachineFlags {
+    /// Flags of a 32 bit ARM file
+    Arm(ArmEFlags),
+    /// Flags of a RISC-V file
+    RiscV(RiscvEFlags),
+    /// Flags of a MIPS file
+    Mips(MipsEFlags),
+    /// Flags of a machine without decoding support
+    Raw(u32),
+}
+
+impl MachineFlags {
+    /// Decodes `flags` for `machine`
+    /// # Arguments
+    /// * `machine` - The machine of the file
+    /// * `flags` - The raw flags from the ELF header
+    pub fn new(machine: Machine, flags: u32) -> Self {
+        match machine {
+            Machine::Arm => Self::Arm(ArmEFlags(flags)),
+            Machine::RiscV => Self::RiscV(RiscvEFlags(flags)),
+            Machine::Mips => Self::Mips(MipsEFlags(flags)),
+            _ => Self::Raw(flags),
+        }
+    }
+
+    /// Returns the raw value of these flags
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::Arm(flags) => flags.0,
+            Self::RiscV(flags) => flags.0,
+            Self::Mips(flags) => flags.0,
+            Self::Raw(flags) => *flags,
+        }
+    }
+}
+
+impl From<MachineFlags> for u32 {
+    fn from(value: MachineFlags) -> Self {
+        value.value()
+    }
+}
+
+impl Display for MachineFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Arm(flags) => flags.fmt(f),
+            Self::RiscV(flags) => flags.fmt(f),
+            Self::Mips(flags) => flags.fmt(f),
+            Self::Raw(flags) => write!(f, "{:#x}", flags),
+        }
+    }
+}
+
+impl Header {
+    /// Returns the flags of this header decoded for its machine
+    pub fn decoded_flags(&self) -> MachineFlags {
+        MachineFlags::new(self.machine, self.flags)
+    }
+}
+
+/// The ELF header flags of 32 bit ARM files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ArmEFlags(pub u32);
+
+impl ArmEFlags {
+    /// `EF_ARM_EABIMASK`: The EABI version in the upper `8` bits
+    pub const EABI_MASK: Self = Self(0xff000000);
+    /// `EF_ARM_LE8`: Little endian code
+    pub const LE8: Self = Self(0x00400000);
+    /// `EF_ARM_BE8`: Big endian code with little endian instructions
+    pub const BE8: Self = Self(0x00800000);
+    /// `EF_ARM_ABI_FLOAT_SOFT`: Floating point arguments are passed in integer registers
+    pub const ABI_FLOAT_SOFT: Self = Self(0x200);
+    /// `EF_ARM_ABI_FLOAT_HARD`: Floating point arguments are passed in VFP registers
+    pub const ABI_FLOAT_HARD: Self = Self(0x400);
+
+    /// Returns whether all bits of `other` are set in `self`
+    /// # Arguments
+    /// * `other` - The flags to check for
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the EABI version, `0` for files not conforming to an EABI
+    pub fn eabi_version(&self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    /// Returns whether the file uses the soft-float calling convention
+    pub fn is_soft_float(&self) -> bool {
+        self.contains(Self::ABI_FLOAT_SOFT)
+    }
+
+    /// Returns whether the file uses the hard-float calling convention
+    pub fn is_hard_float(&self) -> bool {
+        self.contains(Self::ABI_FLOAT_HARD)
+    }
+}
+
+impl Display for ArmEFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#x}", self.0)?;
+
+        match self.eabi_version() {
+            0 => write!(f, ", GNU EABI")?,
+            version @ 1..=5 => write!(f, ", Version{} EABI", version)?,
+            _ => write!(f, ", <unrecognized EABI>")?,
+        }
+
+        if self.contains(Self::BE8) {
+            write!(f, ", BE8")?;
+        }
+        if self.contains(Self::LE8) {
+            write!(f, ", LE8")?;
+        }
+        if self.is_soft_float() {
+            write!(f, ", soft-float ABI")?;
+        }
+        if self.is_hard_float() {
+            write!(f, ", hard-float ABI")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The ELF header flags of RISC-V files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RiscvEFlags(pub u32);
+
+/// The floating point calling convention of a RISC-V file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiscvFloatAbi {
+    /// Floating point arguments are passed in integer registers
+    Soft,
+    /// 32 bit floating point arguments are passed in floating point registers
+    Single,
+    /// Up to 64 bit floating point arguments are passed in floating point registers
+    Double,
+    /// Up to 128 bit floating point arguments are passed in floating point registers
+    Quad,
+}
+
+impl RiscvEFlags {
+    /// `EF_RISCV_RVC`: The file uses compressed instructions
+    pub const RVC: Self = Self(0x1);
+    /// `EF_RISCV_FLOAT_ABI`: The mask of the floating point calling convention
+    pub const FLOAT_ABI: Self = Self(0x6);
+    /// `EF_RISCV_RVE`: The file targets the E base ISA
+    pub const RVE: Self = Self(0x8);
+    /// `EF_RISCV_TSO`: The file requires the total store ordering memory model
+    pub const TSO: Self = Self(0x10);
+
+    /// Returns whether all bits of `other` are set in `self`
+    /// # Arguments
+    /// * `other` - The flags to check for
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether the file uses compressed instructions
+    pub fn rvc(&self) -> bool {
+        self.contains(Self::RVC)
+    }
+
+    /// Returns the floating point calling convention of the file
+    pub fn float_abi(&self) -> RiscvFloatAbi {
+        match self.0 & Self::FLOAT_ABI.0 {
+            0x0 => RiscvFloatAbi::Soft,
+            0x2 => RiscvFloatAbi::Single,
+            0x4 => RiscvFloatAbi::Double,
+            _ => RiscvFloatAbi::Quad,
+        }
+    }
+
+    /// Returns whether the file targets the E base ISA
+    pub fn rve(&self) -> bool {
+        self.contains(Self::RVE)
+    }
+
+    /// Returns whether the file requires the total store ordering memory model
+    pub fn tso(&self) -> bool {
+        self.contains(Self::TSO)
+    }
+}
+
+impl Display for RiscvEFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#x}", self.0)?;
+
+        if self.rvc() {
+            write!(f, ", RVC")?;
+        }
+
+        match self.float_abi() {
+            RiscvFloatAbi::Soft => write!(f, ", soft-float ABI")?,
+            RiscvFloatAbi::Single => write!(f, ", single-float ABI")?,
+            RiscvFloatAbi::Double => write!(f, ", double-float ABI")?,
+            RiscvFloatAbi::Quad => write!(f, ", quad-float ABI")?,
+        }
+
+        if self.rve() {
+            write!(f, ", RVE")?;
+        }
+        if self.tso() {
+            write!(f, ", TSO")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The ELF header flags of MIPS files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MipsEFlags(pub u32);
+
+impl MipsEFlags {
+    /// `EF_MIPS_NOREORDER`: A `.noreorder` directive was used
+    pub const NOREORDER: Self = Self(0x1);
+    /// `EF_MIPS_PIC`: The file contains position independent code
+    pub const PIC: Self = Self(0x2);
+    /// `EF_MIPS_CPIC`: The file uses the standard PIC calling sequence
+    pub const CPIC: Self = Self(0x4);
+    /// `EF_MIPS_ABI2`: The file uses the n32 ABI
+    pub const ABI2: Self = Self(0x20);
+    /// `EF_MIPS_ABI`: The mask of the ABI
+    pub const ABI: Self = Self(0x0000f000);
+    /// `EF_MIPS_ARCH`: The mask of the architecture level
+    pub const ARCH: Self = Self(0xf0000000);
+
+    /// Returns whether all bits of `other` are set in `self`
+    /// # Arguments
+    /// * `other` - The flags to check for
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the raw architecture level (`EF_MIPS_ARCH` shifted down)
+    pub fn arch(&self) -> u32 {
+        self.0 >> 28
+    }
+
+    /// Returns the name of the architecture level (e.g. `mips32r2`)
+    pub fn arch_name(&self) -> Option<&'static str> {
+        Some(match self.arch() {
+            0x0 => "mips1",
+            0x1 => "mips2",
+            0x2 => "mips3",
+            0x3 => "mips4",
+            0x4 => "mips5",
+            0x5 => "mips32",
+            0x6 => "mips64",
+            0x7 => "mips32r2",
+            0x8 => "mips64r2",
+            0x9 => "mips32r6",
+            0xa => "mips64r6",
+            _ => return None,
+        })
+    }
+
+    /// Returns the raw ABI (`EF_MIPS_ABI` shifted down)
+    pub fn abi(&self) -> u32 {
+        (self.0 & Self::ABI.0) >> 12
+    }
+
+    /// Returns the name of the ABI (e.g. `o32`)
+    ///
+    /// The n32 ABI is flagged by [MipsEFlags::ABI2] instead,
+    /// n64 files carry no ABI flags at all
+    pub fn abi_name(&self) -> Option<&'static str> {
+        Some(match self.abi() {
+            0x1 => "o32",
+            0x2 => "o64",
+            0x3 => "eabi32",
+            0x4 => "eabi64",
+            _ => return None,
+        })
+    }
+}
+
+impl Display for MipsEFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#x}", self.0)?;
+
+        if self.contains(Self::NOREORDER) {
+            write!(f, ", noreorder")?;
+        }
+        if self.contains(Self::PIC) {
+            write!(f, ", pic")?;
+        }
+        if self.contains(Self::CPIC) {
+            write!(f, ", cpic")?;
+        }
+        if self.contains(Self::ABI2) {
+            write!(f, ", abi2")?;
+        }
+
+        if let Some(abi) = self.abi_name() {
+            write!(f, ", {}", abi)?;
+        }
+
+        match self.arch_name() {
+            Some(arch) => write!(f, ", {}", arch),
+            None => write!(f, ", unknown ISA"),
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    align_up,
+    io::{Cursor, Read, Seek, SeekFrom},
+    Blob, ProgramHeader, SectionHeader, UnpackError, Unpackable,
+};
+
+/// A single note from a note section (`SHT_NOTE`) or segment (`PT_NOTE`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Note {
+    /// The owner of the note (e.g. `GNU`), without the null terminator
+    pub name: Vec<u8>,
+    /// The type of the note, interpreted depending on `name`
+    pub ty: u32,
+    /// The descriptor of the note
+    pub desc: Vec<u8>,
+}
+
+/// Well-known note types in the `GNU` namespace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum NoteType {
+    /// `NT_GNU_ABI_TAG`: The OS and minimum kernel version
+    GnuAbiTag = 1,
+    /// `NT_GNU_BUILD_ID`: The unique build id
+    GnuBuildId = 3,
+    /// `NT_GNU_GOLD_VERSION`: The version of the gold linker
+    GnuGoldVersion = 4,
+    /// `NT_GNU_PROPERTY_TYPE_0`: Program properties
+    GnuProperty = 5,
+    /// Any other unknown note type
+    Other(u32),
+}
+
+impl NoteType {
+    /// Returns the raw value of this note type
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::GnuAbiTag => 1,
+            Self::GnuBuildId => 3,
+            Self::GnuGoldVersion => 4,
+            Self::GnuProperty => 5,
+            Self::Other(ty) => *ty,
+        }
+    }
+}
+
+impl From<u32> for NoteType {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::GnuAbiTag,
+            3 => Self::GnuBuildId,
+            4 => Self::GnuGoldVersion,
+            5 => Self::GnuProperty,
+            x => Self::Other(x),
+        }
+    }
+}
+
+impl Note {
+    /// Returns the owner of this note as a string
+    /// # Returns
+    /// `None` if the name is not valid UTF-8
+    pub fn name_str(&self) -> Option<&str> {
+        core::str::from_utf8(&self.name).ok()
+    }
+
+    /// Returns the type of this note if it is owned by `GNU`
+    pub fn gnu_type(&self) -> Option<NoteType> {
+        match self.name.as_slice() {
+            b"GNU" => Some(NoteType::from(self.ty)),
+            _ => None,
+        }
+    }
+}
+
+/// The operating system of a `NT_GNU_ABI_TAG` note
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum AbiTagOs {
+    /// `ELF_NOTE_OS_LINUX`
+    Linux = 0,
+    /// `ELF_NOTE_OS_GNU`: The GNU Hurd
+    Gnu = 1,
+    /// `ELF_NOTE_OS_SOLARIS2`
+    Solaris = 2,
+    /// `ELF_NOTE_OS_FREEBSD`
+    FreeBsd = 3,
+    /// Any other unknown operating system
+    Other(u32),
+}
+
+impl AbiTagOs {
+    /// Returns the raw value of this operating system
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::Linux => 0,
+            Self::Gnu => 1,
+            Self::Solaris => 2,
+            Self::FreeBsd => 3,
+            Self::Other(os) => *os,
+        }
+    }
+}
+
+impl From<u32> for AbiTagOs {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Linux,
+            1 => Self::Gnu,
+            2 => Self::Solaris,
+            3 => Self::FreeBsd,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// The contents of a `NT_GNU_ABI_TAG` note (`.note.ABI-tag`):
+/// The operating system and the minimum kernel version it requires
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbiTag {
+    /// The operating system
+    pub os: AbiTagOs,
+    /// The major version of the kernel
+    pub major: u32,
+    /// The minor version of the kernel
+    pub minor: u32,
+    /// The patch level of the kernel
+    pub patch: u32,
+}
+
+impl AbiTag {
+    /// Parses the descriptor of a `NT_GNU_ABI_TAG` note
+    /// # Arguments
+    /// * `desc` - The descriptor, four words
+    /// * `big_endian` - Whether the file is big endian
+    pub fn from_desc(desc: &[u8], big_endian: bool) -> Result<Self, UnpackError> {
+        let mut r = Cursor::new(desc);
+
+        Ok(Self {
+            os: AbiTagOs::from(u32::unpack(&mut r, big_endian)?),
+            major: u32::unpack(&mut r, big_endian)?,
+            minor: u32::unpack(&mut r, big_endian)?,
+            patch: u32::unpack(&mut r, big_endian)?,
+        })
+    }
+}
+
+/// The parsed notes of a note section or segment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteSection {
+    /// The notes in order of appearance
+    pub entries: Vec<Note>,
+}
+
+impl NoteSection {
+    /// Parses the notes contained in `blob`
+    ///
+    /// Names and descriptors are padded to `4` bytes, use
+    /// [NoteIter] for notes with a different alignment
+    /// # Arguments
+    /// * `blob` - The data of the note section or segment
+    /// * `big_endian` - Whether the file is big endian
+    pub fn parse(blob: &Blob, big_endian: bool) -> Result<Vec<Note>, UnpackError> {
+        NoteIter::new(blob, big_endian, 4).collect()
+    }
+
+    /// Parses the notes contained in `blob` into a note section
+    /// # Arguments
+    /// * `blob` - The data of the note section or segment
+    /// * `big_endian` - Whether the file is big endian
+    pub fn from_blob(blob: &Blob, big_endian: bool) -> Result<Self, UnpackError> {
+        Ok(Self {
+            entries: Self::parse(blob, big_endian)?,
+        })
+    }
+
+    /// Finds the first note with the owner `name` and type `ty`
+    /// # Arguments
+    /// * `name` - The owner of the note (e.g. `GNU`)
+    /// * `ty` - The raw type of the note
+    pub fn find(&self, name: &str, ty: u32) -> Option<&Note> {
+        self.entries
+            .iter()
+            .find(|e| e.name == name.as_bytes() && e.ty == ty)
+    }
+}
+
+/// An iterator over the notes in a note section or segment
+///
+/// Most notes are aligned to `4` bytes, even in ELF64 files, while some
+/// (e.g. `.note.gnu.property`) are aligned to `8` bytes. The alignment
+/// of the containing section or segment tells which one is used
+pub struct NoteIter<'a> {
+    cursor: Cursor<&'a [u8]>,
+    big_endian: bool,
+    align: u64,
+    done: bool,
+}
+
+impl<'a> NoteIter<'a> {
+    /// Creates an iterator over the notes in `data`
+    /// # Arguments
+    /// * `data` - The data of the note section or segment
+    /// * `big_endian` - Whether the file is big endian
+    /// * `align` - The alignment of names and descriptors, `8` or `4` for anything else
+    pub fn new(data: &'a Blob, big_endian: bool, align: u64) -> Self {
+        Self {
+            cursor: data.as_cursor(),
+            big_endian,
+            align: match align {
+                8 => 8,
+                _ => 4,
+            },
+            done: false,
+        }
+    }
+
+    /// Reads the next note from the cursor
+    fn read_note(&mut self) -> Result<Note, UnpackError> {
+        let name_size = u32::unpack(&mut self.cursor, self.big_endian)?;
+        let desc_size = u32::unpack(&mut self.cursor, self.big_endian)?;
+        let ty = u32::unpack(&mut self.cursor, self.big_endian)?;
+
+        let mut name = self.read_padded(name_size)?;
+        let desc = self.read_padded(desc_size)?;
+
+        if let Some(len) = name.iter().position(|b| *b == 0) {
+            name.truncate(len);
+        }
+
+        Ok(Note { name, ty, desc })
+    }
+
+    /// Reads `size` bytes and skips the padding to the next aligned offset
+    ///
+    /// The size is checked against the remaining data before allocating,
+    /// so a corrupt size cannot request an arbitrarily large buffer
+    fn read_padded(&mut self, size: u32) -> Result<Vec<u8>, UnpackError> {
+        let remaining = (self.cursor.get_ref().len() as u64).saturating_sub(self.cursor.position());
+        if size as u64 > remaining {
+            return Err(UnpackError::Truncated {
+                size: size as u64,
+                remaining,
+            });
+        }
+
+        let mut res = vec![0u8; size as usize];
+        self.cursor.read_exact(&mut res)?;
+
+        let position = align_up(self.cursor.position(), self.align).unwrap_or(u64::MAX);
+        self.cursor.seek(SeekFrom::Start(position))?;
+
+        Ok(res)
+    }
+}
+
+impl Iterator for NoteIter<'_> {
+    type Item = Result<Note, UnpackError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.cursor.position() >= self.cursor.get_ref().len() as u64 {
+            return None;
+        }
+
+        let note = self.read_note();
+        if note.is_err() {
+            self.done = true;
+        }
+
+        Some(note)
+    }
+}
+
+impl SectionHeader {
+    /// Iterates over the notes contained in this section's data
+    ///
+    /// The alignment of the notes is taken from the section alignment
+    /// # Arguments
+    /// * `big_endian` - Whether the file is big endian
+    pub fn iter_notes(&self, big_endian: bool) -> NoteIter<'_> {
+        NoteIter::new(&self.data, big_endian, self.addr_align)
+    }
+}
+
+impl ProgramHeader {
+    /// Iterates over the notes contained in this segment's data
+    ///
+    /// The alignment of the notes is taken from the segment alignment
+    /// # Arguments
+    /// * `big_endian` - Whether the file is big endian
+    pub fn iter_notes(&self, big_endian: bool) -> NoteIter<'_> {
+        NoteIter::new(&self.data, big_endian, self.alignment)
+    }
+}
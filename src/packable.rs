@@ -1,6 +1,6 @@
-use std::io::{self};
+use alloc::{format, string::String, vec::Vec};
 
-use crate::Class;
+use crate::{io, Class};
 
 /// An error while unpacking
 #[derive(Debug)]
@@ -19,16 +19,86 @@ pub enum UnpackError {
         /// The invalid variant
         variant: usize,
     },
+    /// A string table required to resolve a string could not be found
+    MissingStringTable,
+    /// A string could not be read from its string table
+    InvalidString {
+        /// The offset of the string in the table
+        offset: u64,
+    },
+    /// A section index is out of bounds
+    InvalidSectionIndex {
+        /// The invalid index
+        index: usize,
+    },
+    /// A symbol index is out of bounds
+    InvalidSymbolIndex {
+        /// The invalid index
+        index: usize,
+    },
+    /// The data of a section is required but has not been loaded
+    SectionNotLoaded {
+        /// The index of the section
+        index: usize,
+    },
+    /// A virtual address is not backed by any loaded section or segment data
+    UnmappedAddress {
+        /// The unmapped address
+        address: u64,
+    },
+    /// A size read from the data exceeds the data remaining after it
+    Truncated {
+        /// The size that was read
+        size: u64,
+        /// The amount of bytes remaining
+        remaining: u64,
+    },
     /// An IO error happened during unpacking
-    IO(std::io::Error),
+    IO(io::Error),
 }
 
-impl From<std::io::Error> for UnpackError {
-    fn from(value: std::io::Error) -> Self {
+impl From<io::Error> for UnpackError {
+    fn from(value: io::Error) -> Self {
         Self::IO(value)
     }
 }
 
+impl core::fmt::Display for UnpackError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidMagic { expected, got } => {
+                write!(f, "invalid magic: expected {:x?}, got {:x?}", expected, got)
+            }
+            Self::InvalidEnumVariant { name, variant } => {
+                write!(f, "invalid enum variant for '{}': {}", name, variant)
+            }
+            Self::MissingStringTable => write!(f, "missing string table"),
+            Self::InvalidString { offset } => write!(f, "invalid string at offset {:#x}", offset),
+            Self::InvalidSectionIndex { index } => write!(f, "invalid section index {}", index),
+            Self::InvalidSymbolIndex { index } => write!(f, "invalid symbol index {}", index),
+            Self::SectionNotLoaded { index } => {
+                write!(f, "data of section {} is not loaded", index)
+            }
+            Self::UnmappedAddress { address } => write!(f, "unmapped address {:#x}", address),
+            Self::Truncated { size, remaining } => write!(
+                f,
+                "truncated data: {} bytes needed, {} remaining",
+                size, remaining
+            ),
+            Self::IO(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl core::error::Error for UnpackError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::IO(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
 /// Allows the implementing structs to be serialized
 /// into a binary stream
 pub trait Packable {
@@ -125,9 +195,15 @@ impl PackableClass for u64 {
         class: Class,
     ) -> Result<(), io::Error> {
         if class == Class::ELF64 {
-            self.pack(w, big_endian)
-        } else {
-            (self as u32).pack(w, big_endian)
+            return self.pack(w, big_endian);
+        }
+
+        match u32::try_from(self) {
+            Ok(value) => value.pack(w, big_endian),
+            Err(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("value {:#x} does not fit into an ELF32 file", self),
+            )),
         }
     }
 }
@@ -0,0 +1,337 @@
+use std::fmt::Display;
+
+use crate::{
+    align_up, Class, ELFError, ELFFile, SectionFlags, SectionHeader, SectionType, UnpackError,
+};
+
+/// An error while patching the contents of an ELF file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatchError {
+    /// A section index is out of bounds
+    InvalidSectionIndex {
+        /// The invalid index
+        index: usize,
+    },
+    /// A program header index is out of bounds
+    InvalidProgramIndex {
+        /// The invalid index
+        index: usize,
+    },
+    /// The section occupies no space in the file (`SHT_NOBITS`)
+    NoBitsSection {
+        /// The index of the section
+        index: usize,
+    },
+    /// The new data is larger than the space available to it without moving other contents
+    SizeMismatch {
+        /// The size of the new data
+        size: u64,
+        /// The available space
+        available: u64,
+    },
+    /// An alignment is not a power of two
+    InvalidAlignment {
+        /// The invalid alignment
+        alignment: u64,
+    },
+    /// The section header table cannot hold another section
+    TooManySections,
+    /// The new contents exceed the addressable range of the file
+    OffsetOverflow,
+}
+
+impl Display for PatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidSectionIndex { index } => write!(f, "invalid section index {}", index),
+            Self::InvalidProgramIndex { index } => {
+                write!(f, "invalid program header index {}", index)
+            }
+            Self::NoBitsSection { index } => write!(f, "section {} has no data", index),
+            Self::SizeMismatch { size, available } => write!(
+                f,
+                "data of {:#x} bytes does not fit into {:#x} bytes",
+                size, available
+            ),
+            Self::InvalidAlignment { alignment } => {
+                write!(f, "alignment {:#x} is not a power of two", alignment)
+            }
+            Self::TooManySections => write!(f, "too many sections"),
+            Self::OffsetOverflow => write!(f, "offset overflows the addressable range"),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+impl ELFFile {
+    /// Sets the entry point of the file
+    /// # Arguments
+    /// * `addr` - The virtual address of the new entry point
+    pub fn patch_entry_point(&mut self, addr: u64) {
+        self.header.entry_point = addr;
+    }
+
+    /// Replaces the data of the section at `index` and updates its size
+    ///
+    /// The data may grow into the space up to the data of the next section,
+    /// a header table or the end of the segment containing the section.
+    /// The new data is also written to the loaded data of the segments
+    /// containing the section, so it is not overwritten when writing the file
+    /// # Arguments
+    /// * `index` - The index of the section
+    /// * `data` - The new data
+    pub fn patch_section_data(&mut self, index: usize, data: Vec<u8>) -> Result<(), PatchError> {
+        let section = self
+            .section_headers
+            .get(index)
+            .ok_or(PatchError::InvalidSectionIndex { index })?;
+
+        if section.ty == SectionType::NoBits {
+            return Err(PatchError::NoBitsSection { index });
+        }
+
+        let size = data.len() as u64;
+        if size > section.size {
+            let available = self.available_space(index, section.offset);
+            if size > available {
+                return Err(PatchError::SizeMismatch { size, available });
+            }
+        }
+
+        let section = &mut self.section_headers[index];
+        section.data = data.into();
+        section.size = size;
+
+        let section = &self.section_headers[index];
+        for program in &mut self.program_headers {
+            if program.data.len() as u64 == program.file_size {
+                copy_overlap(
+                    &mut program.data,
+                    program.offset,
+                    &section.data,
+                    section.offset,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the data of the segment at `index` and updates its file size
+    ///
+    /// A segment cannot grow, as this would move the memory image around.
+    /// The new data is also written to the loaded data of the sections
+    /// within the segment, as section data takes precedence when writing the file
+    /// # Arguments
+    /// * `index` - The index of the program header
+    /// * `data` - The new data
+    pub fn patch_program_data(&mut self, index: usize, data: Vec<u8>) -> Result<(), PatchError> {
+        let program = self
+            .program_headers
+            .get_mut(index)
+            .ok_or(PatchError::InvalidProgramIndex { index })?;
+
+        let size = data.len() as u64;
+        if size > program.file_size {
+            return Err(PatchError::SizeMismatch {
+                size,
+                available: program.file_size,
+            });
+        }
+
+        program.data = data.into();
+        program.file_size = size;
+
+        let program = &self.program_headers[index];
+        for section in &mut self.section_headers {
+            if section.has_file_data() && section.data.len() as u64 == section.size {
+                copy_overlap(
+                    &mut section.data,
+                    section.offset,
+                    &program.data,
+                    program.offset,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends a new section to the file
+    ///
+    /// The data is placed after the existing contents of the file and the name
+    /// is appended to the section name string table, which is moved to the end
+    /// if it cannot grow in place. The section header table is moved behind both.
+    /// The data of all sections must be loaded using [ELFFile::load_fully]
+    /// to write the file afterwards
+    /// # Arguments
+    /// * `name` - The name of the section
+    /// * `ty` - The type of the section
+    /// * `flags` - The flags of the section
+    /// * `data` - The contents of the section, only its length is used for `SHT_NOBITS`
+    /// * `alignment` - The alignment of the data in the file, a power of two or `0`
+    /// # Returns
+    /// The index of the new section
+    pub fn inject_section(
+        &mut self,
+        name: &str,
+        ty: SectionType,
+        flags: SectionFlags,
+        data: Vec<u8>,
+        alignment: u64,
+    ) -> Result<usize, ELFError> {
+        if alignment > 1 && !alignment.is_power_of_two() {
+            return Err(PatchError::InvalidAlignment { alignment }.into());
+        }
+
+        let index = self.section_headers.len();
+        if index >= u32::MAX as usize {
+            return Err(PatchError::TooManySections.into());
+        }
+
+        let names_index = self
+            .section_names_index()
+            .filter(|i| *i < index)
+            .ok_or(UnpackError::MissingStringTable)?;
+
+        let names = &self.section_headers[names_index];
+        if names.data.len() as u64 != names.size {
+            return Err(UnpackError::SectionNotLoaded { index: names_index }.into());
+        }
+
+        let name_offset = u32::try_from(names.size).map_err(|_| PatchError::OffsetOverflow)?;
+        let mut names = names.data.to_vec();
+        names.extend_from_slice(name.as_bytes());
+        names.push(0);
+
+        let offset = align_up(self.contents_end(), alignment).ok_or(PatchError::OffsetOverflow)?;
+        let size = data.len() as u64;
+
+        let (data, mut end) = match ty {
+            SectionType::NoBits => (Vec::new(), offset),
+            _ => (
+                data,
+                offset.checked_add(size).ok_or(PatchError::OffsetOverflow)?,
+            ),
+        };
+
+        self.section_headers.push(SectionHeader {
+            name: name_offset,
+            ty,
+            flags,
+            address: 0,
+            offset,
+            size,
+            link: 0,
+            info: 0,
+            addr_align: alignment,
+            entry_size: 0,
+            data: data.into(),
+        });
+
+        if self.patch_section_data(names_index, names.clone()).is_err() {
+            let section = &mut self.section_headers[names_index];
+            section.offset = end;
+            section.size = names.len() as u64;
+            section.data = names.into();
+
+            end = end
+                .checked_add(section.size)
+                .ok_or(PatchError::OffsetOverflow)?;
+        }
+
+        let word_size = match self.header.ident.class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        let sh_offset = align_up(end, word_size).ok_or(PatchError::OffsetOverflow)?;
+        let (null_size, null_link) = self.header.set_section_header_table(
+            sh_offset,
+            self.section_headers.len(),
+            names_index,
+        );
+        let null = &mut self.section_headers[0];
+        null.size = null_size;
+        null.link = null_link;
+
+        Ok(index)
+    }
+
+    /// Returns the end of the contents of the file, excluding the section header table
+    fn contents_end(&self) -> u64 {
+        let program_table = self.header.ph_offset.saturating_add(
+            self.header.get_program_header_size() * self.program_headers.len() as u64,
+        );
+
+        let sections = self
+            .section_headers
+            .iter()
+            .filter(|s| s.has_file_data())
+            .map(|s| s.offset.saturating_add(s.size));
+
+        let programs = self
+            .program_headers
+            .iter()
+            .map(|p| p.offset.saturating_add(p.file_size));
+
+        sections
+            .chain(programs)
+            .fold(self.header.get_header_size().max(program_table), u64::max)
+    }
+
+    /// Returns the space available to the data of the section at `index` starting at `offset`
+    fn available_space(&self, index: usize, offset: u64) -> u64 {
+        let sections = self
+            .section_headers
+            .iter()
+            .enumerate()
+            .filter(|(i, s)| *i != index && s.has_file_data() && s.size != 0)
+            .map(|(_, s)| s.offset);
+
+        let tables = [
+            (self.header.ph_offset, !self.program_headers.is_empty()),
+            (self.header.sh_offset, !self.section_headers.is_empty()),
+        ]
+        .into_iter()
+        .filter(|(_, present)| *present)
+        .map(|(offset, _)| offset);
+
+        let segments = self
+            .program_headers
+            .iter()
+            .filter(|p| {
+                p.file_size != 0
+                    && p.offset <= offset
+                    && offset < p.offset.saturating_add(p.file_size)
+            })
+            .map(|p| p.offset.saturating_add(p.file_size));
+
+        sections
+            .chain(tables)
+            .filter(|o| *o > offset)
+            .chain(segments)
+            .min()
+            .map_or(u64::MAX, |end| end - offset)
+    }
+}
+
+/// Copies the part of `src` that overlaps `dst` in the file
+/// # Arguments
+/// * `dst` - The data to copy to
+/// * `dst_offset` - The offset of `dst` in the file
+/// * `src` - The data to copy from
+/// * `src_offset` - The offset of `src` in the file
+fn copy_overlap(dst: &mut [u8], dst_offset: u64, src: &[u8], src_offset: u64) {
+    let start = dst_offset.max(src_offset);
+    let end = dst_offset
+        .saturating_add(dst.len() as u64)
+        .min(src_offset.saturating_add(src.len() as u64));
+    if start >= end {
+        return;
+    }
+
+    let (start, end) = ((start - dst_offset) as usize, (end - dst_offset) as usize);
+    let from = (dst_offset + start as u64 - src_offset) as usize;
+    dst[start..end].copy_from_slice(&src[from..from + end - start]);
+}
@@ -1,19 +1,18 @@
-use std::{fmt::Debug, io};
+use alloc::{string::ToString, vec::Vec};
+use core::fmt::{Debug, Display};
 
-use crate::{Blob, Packable, PackableClass, UnpackError, Unpackable, UnpackableClass};
+use crate::{io, Address, Blob, Packable, PackableClass, UnpackError, Unpackable, UnpackableClass};
 
 use super::Class;
 
 /// A program header in the ELF file
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProgramHeader {
     /// The type of segment at hand
     pub ty: ProgramHeaderType,
-    /// The flags for this segment:
-    /// - `0x01`: Executable
-    /// - `0x02`: Writable
-    /// - `0x04`: Readable
-    pub flags: u32,
+    /// The flags for this segment
+    pub flags: SegmentFlags,
     /// The offset of the segment in the file image
     pub offset: u64,
     /// The virtual address of this segment in memory
@@ -30,22 +29,81 @@ pub struct ProgramHeader {
     pub data: Blob,
 }
 
+impl ProgramHeader {
+    /// Returns the virtual address of this segment as an [Address]
+    pub fn virtual_address(&self) -> Address {
+        Address(self.virtual_addr)
+    }
+
+    /// Returns the physical address of this segment as an [Address]
+    pub fn physical_address(&self) -> Address {
+        Address(self.physical_addr)
+    }
+
+    /// Returns the offset of this segment in the file as an [Address]
+    pub fn file_offset(&self) -> Address {
+        Address(self.offset)
+    }
+
+    /// Loads the data of this segment from `r` if it has not been loaded yet
+    /// # Arguments
+    /// * `r` - The stream of the file to read from
+    pub fn load_data<R: io::Read + io::Seek>(&mut self, r: &mut R) -> Result<(), io::Error> {
+        if self.data.is_empty() && self.file_size != 0 {
+            self.data = Blob::load(r, self.offset, self.file_size as usize)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the file offset and virtual address of this segment
+    /// are congruent modulo `page_size`, as required for mapping it
+    /// # Arguments
+    /// * `page_size` - The page size to check against, `0` and `1` always match
+    pub fn is_congruent(&self, page_size: u64) -> bool {
+        if page_size <= 1 {
+            return true;
+        }
+
+        self.offset % page_size == self.virtual_addr % page_size
+    }
+}
+
+/// Displays in the form of a `readelf -l` line: Type, offset, virtual address,
+/// physical address, file size, memory size, flags and alignment
+impl Display for ProgramHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:<14} {:#08x} {:#018x} {:#018x} {:#08x} {:#08x} {} {:#x}",
+            self.ty.to_string(),
+            self.offset,
+            self.virtual_addr,
+            self.physical_addr,
+            self.file_size,
+            self.mem_size,
+            self.flags,
+            self.alignment
+        )
+    }
+}
+
 impl PackableClass for ProgramHeader {
-    fn pack_class<W: std::io::Write + io::Seek>(
+    fn pack_class<W: io::Write + io::Seek>(
         self,
         w: &mut W,
         big_endian: bool,
         class: super::Class,
-    ) -> Result<(), std::io::Error> {
+    ) -> Result<(), io::Error> {
         self.ty.pack(w, big_endian)?;
 
         if class == Class::ELF64 {
             self.flags.pack(w, big_endian)?;
         };
 
-        self.offset.pack_class(w, big_endian, class)?;
-        self.virtual_addr.pack_class(w, big_endian, class)?;
-        self.physical_addr.pack_class(w, big_endian, class)?;
+        Address(self.offset).pack_class(w, big_endian, class)?;
+        Address(self.virtual_addr).pack_class(w, big_endian, class)?;
+        Address(self.physical_addr).pack_class(w, big_endian, class)?;
         self.file_size.pack_class(w, big_endian, class)?;
         self.mem_size.pack_class(w, big_endian, class)?;
 
@@ -60,7 +118,7 @@ impl PackableClass for ProgramHeader {
 }
 
 impl UnpackableClass for ProgramHeader {
-    fn unpack_class<R: std::io::Read + io::Seek>(
+    fn unpack_class<R: io::Read + io::Seek>(
         r: &mut R,
         big_endian: bool,
         class: super::Class,
@@ -68,9 +126,9 @@ impl UnpackableClass for ProgramHeader {
         let ty = ProgramHeaderType::unpack(r, big_endian)?;
 
         let flags = if class == Class::ELF64 {
-            u32::unpack(r, big_endian)?
+            SegmentFlags::unpack(r, big_endian)?
         } else {
-            0
+            SegmentFlags::default()
         };
 
         let offset = u64::unpack_class(r, big_endian, class)?;
@@ -80,15 +138,13 @@ impl UnpackableClass for ProgramHeader {
         let mem_size = u64::unpack_class(r, big_endian, class)?;
 
         let flags = if class == Class::ELF32 {
-            u32::unpack(r, big_endian)?
+            SegmentFlags::unpack(r, big_endian)?
         } else {
             flags
         };
 
         let alignment = u64::unpack_class(r, big_endian, class)?;
 
-        let data = Blob::load(r, offset, file_size as usize)?;
-
         Ok(Self {
             ty,
             flags,
@@ -98,13 +154,132 @@ impl UnpackableClass for ProgramHeader {
             file_size,
             mem_size,
             alignment,
-            data,
+            data: Blob { blob: Vec::new() },
         })
     }
 }
 
+/// The permission flags of a segment
+///
+/// Displays in the form `readelf` uses (e.g. `R E`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SegmentFlags(pub u32);
+
+impl SegmentFlags {
+    /// The segment is executable
+    pub const EXECUTE: Self = Self(0x1);
+    /// The segment is writable
+    pub const WRITE: Self = Self(0x2);
+    /// The segment is readable
+    pub const READ: Self = Self(0x4);
+    /// The mask for operating system specific flags
+    pub const MASKOS: Self = Self(0x0ff00000);
+    /// The mask for processor specific flags
+    pub const MASKPROC: Self = Self(0xf0000000);
+
+    /// Creates flags from their raw value
+    /// # Arguments
+    /// * `raw` - The raw value of the flags
+    pub fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw value of these flags
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether the segment is readable
+    pub fn is_readable(&self) -> bool {
+        self.0 & Self::READ.0 != 0
+    }
+
+    /// Returns whether the segment is writable
+    pub fn is_writable(&self) -> bool {
+        self.0 & Self::WRITE.0 != 0
+    }
+
+    /// Returns whether the segment is executable
+    pub fn is_executable(&self) -> bool {
+        self.0 & Self::EXECUTE.0 != 0
+    }
+
+    /// Returns whether the segment is readable
+    #[deprecated(note = "use is_readable")]
+    pub fn readable(&self) -> bool {
+        self.is_readable()
+    }
+
+    /// Returns whether the segment is writable
+    #[deprecated(note = "use is_writable")]
+    pub fn writable(&self) -> bool {
+        self.is_writable()
+    }
+
+    /// Returns whether the segment is executable
+    #[deprecated(note = "use is_executable")]
+    pub fn executable(&self) -> bool {
+        self.is_executable()
+    }
+
+    /// Sets or clears the readable bit, leaving all other bits untouched
+    /// # Arguments
+    /// * `readable` - Whether the segment should be readable
+    pub fn with_readable(self, readable: bool) -> Self {
+        self.with(Self::READ, readable)
+    }
+
+    /// Sets or clears the writable bit, leaving all other bits untouched
+    /// # Arguments
+    /// * `writable` - Whether the segment should be writable
+    pub fn with_writable(self, writable: bool) -> Self {
+        self.with(Self::WRITE, writable)
+    }
+
+    /// Sets or clears the executable bit, leaving all other bits untouched
+    /// # Arguments
+    /// * `executable` - Whether the segment should be executable
+    pub fn with_executable(self, executable: bool) -> Self {
+        self.with(Self::EXECUTE, executable)
+    }
+
+    fn with(self, flag: Self, set: bool) -> Self {
+        if set {
+            Self(self.0 | flag.0)
+        } else {
+            Self(self.0 & !flag.0)
+        }
+    }
+}
+
+impl Display for SegmentFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            if self.is_readable() { 'R' } else { ' ' },
+            if self.is_writable() { 'W' } else { ' ' },
+            if self.is_executable() { 'E' } else { ' ' }
+        )
+    }
+}
+
+impl Packable for SegmentFlags {
+    fn pack<W: io::Write + io::Seek>(&self, w: &mut W, big_endian: bool) -> Result<(), io::Error> {
+        self.0.pack(w, big_endian)
+    }
+}
+
+impl Unpackable for SegmentFlags {
+    fn unpack<R: io::Read + io::Seek>(r: &mut R, big_endian: bool) -> Result<Self, UnpackError> {
+        Ok(Self(u32::unpack(r, big_endian)?))
+    }
+}
+
 /// The type of program header at hand
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum ProgramHeaderType {
     /// A unused program
@@ -115,12 +290,56 @@ pub enum ProgramHeaderType {
     Dynamic = 0x2,
     /// The interpreter to run this executable with
     Interpreter = 0x3,
+    /// Auxiliary information (notes)
+    Note = 0x4,
+    /// Reserved, with unspecified semantics
+    SharedLibrary = 0x5,
     /// The program header tables
     ProgramHeaderTable = 0x6,
+    /// The thread-local storage template
+    ThreadLocalStorage = 0x7,
+    /// The GNU `.eh_frame_hdr` exception handling information
+    GnuEhFrame = 0x6474e550,
+    /// The GNU stack executability indicator
+    GnuStack = 0x6474e551,
+    /// The GNU read-only after relocation region
+    GnuRelro = 0x6474e552,
+    /// The GNU `.note.gnu.property` section
+    GnuProperty = 0x6474e553,
+    /// The Sun `.bss` segment
+    SunwBss = 0x6ffffffa,
+    /// The Sun stack segment
+    SunwStack = 0x6ffffffb,
     /// Any other unknown program type
     Other(u32),
 }
 
+impl Display for ProgramHeaderType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unused => write!(f, "NULL"),
+            Self::Loadable => write!(f, "LOAD"),
+            Self::Dynamic => write!(f, "DYNAMIC"),
+            Self::Interpreter => write!(f, "INTERP"),
+            Self::Note => write!(f, "NOTE"),
+            Self::SharedLibrary => write!(f, "SHLIB"),
+            Self::ProgramHeaderTable => write!(f, "PHDR"),
+            Self::ThreadLocalStorage => write!(f, "TLS"),
+            Self::GnuEhFrame => write!(f, "GNU_EH_FRAME"),
+            Self::GnuStack => write!(f, "GNU_STACK"),
+            Self::GnuRelro => write!(f, "GNU_RELRO"),
+            Self::GnuProperty => write!(f, "GNU_PROPERTY"),
+            Self::SunwBss => write!(f, "SUNWBSS"),
+            Self::SunwStack => write!(f, "SUNWSTACK"),
+            Self::Other(ty @ 0x60000000..=0x6fffffff) => write!(f, "LOOS+{:x}", ty - 0x60000000),
+            Self::Other(ty @ 0x70000000..=0x7fffffff) => {
+                write!(f, "LOPROC+{:x}", ty - 0x70000000)
+            }
+            Self::Other(ty) => write!(f, "<unknown>: {:x}", ty),
+        }
+    }
+}
+
 impl Packable for ProgramHeaderType {
     fn pack<W: io::Write + io::Seek>(&self, w: &mut W, big_endian: bool) -> Result<(), io::Error> {
         let ty: u32 = match self {
@@ -128,7 +347,16 @@ impl Packable for ProgramHeaderType {
             ProgramHeaderType::Loadable => 1,
             ProgramHeaderType::Dynamic => 2,
             ProgramHeaderType::Interpreter => 3,
+            ProgramHeaderType::Note => 4,
+            ProgramHeaderType::SharedLibrary => 5,
             ProgramHeaderType::ProgramHeaderTable => 6,
+            ProgramHeaderType::ThreadLocalStorage => 7,
+            ProgramHeaderType::GnuEhFrame => 0x6474e550,
+            ProgramHeaderType::GnuStack => 0x6474e551,
+            ProgramHeaderType::GnuRelro => 0x6474e552,
+            ProgramHeaderType::GnuProperty => 0x6474e553,
+            ProgramHeaderType::SunwBss => 0x6ffffffa,
+            ProgramHeaderType::SunwStack => 0x6ffffffb,
             ProgramHeaderType::Other(ty) => *ty,
         };
 
@@ -145,7 +373,16 @@ impl Unpackable for ProgramHeaderType {
             0x1 => Self::Loadable,
             0x2 => Self::Dynamic,
             0x3 => Self::Interpreter,
+            0x4 => Self::Note,
+            0x5 => Self::SharedLibrary,
             0x6 => Self::ProgramHeaderTable,
+            0x7 => Self::ThreadLocalStorage,
+            0x6474e550 => Self::GnuEhFrame,
+            0x6474e551 => Self::GnuStack,
+            0x6474e552 => Self::GnuRelro,
+            0x6474e553 => Self::GnuProperty,
+            0x6ffffffa => Self::SunwBss,
+            0x6ffffffb => Self::SunwStack,
             x => Self::Other(x),
         })
     }
@@ -0,0 +1,350 @@
+use alloc::{format, vec::Vec};
+use core::marker::PhantomData;
+
+use crate::{
+    io::{self, Cursor, Seek, SeekFrom},
+    Aarch64RelocationType, Class, Machine, PackableClass, RiscVRelocationType, SectionHeader,
+    SectionType, UnpackError, UnpackableClass, X86_64RelocationType,
+};
+
+/// A relocation entry without an explicit addend (`SHT_REL`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelEntry {
+    /// The location to apply the relocation at
+    pub offset: u64,
+    /// The symbol index and relocation type, packed depending on the class
+    pub info: u64,
+}
+
+/// A relocation entry with an explicit addend (`SHT_RELA`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelaEntry {
+    /// The location to apply the relocation at
+    pub offset: u64,
+    /// The symbol index and relocation type, packed depending on the class
+    pub info: u64,
+    /// The constant addend used to compute the relocated value
+    pub addend: i64,
+}
+
+/// A relocation type decoded for the machine it belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelocationType {
+    /// A relocation of the x86-64 architecture
+    X86_64(X86_64RelocationType),
+    /// A relocation of the AArch64 architecture
+    Aarch64(Aarch64RelocationType),
+    /// A relocation of the RISC-V architecture
+    RiscV(RiscVRelocationType),
+    /// A relocation of a machine without decoding support
+    Other(u32),
+}
+
+impl RelocationType {
+    /// Decodes a raw relocation type for `machine`
+    /// # Arguments
+    /// * `machine` - The machine the relocation belongs to
+    /// * `ty` - The raw relocation type
+    pub fn new(machine: Machine, ty: u32) -> Self {
+        match machine {
+            Machine::X86_64 => Self::X86_64(ty.into()),
+            Machine::AArch64 => Self::Aarch64(ty.into()),
+            Machine::RiscV => Self::RiscV(ty.into()),
+            _ => Self::Other(ty),
+        }
+    }
+
+    /// Returns the raw value of this relocation type
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::X86_64(ty) => ty.value(),
+            Self::Aarch64(ty) => ty.value(),
+            Self::RiscV(ty) => ty.value(),
+            Self::Other(ty) => *ty,
+        }
+    }
+
+    /// Returns the name of this relocation type as displayed by `readelf`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::X86_64(ty) => ty.name(),
+            Self::Aarch64(ty) => ty.name(),
+            Self::RiscV(ty) => ty.name(),
+            Self::Other(_) => "<unknown>",
+        }
+    }
+}
+
+/// Splits `info` into the symbol index and relocation type
+fn split_info(info: u64, class: Class) -> (u32, u32) {
+    match class {
+        Class::ELF32 => ((info >> 8) as u32, (info & 0xff) as u32),
+        Class::ELF64 => ((info >> 32) as u32, (info & 0xffffffff) as u32),
+    }
+}
+
+impl RelEntry {
+    /// Returns the size a relocation takes in a table of `class`
+    /// # Arguments
+    /// * `class` - The ELF class of the relocation table
+    pub fn get_size(class: Class) -> u64 {
+        match class {
+            Class::ELF32 => 0x8,
+            Class::ELF64 => 0x10,
+        }
+    }
+
+    /// Returns the index of the symbol this relocation references
+    /// # Arguments
+    /// * `class` - The ELF class of the file, defining how `info` is split
+    pub fn symbol_index(&self, class: Class) -> u32 {
+        split_info(self.info, class).0
+    }
+
+    /// Returns the processor specific type of this relocation
+    /// # Arguments
+    /// * `class` - The ELF class of the file, defining how `info` is split
+    pub fn relocation_type(&self, class: Class) -> u32 {
+        split_info(self.info, class).1
+    }
+
+    /// Returns the type of this relocation decoded for `machine`
+    /// # Arguments
+    /// * `machine` - The machine of the file
+    /// * `class` - The ELF class of the file, defining how `info` is split
+    pub fn typed(&self, machine: Machine, class: Class) -> RelocationType {
+        RelocationType::new(machine, self.relocation_type(class))
+    }
+}
+
+impl RelaEntry {
+    /// Returns the size a relocation takes in a table of `class`
+    /// # Arguments
+    /// * `class` - The ELF class of the relocation table
+    pub fn get_size(class: Class) -> u64 {
+        match class {
+            Class::ELF32 => 0xC,
+            Class::ELF64 => 0x18,
+        }
+    }
+
+    /// Returns the index of the symbol this relocation references
+    /// # Arguments
+    /// * `class` - The ELF class of the file, defining how `info` is split
+    pub fn symbol_index(&self, class: Class) -> u32 {
+        split_info(self.info, class).0
+    }
+
+    /// Returns the processor specific type of this relocation
+    /// # Arguments
+    /// * `class` - The ELF class of the file, defining how `info` is split
+    pub fn relocation_type(&self, class: Class) -> u32 {
+        split_info(self.info, class).1
+    }
+
+    /// Returns the type of this relocation decoded for `machine`
+    /// # Arguments
+    /// * `machine` - The machine of the file
+    /// * `class` - The ELF class of the file, defining how `info` is split
+    pub fn typed(&self, machine: Machine, class: Class) -> RelocationType {
+        RelocationType::new(machine, self.relocation_type(class))
+    }
+}
+
+impl PackableClass for RelEntry {
+    fn pack_class<W: io::Write + io::Seek>(
+        self,
+        w: &mut W,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<(), io::Error> {
+        self.offset.pack_class(w, big_endian, class)?;
+        self.info.pack_class(w, big_endian, class)?;
+
+        Ok(())
+    }
+}
+
+impl UnpackableClass for RelEntry {
+    fn unpack_class<R: io::Read + io::Seek>(
+        r: &mut R,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<Self, UnpackError> {
+        Ok(Self {
+            offset: u64::unpack_class(r, big_endian, class)?,
+            info: u64::unpack_class(r, big_endian, class)?,
+        })
+    }
+}
+
+impl PackableClass for RelaEntry {
+    fn pack_class<W: io::Write + io::Seek>(
+        self,
+        w: &mut W,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<(), io::Error> {
+        self.offset.pack_class(w, big_endian, class)?;
+        self.info.pack_class(w, big_endian, class)?;
+
+        // The addend is signed, so it is truncated to 32 bits after checking its range
+        let addend = match class {
+            Class::ELF32 => i32::try_from(self.addend).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("addend {:#x} does not fit into an ELF32 file", self.addend),
+                )
+            })? as u32 as u64,
+            Class::ELF64 => self.addend as u64,
+        };
+        addend.pack_class(w, big_endian, class)?;
+
+        Ok(())
+    }
+}
+
+impl UnpackableClass for RelaEntry {
+    fn unpack_class<R: io::Read + io::Seek>(
+        r: &mut R,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<Self, UnpackError> {
+        let offset = u64::unpack_class(r, big_endian, class)?;
+        let info = u64::unpack_class(r, big_endian, class)?;
+        let addend = u64::unpack_class(r, big_endian, class)?;
+
+        // The addend is signed, so it needs sign extension in 32 bit files
+        let addend = match class {
+            Class::ELF32 => addend as u32 as i32 as i64,
+            Class::ELF64 => addend as i64,
+        };
+
+        Ok(Self {
+            offset,
+            info,
+            addend,
+        })
+    }
+}
+
+/// A parsed relocation table (`SHT_REL` or `SHT_RELA`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelocationTable {
+    /// Relocations without addends
+    Rel(Vec<RelEntry>),
+    /// Relocations with addends
+    Rela(Vec<RelaEntry>),
+}
+
+impl RelocationTable {
+    /// Parses the relocation table contained in `section`
+    ///
+    /// Sections of type `SHT_RELA` are parsed as [RelaEntry], all others as [RelEntry]
+    /// # Arguments
+    /// * `section` - The relocation section
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn from_section(
+        section: &SectionHeader,
+        class: Class,
+        big_endian: bool,
+    ) -> Result<RelocationTable, UnpackError> {
+        Ok(match section.ty {
+            SectionType::Rela => Self::Rela(
+                section
+                    .iter_rela(class, big_endian)
+                    .collect::<Result<_, _>>()?,
+            ),
+            _ => Self::Rel(
+                section
+                    .iter_rel(class, big_endian)
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+
+    /// Returns the amount of relocations in this table
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Rel(entries) => entries.len(),
+            Self::Rela(entries) => entries.len(),
+        }
+    }
+
+    /// Returns whether this table contains no relocations
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// An iterator over the entries of a relocation section
+pub struct RelocationIter<'a, T> {
+    cursor: Cursor<&'a [u8]>,
+    big_endian: bool,
+    class: Class,
+    entry_size: u64,
+    index: u64,
+    count: u64,
+    entry: PhantomData<T>,
+}
+
+impl<'a, T> RelocationIter<'a, T> {
+    fn new(section: &'a SectionHeader, class: Class, big_endian: bool, size: u64) -> Self {
+        let entry_size = match section.entry_size {
+            0 => size,
+            entry_size => entry_size,
+        };
+
+        Self {
+            cursor: section.data.as_cursor(),
+            big_endian,
+            class,
+            entry_size,
+            index: 0,
+            count: section.data.blob.len() as u64 / entry_size,
+            entry: PhantomData,
+        }
+    }
+}
+
+impl<T: UnpackableClass> Iterator for RelocationIter<'_, T> {
+    type Item = Result<T, UnpackError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let offset = self.index * self.entry_size;
+        self.index += 1;
+
+        if let Err(e) = self.cursor.seek(SeekFrom::Start(offset)) {
+            return Some(Err(e.into()));
+        }
+
+        Some(T::unpack_class(
+            &mut self.cursor,
+            self.big_endian,
+            self.class,
+        ))
+    }
+}
+
+impl SectionHeader {
+    /// Iterates over the relocations without addends contained in this section's data
+    /// # Arguments
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn iter_rel(&self, class: Class, big_endian: bool) -> RelocationIter<'_, RelEntry> {
+        RelocationIter::new(self, class, big_endian, RelEntry::get_size(class))
+    }
+
+    /// Iterates over the relocations with addends contained in this section's data
+    /// # Arguments
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn iter_rela(&self, class: Class, big_endian: bool) -> RelocationIter<'_, RelaEntry> {
+        RelocationIter::new(self, class, big_endian, RelaEntry::get_size(class))
+    }
+}
@@ -0,0 +1,342 @@
+/// A relocation type of the AArch64 architecture
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Aarch64RelocationType {
+    /// `R_AARCH64_NONE`: No relocation
+    None = 0,
+    /// `R_AARCH64_ABS64`: Direct 64 bit
+    Abs64 = 257,
+    /// `R_AARCH64_ABS32`: Direct 32 bit
+    Abs32 = 258,
+    /// `R_AARCH64_ABS16`: Direct 16 bit
+    Abs16 = 259,
+    /// `R_AARCH64_PREL64`: PC relative 64 bit
+    Prel64 = 260,
+    /// `R_AARCH64_PREL32`: PC relative 32 bit
+    Prel32 = 261,
+    /// `R_AARCH64_PREL16`: PC relative 16 bit
+    Prel16 = 262,
+    /// `R_AARCH64_MOVW_UABS_G0`: Direct MOVZ imm from bits 15:0
+    MovwUAbsG0 = 263,
+    /// `R_AARCH64_MOVW_UABS_G0_NC`: Direct MOVK imm from bits 15:0
+    MovwUAbsG0Nc = 264,
+    /// `R_AARCH64_MOVW_UABS_G1`: Direct MOVZ imm from bits 31:16
+    MovwUAbsG1 = 265,
+    /// `R_AARCH64_MOVW_UABS_G1_NC`: Direct MOVK imm from bits 31:16
+    MovwUAbsG1Nc = 266,
+    /// `R_AARCH64_MOVW_UABS_G2`: Direct MOVZ imm from bits 47:32
+    MovwUAbsG2 = 267,
+    /// `R_AARCH64_MOVW_UABS_G2_NC`: Direct MOVK imm from bits 47:32
+    MovwUAbsG2Nc = 268,
+    /// `R_AARCH64_MOVW_UABS_G3`: Direct MOVZ imm from bits 63:48
+    MovwUAbsG3 = 269,
+    /// `R_AARCH64_MOVW_SABS_G0`: Direct MOVN/MOVZ imm from bits 15:0
+    MovwSAbsG0 = 270,
+    /// `R_AARCH64_MOVW_SABS_G1`: Direct MOVN/MOVZ imm from bits 31:16
+    MovwSAbsG1 = 271,
+    /// `R_AARCH64_MOVW_SABS_G2`: Direct MOVN/MOVZ imm from bits 47:32
+    MovwSAbsG2 = 272,
+    /// `R_AARCH64_LD_PREL_LO19`: PC relative LD imm from bits 20:2
+    LdPrelLo19 = 273,
+    /// `R_AARCH64_ADR_PREL_LO21`: PC relative ADR imm from bits 20:0
+    AdrPrelLo21 = 274,
+    /// `R_AARCH64_ADR_PREL_PG_HI21`: Page relative ADRP imm from bits 32:12
+    AdrPrelPgHi21 = 275,
+    /// `R_AARCH64_ADR_PREL_PG_HI21_NC`: Page relative ADRP imm from bits 32:12, no overflow check
+    AdrPrelPgHi21Nc = 276,
+    /// `R_AARCH64_ADD_ABS_LO12_NC`: Direct ADD imm from bits 11:0
+    AddAbsLo12Nc = 277,
+    /// `R_AARCH64_LDST8_ABS_LO12_NC`: Direct LD/ST imm from bits 11:0
+    Ldst8AbsLo12Nc = 278,
+    /// `R_AARCH64_TSTBR14`: PC relative TBZ/TBNZ imm from bits 15:2
+    TstBr14 = 279,
+    /// `R_AARCH64_CONDBR19`: PC relative B.cond imm from bits 20:2
+    CondBr19 = 280,
+    /// `R_AARCH64_JUMP26`: PC relative B imm from bits 27:2
+    Jump26 = 282,
+    /// `R_AARCH64_CALL26`: PC relative BL imm from bits 27:2
+    Call26 = 283,
+    /// `R_AARCH64_LDST16_ABS_LO12_NC`: Direct LD/ST imm from bits 11:1
+    Ldst16AbsLo12Nc = 284,
+    /// `R_AARCH64_LDST32_ABS_LO12_NC`: Direct LD/ST imm from bits 11:2
+    Ldst32AbsLo12Nc = 285,
+    /// `R_AARCH64_LDST64_ABS_LO12_NC`: Direct LD/ST imm from bits 11:3
+    Ldst64AbsLo12Nc = 286,
+    /// `R_AARCH64_MOVW_PREL_G0`: PC relative MOVN/MOVZ imm from bits 15:0
+    MovwPrelG0 = 287,
+    /// `R_AARCH64_MOVW_PREL_G0_NC`: PC relative MOVK imm from bits 15:0
+    MovwPrelG0Nc = 288,
+    /// `R_AARCH64_MOVW_PREL_G1`: PC relative MOVN/MOVZ imm from bits 31:16
+    MovwPrelG1 = 289,
+    /// `R_AARCH64_MOVW_PREL_G1_NC`: PC relative MOVK imm from bits 31:16
+    MovwPrelG1Nc = 290,
+    /// `R_AARCH64_MOVW_PREL_G2`: PC relative MOVN/MOVZ imm from bits 47:32
+    MovwPrelG2 = 291,
+    /// `R_AARCH64_MOVW_PREL_G2_NC`: PC relative MOVK imm from bits 47:32
+    MovwPrelG2Nc = 292,
+    /// `R_AARCH64_MOVW_PREL_G3`: PC relative MOVN/MOVZ imm from bits 63:48
+    MovwPrelG3 = 293,
+    /// `R_AARCH64_LDST128_ABS_LO12_NC`: Direct LD/ST imm from bits 11:4
+    Ldst128AbsLo12Nc = 299,
+    /// `R_AARCH64_ADR_GOT_PAGE`: Page relative ADRP imm of the GOT entry
+    AdrGotPage = 311,
+    /// `R_AARCH64_LD64_GOT_LO12_NC`: Direct LD imm of the GOT entry from bits 11:3
+    Ld64GotLo12Nc = 312,
+    /// `R_AARCH64_LD64_GOTPAGE_LO15`: GOT page relative LD imm of the GOT entry from bits 14:3
+    Ld64GotPageLo15 = 313,
+    /// `R_AARCH64_TLSGD_ADR_PAGE21`: Page relative ADRP imm of the GD GOT entries
+    TlsGdAdrPage21 = 513,
+    /// `R_AARCH64_TLSGD_ADD_LO12_NC`: Direct ADD imm of the GD GOT entries
+    TlsGdAddLo12Nc = 514,
+    /// `R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21`: Page relative ADRP imm of the IE GOT entry
+    TlsIeAdrGotTprelPage21 = 541,
+    /// `R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC`: Direct LD imm of the IE GOT entry
+    TlsIeLd64GotTprelLo12Nc = 542,
+    /// `R_AARCH64_TLSLE_ADD_TPREL_HI12`: TP relative ADD imm from bits 23:12
+    TlsLeAddTprelHi12 = 549,
+    /// `R_AARCH64_TLSLE_ADD_TPREL_LO12`: TP relative ADD imm from bits 11:0
+    TlsLeAddTprelLo12 = 550,
+    /// `R_AARCH64_TLSLE_ADD_TPREL_LO12_NC`: TP relative ADD imm from bits 11:0, no overflow check
+    TlsLeAddTprelLo12Nc = 551,
+    /// `R_AARCH64_TLSDESC_ADR_PAGE21`: Page relative ADRP imm of the TLS descriptor
+    TlsDescAdrPage21 = 562,
+    /// `R_AARCH64_TLSDESC_LD64_LO12`: Direct LD imm of the TLS descriptor
+    TlsDescLd64Lo12 = 563,
+    /// `R_AARCH64_TLSDESC_ADD_LO12`: Direct ADD imm of the TLS descriptor
+    TlsDescAddLo12 = 564,
+    /// `R_AARCH64_TLSDESC_CALL`: Marker for call through TLS descriptor
+    TlsDescCall = 569,
+    /// `R_AARCH64_COPY`: Copy symbol at runtime
+    Copy = 1024,
+    /// `R_AARCH64_GLOB_DAT`: Create GOT entry
+    GlobDat = 1025,
+    /// `R_AARCH64_JUMP_SLOT`: Create PLT entry
+    JumpSlot = 1026,
+    /// `R_AARCH64_RELATIVE`: Adjust by program base
+    Relative = 1027,
+    /// `R_AARCH64_TLS_DTPMOD`: Module number
+    TlsDtpMod = 1028,
+    /// `R_AARCH64_TLS_DTPREL`: Module relative offset
+    TlsDtpRel = 1029,
+    /// `R_AARCH64_TLS_TPREL`: TP relative offset
+    TlsTpRel = 1030,
+    /// `R_AARCH64_TLSDESC`: TLS descriptor
+    TlsDesc = 1031,
+    /// `R_AARCH64_IRELATIVE`: Adjust indirectly by program base
+    IRelative = 1032,
+    /// Any other unknown relocation type
+    Other(u32),
+}
+
+impl Aarch64RelocationType {
+    /// Returns the raw value of this relocation type
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::None => 0,
+            Self::Abs64 => 257,
+            Self::Abs32 => 258,
+            Self::Abs16 => 259,
+            Self::Prel64 => 260,
+            Self::Prel32 => 261,
+            Self::Prel16 => 262,
+            Self::MovwUAbsG0 => 263,
+            Self::MovwUAbsG0Nc => 264,
+            Self::MovwUAbsG1 => 265,
+            Self::MovwUAbsG1Nc => 266,
+            Self::MovwUAbsG2 => 267,
+            Self::MovwUAbsG2Nc => 268,
+            Self::MovwUAbsG3 => 269,
+            Self::MovwSAbsG0 => 270,
+            Self::MovwSAbsG1 => 271,
+            Self::MovwSAbsG2 => 272,
+            Self::LdPrelLo19 => 273,
+            Self::AdrPrelLo21 => 274,
+            Self::AdrPrelPgHi21 => 275,
+            Self::AdrPrelPgHi21Nc => 276,
+            Self::AddAbsLo12Nc => 277,
+            Self::Ldst8AbsLo12Nc => 278,
+            Self::TstBr14 => 279,
+            Self::CondBr19 => 280,
+            Self::Jump26 => 282,
+            Self::Call26 => 283,
+            Self::Ldst16AbsLo12Nc => 284,
+            Self::Ldst32AbsLo12Nc => 285,
+            Self::Ldst64AbsLo12Nc => 286,
+            Self::MovwPrelG0 => 287,
+            Self::MovwPrelG0Nc => 288,
+            Self::MovwPrelG1 => 289,
+            Self::MovwPrelG1Nc => 290,
+            Self::MovwPrelG2 => 291,
+            Self::MovwPrelG2Nc => 292,
+            Self::MovwPrelG3 => 293,
+            Self::Ldst128AbsLo12Nc => 299,
+            Self::AdrGotPage => 311,
+            Self::Ld64GotLo12Nc => 312,
+            Self::Ld64GotPageLo15 => 313,
+            Self::TlsGdAdrPage21 => 513,
+            Self::TlsGdAddLo12Nc => 514,
+            Self::TlsIeAdrGotTprelPage21 => 541,
+            Self::TlsIeLd64GotTprelLo12Nc => 542,
+            Self::TlsLeAddTprelHi12 => 549,
+            Self::TlsLeAddTprelLo12 => 550,
+            Self::TlsLeAddTprelLo12Nc => 551,
+            Self::TlsDescAdrPage21 => 562,
+            Self::TlsDescLd64Lo12 => 563,
+            Self::TlsDescAddLo12 => 564,
+            Self::TlsDescCall => 569,
+            Self::Copy => 1024,
+            Self::GlobDat => 1025,
+            Self::JumpSlot => 1026,
+            Self::Relative => 1027,
+            Self::TlsDtpMod => 1028,
+            Self::TlsDtpRel => 1029,
+            Self::TlsTpRel => 1030,
+            Self::TlsDesc => 1031,
+            Self::IRelative => 1032,
+            Self::Other(ty) => *ty,
+        }
+    }
+
+    /// Returns the name of this relocation type as displayed by `readelf`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "R_AARCH64_NONE",
+            Self::Abs64 => "R_AARCH64_ABS64",
+            Self::Abs32 => "R_AARCH64_ABS32",
+            Self::Abs16 => "R_AARCH64_ABS16",
+            Self::Prel64 => "R_AARCH64_PREL64",
+            Self::Prel32 => "R_AARCH64_PREL32",
+            Self::Prel16 => "R_AARCH64_PREL16",
+            Self::MovwUAbsG0 => "R_AARCH64_MOVW_UABS_G0",
+            Self::MovwUAbsG0Nc => "R_AARCH64_MOVW_UABS_G0_NC",
+            Self::MovwUAbsG1 => "R_AARCH64_MOVW_UABS_G1",
+            Self::MovwUAbsG1Nc => "R_AARCH64_MOVW_UABS_G1_NC",
+            Self::MovwUAbsG2 => "R_AARCH64_MOVW_UABS_G2",
+            Self::MovwUAbsG2Nc => "R_AARCH64_MOVW_UABS_G2_NC",
+            Self::MovwUAbsG3 => "R_AARCH64_MOVW_UABS_G3",
+            Self::MovwSAbsG0 => "R_AARCH64_MOVW_SABS_G0",
+            Self::MovwSAbsG1 => "R_AARCH64_MOVW_SABS_G1",
+            Self::MovwSAbsG2 => "R_AARCH64_MOVW_SABS_G2",
+            Self::LdPrelLo19 => "R_AARCH64_LD_PREL_LO19",
+            Self::AdrPrelLo21 => "R_AARCH64_ADR_PREL_LO21",
+            Self::AdrPrelPgHi21 => "R_AARCH64_ADR_PREL_PG_HI21",
+            Self::AdrPrelPgHi21Nc => "R_AARCH64_ADR_PREL_PG_HI21_NC",
+            Self::AddAbsLo12Nc => "R_AARCH64_ADD_ABS_LO12_NC",
+            Self::Ldst8AbsLo12Nc => "R_AARCH64_LDST8_ABS_LO12_NC",
+            Self::TstBr14 => "R_AARCH64_TSTBR14",
+            Self::CondBr19 => "R_AARCH64_CONDBR19",
+            Self::Jump26 => "R_AARCH64_JUMP26",
+            Self::Call26 => "R_AARCH64_CALL26",
+            Self::Ldst16AbsLo12Nc => "R_AARCH64_LDST16_ABS_LO12_NC",
+            Self::Ldst32AbsLo12Nc => "R_AARCH64_LDST32_ABS_LO12_NC",
+            Self::Ldst64AbsLo12Nc => "R_AARCH64_LDST64_ABS_LO12_NC",
+            Self::MovwPrelG0 => "R_AARCH64_MOVW_PREL_G0",
+            Self::MovwPrelG0Nc => "R_AARCH64_MOVW_PREL_G0_NC",
+            Self::MovwPrelG1 => "R_AARCH64_MOVW_PREL_G1",
+            Self::MovwPrelG1Nc => "R_AARCH64_MOVW_PREL_G1_NC",
+            Self::MovwPrelG2 => "R_AARCH64_MOVW_PREL_G2",
+            Self::MovwPrelG2Nc => "R_AARCH64_MOVW_PREL_G2_NC",
+            Self::MovwPrelG3 => "R_AARCH64_MOVW_PREL_G3",
+            Self::Ldst128AbsLo12Nc => "R_AARCH64_LDST128_ABS_LO12_NC",
+            Self::AdrGotPage => "R_AARCH64_ADR_GOT_PAGE",
+            Self::Ld64GotLo12Nc => "R_AARCH64_LD64_GOT_LO12_NC",
+            Self::Ld64GotPageLo15 => "R_AARCH64_LD64_GOTPAGE_LO15",
+            Self::TlsGdAdrPage21 => "R_AARCH64_TLSGD_ADR_PAGE21",
+            Self::TlsGdAddLo12Nc => "R_AARCH64_TLSGD_ADD_LO12_NC",
+            Self::TlsIeAdrGotTprelPage21 => "R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21",
+            Self::TlsIeLd64GotTprelLo12Nc => "R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC",
+            Self::TlsLeAddTprelHi12 => "R_AARCH64_TLSLE_ADD_TPREL_HI12",
+            Self::TlsLeAddTprelLo12 => "R_AARCH64_TLSLE_ADD_TPREL_LO12",
+            Self::TlsLeAddTprelLo12Nc => "R_AARCH64_TLSLE_ADD_TPREL_LO12_NC",
+            Self::TlsDescAdrPage21 => "R_AARCH64_TLSDESC_ADR_PAGE21",
+            Self::TlsDescLd64Lo12 => "R_AARCH64_TLSDESC_LD64_LO12",
+            Self::TlsDescAddLo12 => "R_AARCH64_TLSDESC_ADD_LO12",
+            Self::TlsDescCall => "R_AARCH64_TLSDESC_CALL",
+            Self::Copy => "R_AARCH64_COPY",
+            Self::GlobDat => "R_AARCH64_GLOB_DAT",
+            Self::JumpSlot => "R_AARCH64_JUMP_SLOT",
+            Self::Relative => "R_AARCH64_RELATIVE",
+            Self::TlsDtpMod => "R_AARCH64_TLS_DTPMOD",
+            Self::TlsDtpRel => "R_AARCH64_TLS_DTPREL",
+            Self::TlsTpRel => "R_AARCH64_TLS_TPREL",
+            Self::TlsDesc => "R_AARCH64_TLSDESC",
+            Self::IRelative => "R_AARCH64_IRELATIVE",
+            Self::Other(_) => "<unknown>",
+        }
+    }
+}
+
+impl From<u32> for Aarch64RelocationType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::None,
+            257 => Self::Abs64,
+            258 => Self::Abs32,
+            259 => Self::Abs16,
+            260 => Self::Prel64,
+            261 => Self::Prel32,
+            262 => Self::Prel16,
+            263 => Self::MovwUAbsG0,
+            264 => Self::MovwUAbsG0Nc,
+            265 => Self::MovwUAbsG1,
+            266 => Self::MovwUAbsG1Nc,
+            267 => Self::MovwUAbsG2,
+            268 => Self::MovwUAbsG2Nc,
+            269 => Self::MovwUAbsG3,
+            270 => Self::MovwSAbsG0,
+            271 => Self::MovwSAbsG1,
+            272 => Self::MovwSAbsG2,
+            273 => Self::LdPrelLo19,
+            274 => Self::AdrPrelLo21,
+            275 => Self::AdrPrelPgHi21,
+            276 => Self::AdrPrelPgHi21Nc,
+            277 => Self::AddAbsLo12Nc,
+            278 => Self::Ldst8AbsLo12Nc,
+            279 => Self::TstBr14,
+            280 => Self::CondBr19,
+            282 => Self::Jump26,
+            283 => Self::Call26,
+            284 => Self::Ldst16AbsLo12Nc,
+            285 => Self::Ldst32AbsLo12Nc,
+            286 => Self::Ldst64AbsLo12Nc,
+            287 => Self::MovwPrelG0,
+            288 => Self::MovwPrelG0Nc,
+            289 => Self::MovwPrelG1,
+            290 => Self::MovwPrelG1Nc,
+            291 => Self::MovwPrelG2,
+            292 => Self::MovwPrelG2Nc,
+            293 => Self::MovwPrelG3,
+            299 => Self::Ldst128AbsLo12Nc,
+            311 => Self::AdrGotPage,
+            312 => Self::Ld64GotLo12Nc,
+            313 => Self::Ld64GotPageLo15,
+            513 => Self::TlsGdAdrPage21,
+            514 => Self::TlsGdAddLo12Nc,
+            541 => Self::TlsIeAdrGotTprelPage21,
+            542 => Self::TlsIeLd64GotTprelLo12Nc,
+            549 => Self::TlsLeAddTprelHi12,
+            550 => Self::TlsLeAddTprelLo12,
+            551 => Self::TlsLeAddTprelLo12Nc,
+            562 => Self::TlsDescAdrPage21,
+            563 => Self::TlsDescLd64Lo12,
+            564 => Self::TlsDescAddLo12,
+            569 => Self::TlsDescCall,
+            1024 => Self::Copy,
+            1025 => Self::GlobDat,
+            1026 => Self::JumpSlot,
+            1027 => Self::Relative,
+            1028 => Self::TlsDtpMod,
+            1029 => Self::TlsDtpRel,
+            1030 => Self::TlsTpRel,
+            1031 => Self::TlsDesc,
+            1032 => Self::IRelative,
+            x => Self::Other(x),
+        }
+    }
+}
+
+impl From<Aarch64RelocationType> for u32 {
+    fn from(value: Aarch64RelocationType) -> Self {
+        value.value()
+    }
+}
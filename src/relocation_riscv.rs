@@ -0,0 +1,339 @@
+use crate::{Class, RelaEntry, SymbolTable};
+
+/// A relocation type of the RISC-V architecture
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum RiscVRelocationType {
+    /// `R_RISCV_NONE`: No relocation
+    None = 0,
+    /// `R_RISCV_32`: Direct 32 bit
+    Abs32 = 1,
+    /// `R_RISCV_64`: Direct 64 bit
+    Abs64 = 2,
+    /// `R_RISCV_RELATIVE`: Adjust by program base
+    Relative = 3,
+    /// `R_RISCV_COPY`: Copy symbol at runtime
+    Copy = 4,
+    /// `R_RISCV_JUMP_SLOT`: Create PLT entry
+    JumpSlot = 5,
+    /// `R_RISCV_TLS_DTPMOD32`: 32 bit module number
+    TlsDtpMod32 = 6,
+    /// `R_RISCV_TLS_DTPMOD64`: 64 bit module number
+    TlsDtpMod64 = 7,
+    /// `R_RISCV_TLS_DTPREL32`: 32 bit module relative offset
+    TlsDtpRel32 = 8,
+    /// `R_RISCV_TLS_DTPREL64`: 64 bit module relative offset
+    TlsDtpRel64 = 9,
+    /// `R_RISCV_TLS_TPREL32`: 32 bit TP relative offset
+    TlsTpRel32 = 10,
+    /// `R_RISCV_TLS_TPREL64`: 64 bit TP relative offset
+    TlsTpRel64 = 11,
+    /// `R_RISCV_TLSDESC`: TLS descriptor
+    TlsDesc = 12,
+    /// `R_RISCV_BRANCH`: PC relative branch
+    Branch = 16,
+    /// `R_RISCV_JAL`: PC relative jump
+    Jal = 17,
+    /// `R_RISCV_CALL`: PC relative call
+    Call = 18,
+    /// `R_RISCV_CALL_PLT`: PC relative call through the PLT
+    CallPlt = 19,
+    /// `R_RISCV_GOT_HI20`: PC relative GOT reference
+    GotHi20 = 20,
+    /// `R_RISCV_TLS_GOT_HI20`: PC relative TLS IE GOT reference
+    TlsGotHi20 = 21,
+    /// `R_RISCV_TLS_GD_HI20`: PC relative TLS GD reference
+    TlsGdHi20 = 22,
+    /// `R_RISCV_PCREL_HI20`: PC relative reference, upper 20 bits
+    PcrelHi20 = 23,
+    /// `R_RISCV_PCREL_LO12_I`: PC relative reference, lower 12 bits of an I-type instruction
+    PcrelLo12I = 24,
+    /// `R_RISCV_PCREL_LO12_S`: PC relative reference, lower 12 bits of an S-type instruction
+    PcrelLo12S = 25,
+    /// `R_RISCV_HI20`: Absolute address, upper 20 bits
+    Hi20 = 26,
+    /// `R_RISCV_LO12_I`: Absolute address, lower 12 bits of an I-type instruction
+    Lo12I = 27,
+    /// `R_RISCV_LO12_S`: Absolute address, lower 12 bits of an S-type instruction
+    Lo12S = 28,
+    /// `R_RISCV_TPREL_HI20`: TP relative offset, upper 20 bits
+    TpRelHi20 = 29,
+    /// `R_RISCV_TPREL_LO12_I`: TP relative offset, lower 12 bits of an I-type instruction
+    TpRelLo12I = 30,
+    /// `R_RISCV_TPREL_LO12_S`: TP relative offset, lower 12 bits of an S-type instruction
+    TpRelLo12S = 31,
+    /// `R_RISCV_TPREL_ADD`: TP relative add hint
+    TpRelAdd = 32,
+    /// `R_RISCV_ADD8`: 8 bit label addition
+    Add8 = 33,
+    /// `R_RISCV_ADD16`: 16 bit label addition
+    Add16 = 34,
+    /// `R_RISCV_ADD32`: 32 bit label addition
+    Add32 = 35,
+    /// `R_RISCV_ADD64`: 64 bit label addition
+    Add64 = 36,
+    /// `R_RISCV_SUB8`: 8 bit label subtraction
+    Sub8 = 37,
+    /// `R_RISCV_SUB16`: 16 bit label subtraction
+    Sub16 = 38,
+    /// `R_RISCV_SUB32`: 32 bit label subtraction
+    Sub32 = 39,
+    /// `R_RISCV_SUB64`: 64 bit label subtraction
+    Sub64 = 40,
+    /// `R_RISCV_GOT32_PCREL`: 32 bit PC relative GOT offset
+    Got32Pcrel = 41,
+    /// `R_RISCV_ALIGN`: Alignment statement for linker relaxation
+    Align = 43,
+    /// `R_RISCV_RVC_BRANCH`: PC relative branch of a compressed instruction
+    RvcBranch = 44,
+    /// `R_RISCV_RVC_JUMP`: PC relative jump of a compressed instruction
+    RvcJump = 45,
+    /// `R_RISCV_RELAX`: The preceding relocation may be relaxed
+    Relax = 51,
+    /// `R_RISCV_SUB6`: 6 bit label subtraction
+    Sub6 = 52,
+    /// `R_RISCV_SET6`: 6 bit label assignment
+    Set6 = 53,
+    /// `R_RISCV_SET8`: 8 bit label assignment
+    Set8 = 54,
+    /// `R_RISCV_SET16`: 16 bit label assignment
+    Set16 = 55,
+    /// `R_RISCV_SET32`: 32 bit label assignment
+    Set32 = 56,
+    /// `R_RISCV_32_PCREL`: 32 bit PC relative
+    Pcrel32 = 57,
+    /// `R_RISCV_IRELATIVE`: Adjust indirectly by program base
+    IRelative = 58,
+    /// `R_RISCV_PLT32`: 32 bit PC relative reference to the PLT
+    Plt32 = 59,
+    /// `R_RISCV_SET_ULEB128`: ULEB128 label assignment
+    SetUleb128 = 60,
+    /// `R_RISCV_SUB_ULEB128`: ULEB128 label subtraction
+    SubUleb128 = 61,
+    /// Any other unknown relocation type
+    Other(u32),
+}
+
+impl RiscVRelocationType {
+    /// Returns the raw value of this relocation type
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::None => 0,
+            Self::Abs32 => 1,
+            Self::Abs64 => 2,
+            Self::Relative => 3,
+            Self::Copy => 4,
+            Self::JumpSlot => 5,
+            Self::TlsDtpMod32 => 6,
+            Self::TlsDtpMod64 => 7,
+            Self::TlsDtpRel32 => 8,
+            Self::TlsDtpRel64 => 9,
+            Self::TlsTpRel32 => 10,
+            Self::TlsTpRel64 => 11,
+            Self::TlsDesc => 12,
+            Self::Branch => 16,
+            Self::Jal => 17,
+            Self::Call => 18,
+            Self::CallPlt => 19,
+            Self::GotHi20 => 20,
+            Self::TlsGotHi20 => 21,
+            Self::TlsGdHi20 => 22,
+            Self::PcrelHi20 => 23,
+            Self::PcrelLo12I => 24,
+            Self::PcrelLo12S => 25,
+            Self::Hi20 => 26,
+            Self::Lo12I => 27,
+            Self::Lo12S => 28,
+            Self::TpRelHi20 => 29,
+            Self::TpRelLo12I => 30,
+            Self::TpRelLo12S => 31,
+            Self::TpRelAdd => 32,
+            Self::Add8 => 33,
+            Self::Add16 => 34,
+            Self::Add32 => 35,
+            Self::Add64 => 36,
+            Self::Sub8 => 37,
+            Self::Sub16 => 38,
+            Self::Sub32 => 39,
+            Self::Sub64 => 40,
+            Self::Got32Pcrel => 41,
+            Self::Align => 43,
+            Self::RvcBranch => 44,
+            Self::RvcJump => 45,
+            Self::Relax => 51,
+            Self::Sub6 => 52,
+            Self::Set6 => 53,
+            Self::Set8 => 54,
+            Self::Set16 => 55,
+            Self::Set32 => 56,
+            Self::Pcrel32 => 57,
+            Self::IRelative => 58,
+            Self::Plt32 => 59,
+            Self::SetUleb128 => 60,
+            Self::SubUleb128 => 61,
+            Self::Other(ty) => *ty,
+        }
+    }
+
+    /// Returns the name of this relocation type as displayed by `readelf`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "R_RISCV_NONE",
+            Self::Abs32 => "R_RISCV_32",
+            Self::Abs64 => "R_RISCV_64",
+            Self::Relative => "R_RISCV_RELATIVE",
+            Self::Copy => "R_RISCV_COPY",
+            Self::JumpSlot => "R_RISCV_JUMP_SLOT",
+            Self::TlsDtpMod32 => "R_RISCV_TLS_DTPMOD32",
+            Self::TlsDtpMod64 => "R_RISCV_TLS_DTPMOD64",
+            Self::TlsDtpRel32 => "R_RISCV_TLS_DTPREL32",
+            Self::TlsDtpRel64 => "R_RISCV_TLS_DTPREL64",
+            Self::TlsTpRel32 => "R_RISCV_TLS_TPREL32",
+            Self::TlsTpRel64 => "R_RISCV_TLS_TPREL64",
+            Self::TlsDesc => "R_RISCV_TLSDESC",
+            Self::Branch => "R_RISCV_BRANCH",
+            Self::Jal => "R_RISCV_JAL",
+            Self::Call => "R_RISCV_CALL",
+            Self::CallPlt => "R_RISCV_CALL_PLT",
+            Self::GotHi20 => "R_RISCV_GOT_HI20",
+            Self::TlsGotHi20 => "R_RISCV_TLS_GOT_HI20",
+            Self::TlsGdHi20 => "R_RISCV_TLS_GD_HI20",
+            Self::PcrelHi20 => "R_RISCV_PCREL_HI20",
+            Self::PcrelLo12I => "R_RISCV_PCREL_LO12_I",
+            Self::PcrelLo12S => "R_RISCV_PCREL_LO12_S",
+            Self::Hi20 => "R_RISCV_HI20",
+            Self::Lo12I => "R_RISCV_LO12_I",
+            Self::Lo12S => "R_RISCV_LO12_S",
+            Self::TpRelHi20 => "R_RISCV_TPREL_HI20",
+            Self::TpRelLo12I => "R_RISCV_TPREL_LO12_I",
+            Self::TpRelLo12S => "R_RISCV_TPREL_LO12_S",
+            Self::TpRelAdd => "R_RISCV_TPREL_ADD",
+            Self::Add8 => "R_RISCV_ADD8",
+            Self::Add16 => "R_RISCV_ADD16",
+            Self::Add32 => "R_RISCV_ADD32",
+            Self::Add64 => "R_RISCV_ADD64",
+            Self::Sub8 => "R_RISCV_SUB8",
+            Self::Sub16 => "R_RISCV_SUB16",
+            Self::Sub32 => "R_RISCV_SUB32",
+            Self::Sub64 => "R_RISCV_SUB64",
+            Self::Got32Pcrel => "R_RISCV_GOT32_PCREL",
+            Self::Align => "R_RISCV_ALIGN",
+            Self::RvcBranch => "R_RISCV_RVC_BRANCH",
+            Self::RvcJump => "R_RISCV_RVC_JUMP",
+            Self::Relax => "R_RISCV_RELAX",
+            Self::Sub6 => "R_RISCV_SUB6",
+            Self::Set6 => "R_RISCV_SET6",
+            Self::Set8 => "R_RISCV_SET8",
+            Self::Set16 => "R_RISCV_SET16",
+            Self::Set32 => "R_RISCV_SET32",
+            Self::Pcrel32 => "R_RISCV_32_PCREL",
+            Self::IRelative => "R_RISCV_IRELATIVE",
+            Self::Plt32 => "R_RISCV_PLT32",
+            Self::SetUleb128 => "R_RISCV_SET_ULEB128",
+            Self::SubUleb128 => "R_RISCV_SUB_ULEB128",
+            Self::Other(_) => "<unknown>",
+        }
+    }
+}
+
+impl From<u32> for RiscVRelocationType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Abs32,
+            2 => Self::Abs64,
+            3 => Self::Relative,
+            4 => Self::Copy,
+            5 => Self::JumpSlot,
+            6 => Self::TlsDtpMod32,
+            7 => Self::TlsDtpMod64,
+            8 => Self::TlsDtpRel32,
+            9 => Self::TlsDtpRel64,
+            10 => Self::TlsTpRel32,
+            11 => Self::TlsTpRel64,
+            12 => Self::TlsDesc,
+            16 => Self::Branch,
+            17 => Self::Jal,
+            18 => Self::Call,
+            19 => Self::CallPlt,
+            20 => Self::GotHi20,
+            21 => Self::TlsGotHi20,
+            22 => Self::TlsGdHi20,
+            23 => Self::PcrelHi20,
+            24 => Self::PcrelLo12I,
+            25 => Self::PcrelLo12S,
+            26 => Self::Hi20,
+            27 => Self::Lo12I,
+            28 => Self::Lo12S,
+            29 => Self::TpRelHi20,
+            30 => Self::TpRelLo12I,
+            31 => Self::TpRelLo12S,
+            32 => Self::TpRelAdd,
+            33 => Self::Add8,
+            34 => Self::Add16,
+            35 => Self::Add32,
+            36 => Self::Add64,
+            37 => Self::Sub8,
+            38 => Self::Sub16,
+            39 => Self::Sub32,
+            40 => Self::Sub64,
+            41 => Self::Got32Pcrel,
+            43 => Self::Align,
+            44 => Self::RvcBranch,
+            45 => Self::RvcJump,
+            51 => Self::Relax,
+            52 => Self::Sub6,
+            53 => Self::Set6,
+            54 => Self::Set8,
+            55 => Self::Set16,
+            56 => Self::Set32,
+            57 => Self::Pcrel32,
+            58 => Self::IRelative,
+            59 => Self::Plt32,
+            60 => Self::SetUleb128,
+            61 => Self::SubUleb128,
+            x => Self::Other(x),
+        }
+    }
+}
+
+impl From<RiscVRelocationType> for u32 {
+    fn from(value: RiscVRelocationType) -> Self {
+        value.value()
+    }
+}
+
+impl RelaEntry {
+    /// Finds the `R_RISCV_PCREL_HI20` relocation this `R_RISCV_PCREL_LO12_I`
+    /// or `R_RISCV_PCREL_LO12_S` relocation is paired with
+    ///
+    /// The symbol of a `PCREL_LO12` relocation points at the instruction
+    /// carrying the matching `PCREL_HI20` relocation, so both have to come
+    /// from the same relocation section
+    /// # Arguments
+    /// * `relocations` - The relocations of the section this relocation belongs to
+    /// * `symbols` - The symbol table linked to the relocation section
+    /// * `class` - The ELF class of the file
+    /// # Returns
+    /// `None` if this is not a `PCREL_LO12` relocation or no matching
+    /// `PCREL_HI20` relocation exists
+    pub fn riscv_pcrel_hi20<'a>(
+        &self,
+        relocations: &'a [RelaEntry],
+        symbols: &SymbolTable,
+        class: Class,
+    ) -> Option<&'a RelaEntry> {
+        match RiscVRelocationType::from(self.relocation_type(class)) {
+            RiscVRelocationType::PcrelLo12I | RiscVRelocationType::PcrelLo12S => {}
+            _ => return None,
+        }
+
+        let target = symbols.get(self.symbol_index(class) as usize)?.value;
+
+        relocations.iter().find(|r| {
+            r.offset == target
+                && RiscVRelocationType::from(r.relocation_type(class))
+                    == RiscVRelocationType::PcrelHi20
+        })
+    }
+}
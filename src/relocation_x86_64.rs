@@ -0,0 +1,242 @@
+/// A relocation type of the x86-64 architecture
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum X86_64RelocationType {
+    /// `R_X86_64_NONE`: No relocation
+    None = 0,
+    /// `R_X86_64_64`: Direct 64 bit
+    Abs64 = 1,
+    /// `R_X86_64_PC32`: PC relative 32 bit signed
+    Pc32 = 2,
+    /// `R_X86_64_GOT32`: 32 bit GOT entry
+    Got32 = 3,
+    /// `R_X86_64_PLT32`: 32 bit PLT address
+    Plt32 = 4,
+    /// `R_X86_64_COPY`: Copy symbol at runtime
+    Copy = 5,
+    /// `R_X86_64_GLOB_DAT`: Create GOT entry
+    GlobDat = 6,
+    /// `R_X86_64_JUMP_SLOT`: Create PLT entry
+    JumpSlot = 7,
+    /// `R_X86_64_RELATIVE`: Adjust by program base
+    Relative = 8,
+    /// `R_X86_64_GOTPCREL`: 32 bit signed PC relative offset to GOT
+    GotPcRel = 9,
+    /// `R_X86_64_32`: Direct 32 bit zero extended
+    Abs32 = 10,
+    /// `R_X86_64_32S`: Direct 32 bit sign extended
+    Abs32S = 11,
+    /// `R_X86_64_16`: Direct 16 bit zero extended
+    Abs16 = 12,
+    /// `R_X86_64_PC16`: 16 bit sign extended PC relative
+    Pc16 = 13,
+    /// `R_X86_64_8`: Direct 8 bit sign extended
+    Abs8 = 14,
+    /// `R_X86_64_PC8`: 8 bit sign extended PC relative
+    Pc8 = 15,
+    /// `R_X86_64_DTPMOD64`: ID of module containing symbol
+    DtpMod64 = 16,
+    /// `R_X86_64_DTPOFF64`: Offset in module's TLS block
+    DtpOff64 = 17,
+    /// `R_X86_64_TPOFF64`: Offset in initial TLS block
+    TpOff64 = 18,
+    /// `R_X86_64_TLSGD`: 32 bit signed PC relative offset to two GOT entries for GD symbol
+    TlsGd = 19,
+    /// `R_X86_64_TLSLD`: 32 bit signed PC relative offset to two GOT entries for LD symbol
+    TlsLd = 20,
+    /// `R_X86_64_DTPOFF32`: Offset in TLS block
+    DtpOff32 = 21,
+    /// `R_X86_64_GOTTPOFF`: 32 bit signed PC relative offset to GOT entry for IE symbol
+    GotTpOff = 22,
+    /// `R_X86_64_TPOFF32`: Offset in initial TLS block
+    TpOff32 = 23,
+    /// `R_X86_64_PC64`: PC relative 64 bit
+    Pc64 = 24,
+    /// `R_X86_64_GOTOFF64`: 64 bit offset to GOT
+    GotOff64 = 25,
+    /// `R_X86_64_GOTPC32`: 32 bit signed PC relative offset to GOT
+    GotPc32 = 26,
+    /// `R_X86_64_GOT64`: 64 bit GOT entry offset
+    Got64 = 27,
+    /// `R_X86_64_GOTPCREL64`: 64 bit PC relative offset to GOT entry
+    GotPcRel64 = 28,
+    /// `R_X86_64_GOTPC64`: 64 bit PC relative offset to GOT
+    GotPc64 = 29,
+    /// `R_X86_64_GOTPLT64`: Like GOT64, indicates that a PLT entry is needed
+    GotPlt64 = 30,
+    /// `R_X86_64_PLTOFF64`: 64 bit GOT relative offset to PLT entry
+    PltOff64 = 31,
+    /// `R_X86_64_SIZE32`: Size of symbol plus 32 bit addend
+    Size32 = 32,
+    /// `R_X86_64_SIZE64`: Size of symbol plus 64 bit addend
+    Size64 = 33,
+    /// `R_X86_64_GOTPC32_TLSDESC`: GOT offset for TLS descriptor
+    GotPc32TlsDesc = 34,
+    /// `R_X86_64_TLSDESC_CALL`: Marker for call through TLS descriptor
+    TlsDescCall = 35,
+    /// `R_X86_64_TLSDESC`: TLS descriptor
+    TlsDesc = 36,
+    /// `R_X86_64_IRELATIVE`: Adjust indirectly by program base
+    IRelative = 37,
+    /// `R_X86_64_RELATIVE64`: 64 bit adjust by program base
+    Relative64 = 38,
+    /// `R_X86_64_GOTPCRELX`: Load from 32 bit signed PC relative offset to GOT entry without REX prefix, relaxable
+    GotPcRelX = 41,
+    /// `R_X86_64_REX_GOTPCRELX`: Load from 32 bit signed PC relative offset to GOT entry with REX prefix, relaxable
+    RexGotPcRelX = 42,
+    /// Any other unknown relocation type
+    Other(u32),
+}
+
+impl X86_64RelocationType {
+    /// Returns the raw value of this relocation type
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::None => 0,
+            Self::Abs64 => 1,
+            Self::Pc32 => 2,
+            Self::Got32 => 3,
+            Self::Plt32 => 4,
+            Self::Copy => 5,
+            Self::GlobDat => 6,
+            Self::JumpSlot => 7,
+            Self::Relative => 8,
+            Self::GotPcRel => 9,
+            Self::Abs32 => 10,
+            Self::Abs32S => 11,
+            Self::Abs16 => 12,
+            Self::Pc16 => 13,
+            Self::Abs8 => 14,
+            Self::Pc8 => 15,
+            Self::DtpMod64 => 16,
+            Self::DtpOff64 => 17,
+            Self::TpOff64 => 18,
+            Self::TlsGd => 19,
+            Self::TlsLd => 20,
+            Self::DtpOff32 => 21,
+            Self::GotTpOff => 22,
+            Self::TpOff32 => 23,
+            Self::Pc64 => 24,
+            Self::GotOff64 => 25,
+            Self::GotPc32 => 26,
+            Self::Got64 => 27,
+            Self::GotPcRel64 => 28,
+            Self::GotPc64 => 29,
+            Self::GotPlt64 => 30,
+            Self::PltOff64 => 31,
+            Self::Size32 => 32,
+            Self::Size64 => 33,
+            Self::GotPc32TlsDesc => 34,
+            Self::TlsDescCall => 35,
+            Self::TlsDesc => 36,
+            Self::IRelative => 37,
+            Self::Relative64 => 38,
+            Self::GotPcRelX => 41,
+            Self::RexGotPcRelX => 42,
+            Self::Other(ty) => *ty,
+        }
+    }
+
+    /// Returns the name of this relocation type as displayed by `readelf`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "R_X86_64_NONE",
+            Self::Abs64 => "R_X86_64_64",
+            Self::Pc32 => "R_X86_64_PC32",
+            Self::Got32 => "R_X86_64_GOT32",
+            Self::Plt32 => "R_X86_64_PLT32",
+            Self::Copy => "R_X86_64_COPY",
+            Self::GlobDat => "R_X86_64_GLOB_DAT",
+            Self::JumpSlot => "R_X86_64_JUMP_SLOT",
+            Self::Relative => "R_X86_64_RELATIVE",
+            Self::GotPcRel => "R_X86_64_GOTPCREL",
+            Self::Abs32 => "R_X86_64_32",
+            Self::Abs32S => "R_X86_64_32S",
+            Self::Abs16 => "R_X86_64_16",
+            Self::Pc16 => "R_X86_64_PC16",
+            Self::Abs8 => "R_X86_64_8",
+            Self::Pc8 => "R_X86_64_PC8",
+            Self::DtpMod64 => "R_X86_64_DTPMOD64",
+            Self::DtpOff64 => "R_X86_64_DTPOFF64",
+            Self::TpOff64 => "R_X86_64_TPOFF64",
+            Self::TlsGd => "R_X86_64_TLSGD",
+            Self::TlsLd => "R_X86_64_TLSLD",
+            Self::DtpOff32 => "R_X86_64_DTPOFF32",
+            Self::GotTpOff => "R_X86_64_GOTTPOFF",
+            Self::TpOff32 => "R_X86_64_TPOFF32",
+            Self::Pc64 => "R_X86_64_PC64",
+            Self::GotOff64 => "R_X86_64_GOTOFF64",
+            Self::GotPc32 => "R_X86_64_GOTPC32",
+            Self::Got64 => "R_X86_64_GOT64",
+            Self::GotPcRel64 => "R_X86_64_GOTPCREL64",
+            Self::GotPc64 => "R_X86_64_GOTPC64",
+            Self::GotPlt64 => "R_X86_64_GOTPLT64",
+            Self::PltOff64 => "R_X86_64_PLTOFF64",
+            Self::Size32 => "R_X86_64_SIZE32",
+            Self::Size64 => "R_X86_64_SIZE64",
+            Self::GotPc32TlsDesc => "R_X86_64_GOTPC32_TLSDESC",
+            Self::TlsDescCall => "R_X86_64_TLSDESC_CALL",
+            Self::TlsDesc => "R_X86_64_TLSDESC",
+            Self::IRelative => "R_X86_64_IRELATIVE",
+            Self::Relative64 => "R_X86_64_RELATIVE64",
+            Self::GotPcRelX => "R_X86_64_GOTPCRELX",
+            Self::RexGotPcRelX => "R_X86_64_REX_GOTPCRELX",
+            Self::Other(_) => "<unknown>",
+        }
+    }
+}
+
+impl From<u32> for X86_64RelocationType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Abs64,
+            2 => Self::Pc32,
+            3 => Self::Got32,
+            4 => Self::Plt32,
+            5 => Self::Copy,
+            6 => Self::GlobDat,
+            7 => Self::JumpSlot,
+            8 => Self::Relative,
+            9 => Self::GotPcRel,
+            10 => Self::Abs32,
+            11 => Self::Abs32S,
+            12 => Self::Abs16,
+            13 => Self::Pc16,
+            14 => Self::Abs8,
+            15 => Self::Pc8,
+            16 => Self::DtpMod64,
+            17 => Self::DtpOff64,
+            18 => Self::TpOff64,
+            19 => Self::TlsGd,
+            20 => Self::TlsLd,
+            21 => Self::DtpOff32,
+            22 => Self::GotTpOff,
+            23 => Self::TpOff32,
+            24 => Self::Pc64,
+            25 => Self::GotOff64,
+            26 => Self::GotPc32,
+            27 => Self::Got64,
+            28 => Self::GotPcRel64,
+            29 => Self::GotPc64,
+            30 => Self::GotPlt64,
+            31 => Self::PltOff64,
+            32 => Self::Size32,
+            33 => Self::Size64,
+            34 => Self::GotPc32TlsDesc,
+            35 => Self::TlsDescCall,
+            36 => Self::TlsDesc,
+            37 => Self::IRelative,
+            38 => Self::Relative64,
+            41 => Self::GotPcRelX,
+            42 => Self::RexGotPcRelX,
+            x => Self::Other(x),
+        }
+    }
+}
+
+impl From<X86_64RelocationType> for u32 {
+    fn from(value: X86_64RelocationType) -> Self {
+        value.value()
+    }
+}
@@ -1,21 +1,23 @@
-use std::{
-    fmt::Debug,
-    io::{self},
+use alloc::{string::ToString, vec::Vec};
+use core::{
+    fmt::{Debug, Display},
+    ops::{BitAnd, BitOr},
 };
 
-use crate::{Blob, Packable, PackableClass, UnpackError, Unpackable, UnpackableClass};
+use crate::{io, Address, Blob, Packable, PackableClass, UnpackError, Unpackable, UnpackableClass};
 
 use super::Class;
 
 /// A section header in the ELF file
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SectionHeader {
     /// The index into the `.shstrtab` section for the name of this section
     pub name: u32,
     /// The type of section at hand
-    pub ty: u32,
+    pub ty: SectionType,
     /// Attributes for this section
-    pub flags: u64,
+    pub flags: SectionFlags,
     /// The virtual address for this section
     pub address: u64,
     /// The offset of this section in the file image
@@ -34,19 +36,70 @@ pub struct SectionHeader {
     pub data: Blob,
 }
 
+impl SectionHeader {
+    /// Returns the virtual address of this section as an [Address]
+    pub fn virtual_address(&self) -> Address {
+        Address(self.address)
+    }
+
+    /// Returns the offset of this section in the file as an [Address]
+    pub fn file_offset(&self) -> Address {
+        Address(self.offset)
+    }
+
+    /// Returns whether this section occupies space in the file,
+    /// which `SHT_NULL` and `SHT_NOBITS` sections do not
+    pub fn has_file_data(&self) -> bool {
+        !matches!(self.ty, SectionType::Null | SectionType::NoBits)
+    }
+
+    /// Loads the data of this section from `r` if it has not been loaded yet
+    ///
+    /// Sections without data in the file stay empty, see [SectionHeader::has_file_data]
+    /// # Arguments
+    /// * `r` - The stream of the file to read from
+    pub fn load_data<R: io::Read + io::Seek>(&mut self, r: &mut R) -> Result<(), io::Error> {
+        if self.has_file_data() && self.data.is_empty() && self.size != 0 {
+            self.data = Blob::load(r, self.offset, self.size as usize)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays in the form of a `readelf -S` line without the name:
+/// Type, address, offset, size, entry size, flags, link, info and alignment
+impl Display for SectionHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:<15} {:016x} {:06x} {:06x} {:02x} {:>3} {:2} {:3} {:2}",
+            self.ty.to_string(),
+            self.address,
+            self.offset,
+            self.size,
+            self.entry_size,
+            self.flags.to_string(),
+            self.link,
+            self.info,
+            self.addr_align
+        )
+    }
+}
+
 impl PackableClass for SectionHeader {
-    fn pack_class<W: std::io::Write + io::Seek>(
+    fn pack_class<W: io::Write + io::Seek>(
         self,
         w: &mut W,
         big_endian: bool,
         class: Class,
-    ) -> Result<(), std::io::Error> {
+    ) -> Result<(), io::Error> {
         self.name.pack(w, big_endian)?;
         self.ty.pack(w, big_endian)?;
 
         self.flags.pack_class(w, big_endian, class)?;
-        self.address.pack_class(w, big_endian, class)?;
-        self.offset.pack_class(w, big_endian, class)?;
+        Address(self.address).pack_class(w, big_endian, class)?;
+        Address(self.offset).pack_class(w, big_endian, class)?;
         self.size.pack_class(w, big_endian, class)?;
         self.link.pack(w, big_endian)?;
         self.info.pack(w, big_endian)?;
@@ -58,15 +111,15 @@ impl PackableClass for SectionHeader {
 }
 
 impl UnpackableClass for SectionHeader {
-    fn unpack_class<R: std::io::Read + io::Seek>(
+    fn unpack_class<R: io::Read + io::Seek>(
         r: &mut R,
         big_endian: bool,
         class: Class,
     ) -> Result<Self, UnpackError> {
         let name = u32::unpack(r, big_endian)?;
-        let ty = u32::unpack(r, big_endian)?;
+        let ty = SectionType::unpack(r, big_endian)?;
 
-        let flags = u64::unpack_class(r, big_endian, class)?;
+        let flags = SectionFlags::unpack_class(r, big_endian, class)?;
         let address = u64::unpack_class(r, big_endian, class)?;
         let offset = u64::unpack_class(r, big_endian, class)?;
         let size = u64::unpack_class(r, big_endian, class)?;
@@ -75,8 +128,6 @@ impl UnpackableClass for SectionHeader {
         let addr_align = u64::unpack_class(r, big_endian, class)?;
         let entry_size = u64::unpack_class(r, big_endian, class)?;
 
-        let data = Blob::load(r, offset, size as usize)?;
-
         Ok(Self {
             name,
             ty,
@@ -88,7 +139,391 @@ impl UnpackableClass for SectionHeader {
             info,
             addr_align,
             entry_size,
-            data,
+            data: Blob { blob: Vec::new() },
+        })
+    }
+}
+
+/// The type of section at hand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum SectionType {
+    /// An unused section header
+    Null = 0x0,
+    /// Information defined by the program
+    ProgramData = 0x1,
+    /// A full symbol table
+    SymbolTable = 0x2,
+    /// A string table
+    StringTable = 0x3,
+    /// Relocation entries with explicit addends
+    Rela = 0x4,
+    /// A symbol hash table
+    Hash = 0x5,
+    /// Dynamic linking information
+    Dynamic = 0x6,
+    /// Notes
+    Note = 0x7,
+    /// A section occupying no space in the file (`.bss`)
+    NoBits = 0x8,
+    /// Relocation entries without explicit addends
+    Rel = 0x9,
+    /// A minimal symbol table for dynamic linking
+    DynSymbol = 0xB,
+    /// An array of constructors
+    InitArray = 0xE,
+    /// An array of destructors
+    FiniArray = 0xF,
+    /// An array of pre-constructors
+    PreInitArray = 0x10,
+    /// A section group
+    Group = 0x11,
+    /// Extended section indices for a symbol table
+    ExtendedSectionIndices = 0x12,
+    /// A GNU-style symbol hash table
+    GnuHash = 0x6ffffff6,
+    /// GNU symbol version definitions
+    GnuVersionDef = 0x6ffffffd,
+    /// GNU symbol version requirements
+    GnuVersionNeed = 0x6ffffffe,
+    /// The GNU symbol version table
+    GnuVersionSymbol = 0x6fffffff,
+    /// Any other unknown section type
+    Other(u32),
+}
+
+impl Display for SectionType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Null => write!(f, "NULL"),
+            Self::ProgramData => write!(f, "PROGBITS"),
+            Self::SymbolTable => write!(f, "SYMTAB"),
+            Self::StringTable => write!(f, "STRTAB"),
+            Self::Rela => write!(f, "RELA"),
+            Self::Hash => write!(f, "HASH"),
+            Self::Dynamic => write!(f, "DYNAMIC"),
+            Self::Note => write!(f, "NOTE"),
+            Self::NoBits => write!(f, "NOBITS"),
+            Self::Rel => write!(f, "REL"),
+            Self::DynSymbol => write!(f, "DYNSYM"),
+            Self::InitArray => write!(f, "INIT_ARRAY"),
+            Self::FiniArray => write!(f, "FINI_ARRAY"),
+            Self::PreInitArray => write!(f, "PREINIT_ARRAY"),
+            Self::Group => write!(f, "GROUP"),
+            Self::ExtendedSectionIndices => write!(f, "SYMTAB SECTION INDICES"),
+            Self::GnuHash => write!(f, "GNU_HASH"),
+            Self::GnuVersionDef => write!(f, "VERDEF"),
+            Self::GnuVersionNeed => write!(f, "VERNEED"),
+            Self::GnuVersionSymbol => write!(f, "VERSYM"),
+            Self::Other(ty @ 0x60000000..=0x6fffffff) => write!(f, "LOOS+{:x}", ty - 0x60000000),
+            Self::Other(ty @ 0x70000000..=0x7fffffff) => {
+                write!(f, "LOPROC+{:x}", ty - 0x70000000)
+            }
+            Self::Other(ty) => write!(f, "<unknown>: {:x}", ty),
+        }
+    }
+}
+
+impl Packable for SectionType {
+    fn pack<W: io::Write + io::Seek>(&self, w: &mut W, big_endian: bool) -> Result<(), io::Error> {
+        let ty: u32 = match self {
+            SectionType::Null => 0x0,
+            SectionType::ProgramData => 0x1,
+            SectionType::SymbolTable => 0x2,
+            SectionType::StringTable => 0x3,
+            SectionType::Rela => 0x4,
+            SectionType::Hash => 0x5,
+            SectionType::Dynamic => 0x6,
+            SectionType::Note => 0x7,
+            SectionType::NoBits => 0x8,
+            SectionType::Rel => 0x9,
+            SectionType::DynSymbol => 0xB,
+            SectionType::InitArray => 0xE,
+            SectionType::FiniArray => 0xF,
+            SectionType::PreInitArray => 0x10,
+            SectionType::Group => 0x11,
+            SectionType::ExtendedSectionIndices => 0x12,
+            SectionType::GnuHash => 0x6ffffff6,
+            SectionType::GnuVersionDef => 0x6ffffffd,
+            SectionType::GnuVersionNeed => 0x6ffffffe,
+            SectionType::GnuVersionSymbol => 0x6fffffff,
+            SectionType::Other(ty) => *ty,
+        };
+
+        ty.pack(w, big_endian)
+    }
+}
+
+impl Unpackable for SectionType {
+    fn unpack<R: io::Read + io::Seek>(r: &mut R, big_endian: bool) -> Result<Self, UnpackError> {
+        let ty = u32::unpack(r, big_endian)?;
+
+        Ok(match ty {
+            0x0 => Self::Null,
+            0x1 => Self::ProgramData,
+            0x2 => Self::SymbolTable,
+            0x3 => Self::StringTable,
+            0x4 => Self::Rela,
+            0x5 => Self::Hash,
+            0x6 => Self::Dynamic,
+            0x7 => Self::Note,
+            0x8 => Self::NoBits,
+            0x9 => Self::Rel,
+            0xB => Self::DynSymbol,
+            0xE => Self::InitArray,
+            0xF => Self::FiniArray,
+            0x10 => Self::PreInitArray,
+            0x11 => Self::Group,
+            0x12 => Self::ExtendedSectionIndices,
+            0x6ffffff6 => Self::GnuHash,
+            0x6ffffffd => Self::GnuVersionDef,
+            0x6ffffffe => Self::GnuVersionNeed,
+            0x6fffffff => Self::GnuVersionSymbol,
+            x => Self::Other(x),
         })
     }
 }
+
+/// The attribute flags of a section
+///
+/// Displays in the short form `readelf` uses (e.g. `WAX`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SectionFlags(pub u64);
+
+impl SectionFlags {
+    /// The section contains writable data
+    pub const WRITE: Self = Self(0x1);
+    /// The section occupies memory during execution
+    pub const ALLOC: Self = Self(0x2);
+    /// The section contains executable instructions
+    pub const EXECINSTR: Self = Self(0x4);
+    /// The section data may be merged to eliminate duplication
+    pub const MERGE: Self = Self(0x10);
+    /// The section consists of null terminated strings
+    pub const STRINGS: Self = Self(0x20);
+    /// The `info` field holds a section header table index
+    pub const INFO_LINK: Self = Self(0x40);
+    /// Special ordering requirements for linkers
+    pub const LINK_ORDER: Self = Self(0x80);
+    /// OS-specific processing is required to avoid incorrect behaviour
+    pub const OS_NONCONFORMING: Self = Self(0x100);
+    /// The section is a member of a section group
+    pub const GROUP: Self = Self(0x200);
+    /// The section holds thread-local data
+    pub const TLS: Self = Self(0x400);
+    /// The section holds compressed data
+    pub const COMPRESSED: Self = Self(0x800);
+    /// The GNU flag to retain the section during garbage collection
+    pub const GNU_RETAIN: Self = Self(0x200000);
+    /// The section is excluded from executables and shared objects
+    pub const EXCLUDE: Self = Self(0x80000000);
+    /// The mask for operating system specific flags
+    pub const MASKOS: Self = Self(0x0ff00000);
+    /// The mask for processor specific flags
+    pub const MASKPROC: Self = Self(0xf0000000);
+
+    /// Returns whether all the bits in `other` are set in `self`
+    /// # Arguments
+    /// * `other` - The flags to check for
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether any of the bits in `other` are set in `self`
+    /// # Arguments
+    /// * `other` - The flags to check for
+    pub fn intersects(&self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Creates flags from their raw value
+    /// # Arguments
+    /// * `raw` - The raw value of the flags
+    pub fn from_raw(raw: u64) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw value of these flags
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns whether the section contains writable data
+    pub fn is_writable(&self) -> bool {
+        self.contains(Self::WRITE)
+    }
+
+    /// Returns whether the section occupies memory during execution
+    pub fn is_allocated(&self) -> bool {
+        self.contains(Self::ALLOC)
+    }
+
+    /// Returns whether the section contains executable instructions
+    pub fn is_executable(&self) -> bool {
+        self.contains(Self::EXECINSTR)
+    }
+
+    /// Returns whether the section may be merged to eliminate duplication
+    pub fn is_mergeable(&self) -> bool {
+        self.contains(Self::MERGE)
+    }
+
+    /// Returns whether the section consists of null terminated strings
+    pub fn is_strings(&self) -> bool {
+        self.contains(Self::STRINGS)
+    }
+
+    /// Returns whether the section holds thread-local data
+    pub fn is_tls(&self) -> bool {
+        self.contains(Self::TLS)
+    }
+
+    /// Returns whether the section holds compressed data
+    pub fn is_compressed(&self) -> bool {
+        self.contains(Self::COMPRESSED)
+    }
+}
+
+impl BitOr for SectionFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for SectionFlags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl Display for SectionFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let letters = [
+            (Self::WRITE, 'W'),
+            (Self::ALLOC, 'A'),
+            (Self::EXECINSTR, 'X'),
+            (Self::MERGE, 'M'),
+            (Self::STRINGS, 'S'),
+            (Self::INFO_LINK, 'I'),
+            (Self::LINK_ORDER, 'L'),
+            (Self::OS_NONCONFORMING, 'O'),
+            (Self::GROUP, 'G'),
+            (Self::TLS, 'T'),
+            (Self::COMPRESSED, 'C'),
+            (Self::GNU_RETAIN, 'R'),
+            (Self::EXCLUDE, 'E'),
+        ];
+
+        let mut remaining = self.0;
+        for (flag, letter) in letters {
+            if self.contains(flag) {
+                write!(f, "{}", letter)?;
+                remaining &= !flag.0;
+            }
+        }
+
+        if remaining & Self::MASKOS.0 != 0 {
+            write!(f, "o")?;
+        }
+        if remaining & Self::MASKPROC.0 != 0 {
+            write!(f, "p")?;
+        }
+        if remaining & !(Self::MASKOS.0 | Self::MASKPROC.0) != 0 {
+            write!(f, "x")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PackableClass for SectionFlags {
+    fn pack_class<W: io::Write + io::Seek>(
+        self,
+        w: &mut W,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<(), io::Error> {
+        self.0.pack_class(w, big_endian, class)
+    }
+}
+
+impl UnpackableClass for SectionFlags {
+    fn unpack_class<R: io::Read + io::Seek>(
+        r: &mut R,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<Self, UnpackError> {
+        Ok(Self(u64::unpack_class(r, big_endian, class)?))
+    }
+}
+
+/// The lowest reserved section index (`SHN_LORESERVE`), higher indices
+/// need the extended section numbering
+pub(crate) const SHN_LORESERVE: u32 = 0xff00;
+
+/// A section index as found in symbols and the ELF header,
+/// distinguishing the reserved indices from real ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionIndex {
+    /// `SHN_UNDEF`: An undefined or meaningless section reference
+    Undefined,
+    /// An index into the section header table
+    Normal(u16),
+    /// `SHN_ABS`: The value is absolute and not affected by relocation
+    Abs,
+    /// `SHN_COMMON`: A common block that is not yet allocated
+    Common,
+    /// `SHN_XINDEX`: The real index is stored elsewhere
+    XIndex,
+    /// `SHN_LOOS..=SHN_HIOS`: Operating system specific semantics
+    OsSpecific(u16),
+    /// `SHN_LOPROC..=SHN_HIPROC`: Processor specific semantics
+    ProcessorSpecific(u16),
+    /// Any other index in the reserved range starting at `SHN_LORESERVE`
+    Reserved(u16),
+}
+
+impl SectionIndex {
+    /// Returns the raw value of this section index
+    pub fn value(&self) -> u16 {
+        match self {
+            Self::Undefined => 0,
+            Self::Normal(index) => *index,
+            Self::Abs => 0xfff1,
+            Self::Common => 0xfff2,
+            Self::XIndex => 0xffff,
+            Self::OsSpecific(index) => *index,
+            Self::ProcessorSpecific(index) => *index,
+            Self::Reserved(index) => *index,
+        }
+    }
+
+    /// Returns the index into the section header table if this is a normal index
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            Self::Normal(index) => Some(*index as usize),
+            _ => None,
+        }
+    }
+}
+
+impl From<u16> for SectionIndex {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::Undefined,
+            0xfff1 => Self::Abs,
+            0xfff2 => Self::Common,
+            0xffff => Self::XIndex,
+            0xff00..=0xff1f => Self::ProcessorSpecific(value),
+            0xff20..=0xff3f => Self::OsSpecific(value),
+            0xff40..=0xfff0 | 0xfff3..=0xfffe => Self::Reserved(value),
+            x => Self::Normal(x),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{ELFFile, ELFType, ProgramHeaderType};
+
+/// The hardening properties of an ELF file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SecurityProperties {
+    /// The stack is not executable, see [ELFFile::has_nx_stack]
+    pub nx_stack: bool,
+    /// Relocated data is made read-only, see [ELFFile::has_relro]
+    pub relro: bool,
+    /// The file is position independent, see [ELFFile::has_pie]
+    pub pie: bool,
+    /// All symbols are bound at load time, see [ELFFile::has_bind_now]
+    pub bind_now: bool,
+}
+
+impl ELFFile {
+    /// Returns whether the stack is not executable
+    ///
+    /// This requires a `PT_GNU_STACK` segment without the execute flag,
+    /// the stack is executable by default without one
+    pub fn has_nx_stack(&self) -> bool {
+        self.programs_of_type(ProgramHeaderType::GnuStack)
+            .next()
+            .is_some_and(|(_, p)| !p.flags.is_executable())
+    }
+
+    /// Returns whether the file has a `PT_GNU_RELRO` segment that is made read-only after relocation
+    pub fn has_relro(&self) -> bool {
+        self.programs_of_type(ProgramHeaderType::GnuRelro)
+            .next()
+            .is_some()
+    }
+
+    /// Returns whether the file is position independent (`ET_DYN`)
+    ///
+    /// This includes shared objects, use [ELFFile::is_pie_flagged] to tell
+    /// position independent executables apart
+    pub fn has_pie(&self) -> bool {
+        self.elf_type() == ELFType::SharedObject
+    }
+
+    /// Returns whether all symbols are bound at load time, see [ELFFile::bind_now]
+    ///
+    /// A malformed dynamic table is treated as not binding now
+    pub fn has_bind_now(&self) -> bool {
+        self.bind_now().unwrap_or(false)
+    }
+
+    /// Returns all hardening properties of this file
+    pub fn security_properties(&self) -> SecurityProperties {
+        SecurityProperties {
+            nx_stack: self.has_nx_stack(),
+            relro: self.has_relro(),
+            pie: self.has_pie(),
+            bind_now: self.has_bind_now(),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use alloc::string::String;
+
+use alloc::borrow::Cow;
+use core::str::Utf8Error;
+
+use crate::{Blob, SectionHeader, SectionType, UnpackError};
+
+/// Reads the null terminated string at the start of `data`
+///
+/// The string ends at the first null byte or at the end of `data` if there is none
+/// # Arguments
+/// * `data` - The bytes to read the string from
+pub fn str_from_null_terminated_slice(data: &[u8]) -> Result<&str, Utf8Error> {
+    let len = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+
+    core::str::from_utf8(&data[..len])
+}
+
+/// A pool of null terminated strings referenced by their byte offset,
+/// as found in `.shstrtab`, `.strtab` and `.dynstr`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StringTable<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> StringTable<'a> {
+    /// Creates a string table from its raw data
+    /// # Arguments
+    /// * `data` - The contents of the string table section
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Gets the string starting at `offset`
+    /// # Arguments
+    /// * `offset` - The byte offset of the string in the table
+    /// # Returns
+    /// [UnpackError::InvalidString] if `offset` is out of bounds, the string
+    /// is not null terminated or it is not valid UTF-8
+    pub fn get(&self, offset: u32) -> Result<&'a str, UnpackError> {
+        let bytes = self.get_bytes(offset)?;
+
+        core::str::from_utf8(bytes).map_err(|_| UnpackError::InvalidString {
+            offset: offset as u64,
+        })
+    }
+
+    /// Gets the raw bytes of the string starting at `offset`, without the null terminator
+    /// # Arguments
+    /// * `offset` - The byte offset of the string in the table
+    /// # Returns
+    /// [UnpackError::InvalidString] if `offset` is out of bounds or the string is not null terminated
+    pub fn get_bytes(&self, offset: u32) -> Result<&'a [u8], UnpackError> {
+        let invalid = || UnpackError::InvalidString {
+            offset: offset as u64,
+        };
+
+        let tail = self.data.get(offset as usize..).ok_or_else(invalid)?;
+        let len = tail.iter().position(|b| *b == 0).ok_or_else(invalid)?;
+
+        Ok(&tail[..len])
+    }
+
+    /// Gets the string starting at `offset`, replacing invalid UTF-8 sequences
+    /// # Arguments
+    /// * `offset` - The byte offset of the string in the table
+    /// # Returns
+    /// [UnpackError::InvalidString] if `offset` is out of bounds or the string is not null terminated
+    pub fn get_lossy(&self, offset: u32) -> Result<Cow<'a, str>, UnpackError> {
+        Ok(String::from_utf8_lossy(self.get_bytes(offset)?))
+    }
+
+    /// Iterates over all strings in the table in order, along with their offsets
+    ///
+    /// An unterminated tail at the end of the table is reported as an error
+    pub fn iter(&self) -> StringTableIter<'a> {
+        StringTableIter {
+            table: *self,
+            offset: 0,
+        }
+    }
+}
+
+impl<'a> From<&'a Blob> for StringTable<'a> {
+    fn from(value: &'a Blob) -> Self {
+        Self::new(&value.blob)
+    }
+}
+
+impl SectionHeader {
+    /// Returns the data of this section as a string table
+    /// # Returns
+    /// `None` if this is not a `SHT_STRTAB` section
+    pub fn as_string_table(&self) -> Option<StringTable<'_>> {
+        match self.ty {
+            SectionType::StringTable => Some(StringTable::from(&self.data)),
+            _ => None,
+        }
+    }
+}
+
+/// An iterator over the strings in a [StringTable]
+pub struct StringTableIter<'a> {
+    table: StringTable<'a>,
+    offset: usize,
+}
+
+impl<'a> Iterator for StringTableIter<'a> {
+    type Item = (u32, Result<&'a str, UnpackError>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.table.data.len() {
+            return None;
+        }
+
+        let offset = self.offset as u32;
+        let res = self.table.get(offset);
+
+        self.offset = match self.table.get_bytes(offset) {
+            Ok(bytes) => self.offset + bytes.len() + 1,
+            Err(_) => self.table.data.len(),
+        };
+
+        Some((offset, res))
+    }
+}
@@ -0,0 +1,650 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use crate::{io::Read, ELFFile, SectionType};
+use crate::{
+    io::{self, Cursor, Seek, SeekFrom},
+    section::SHN_LORESERVE,
+    Class, Packable, PackableClass, SectionHeader, SectionIndex, StringTable, UnpackError,
+    Unpackable, UnpackableClass,
+};
+
+const SYMBOL_SIZE_32: u64 = 0x10;
+const SYMBOL_SIZE_64: u64 = 0x18;
+
+/// A symbol in a symbol table (`.symtab` or `.dynsym`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    /// The index into the linked string table for the name of this symbol
+    pub name_offset: u32,
+    /// The name of this symbol, if it has been resolved through the linked string table
+    pub name: Option<String>,
+    /// The binding (upper 4 bits) and type (lower 4 bits) of this symbol
+    pub info: u8,
+    /// The visibility of this symbol (lower 2 bits)
+    pub other: u8,
+    /// The index of the section this symbol is defined in
+    pub section_index: u16,
+    /// The value of this symbol, normally an address
+    pub value: u64,
+    /// The size of the object described by this symbol
+    pub size: u64,
+}
+
+/// The binding of a symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolBinding {
+    /// Not visible outside the object file
+    Local,
+    /// Visible to all object files being combined
+    Global,
+    /// Like global, but with lower precedence
+    Weak,
+    /// A GNU unique symbol, unique in the entire process
+    GnuUnique,
+    /// Any other unknown binding
+    Other(u8),
+}
+
+/// The type of a symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolType {
+    /// The type is not specified
+    NoType,
+    /// A data object
+    Object,
+    /// A function or other executable code
+    Func,
+    /// A section
+    Section,
+    /// The source file of the object file
+    File,
+    /// An uninitialized common block
+    Common,
+    /// A thread-local storage entity
+    Tls,
+    /// A GNU indirect function
+    GnuIFunc,
+    /// Any other unknown type
+    Other(u8),
+}
+
+/// The visibility of a symbol, stored in the lower `2` bits of `other`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolVisibility {
+    /// Visibility as specified by the binding
+    Default,
+    /// Processor specific hidden class
+    Internal,
+    /// Not visible to other components
+    Hidden,
+    /// Visible, but not preemptible
+    Protected,
+}
+
+impl SymbolBinding {
+    /// Returns the raw value of this binding
+    pub fn value(&self) -> u8 {
+        match self {
+            Self::Local => 0,
+            Self::Global => 1,
+            Self::Weak => 2,
+            Self::GnuUnique => 10,
+            Self::Other(binding) => *binding,
+        }
+    }
+}
+
+impl From<u8> for SymbolBinding {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Local,
+            1 => Self::Global,
+            2 => Self::Weak,
+            10 => Self::GnuUnique,
+            x => Self::Other(x),
+        }
+    }
+}
+
+impl SymbolType {
+    /// Returns the raw value of this type
+    pub fn value(&self) -> u8 {
+        match self {
+            Self::NoType => 0,
+            Self::Object => 1,
+            Self::Func => 2,
+            Self::Section => 3,
+            Self::File => 4,
+            Self::Common => 5,
+            Self::Tls => 6,
+            Self::GnuIFunc => 10,
+            Self::Other(ty) => *ty,
+        }
+    }
+}
+
+impl From<u8> for SymbolType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoType,
+            1 => Self::Object,
+            2 => Self::Func,
+            3 => Self::Section,
+            4 => Self::File,
+            5 => Self::Common,
+            6 => Self::Tls,
+            10 => Self::GnuIFunc,
+            x => Self::Other(x),
+        }
+    }
+}
+
+impl SymbolVisibility {
+    /// Returns the raw value of this visibility
+    pub fn value(&self) -> u8 {
+        match self {
+            Self::Default => 0,
+            Self::Internal => 1,
+            Self::Hidden => 2,
+            Self::Protected => 3,
+        }
+    }
+}
+
+impl From<u8> for SymbolVisibility {
+    fn from(value: u8) -> Self {
+        match value & 0x3 {
+            0 => Self::Default,
+            1 => Self::Internal,
+            2 => Self::Hidden,
+            _ => Self::Protected,
+        }
+    }
+}
+
+/// The visibility of a symbol
+#[deprecated(note = "use SymbolVisibility")]
+pub type Visibility = SymbolVisibility;
+
+impl Symbol {
+    /// Returns the binding of this symbol
+    pub fn binding(&self) -> SymbolBinding {
+        SymbolBinding::from(self.info >> 4)
+    }
+
+    /// Returns the type of this symbol
+    pub fn symbol_type(&self) -> SymbolType {
+        SymbolType::from(self.info & 0xf)
+    }
+
+    /// Returns the type of this symbol
+    #[deprecated(note = "use symbol_type")]
+    pub fn ty(&self) -> SymbolType {
+        self.symbol_type()
+    }
+
+    /// Returns the visibility of this symbol
+    pub fn visibility(&self) -> SymbolVisibility {
+        SymbolVisibility::from(self.other)
+    }
+
+    /// Returns the section this symbol is defined in, resolving reserved indices
+    pub fn section(&self) -> SectionIndex {
+        SectionIndex::from(self.section_index)
+    }
+
+    /// Sets the binding of this symbol, keeping its type
+    /// # Arguments
+    /// * `binding` - The new binding, only the lower `4` bits of its value are used
+    pub fn set_binding(&mut self, binding: SymbolBinding) {
+        self.info = (binding.value() << 4) | (self.info & 0xf);
+    }
+
+    /// Sets the type of this symbol, keeping its binding
+    /// # Arguments
+    /// * `symbol_type` - The new type, only the lower `4` bits of its value are used
+    pub fn set_symbol_type(&mut self, symbol_type: SymbolType) {
+        self.info = (self.info & 0xf0) | (symbol_type.value() & 0xf);
+    }
+
+    /// Sets the type of this symbol, keeping its binding
+    /// # Arguments
+    /// * `ty` - The new type, only the lower `4` bits of its value are used
+    #[deprecated(note = "use set_symbol_type")]
+    pub fn set_ty(&mut self, ty: SymbolType) {
+        self.set_symbol_type(ty);
+    }
+
+    /// Sets the visibility of this symbol, keeping the other bits of `other`
+    /// # Arguments
+    /// * `visibility` - The new visibility
+    pub fn set_visibility(&mut self, visibility: SymbolVisibility) {
+        self.other = (self.other & !0x3) | visibility.value();
+    }
+
+    /// Returns the size a symbol takes in a symbol table of `class`
+    /// # Arguments
+    /// * `class` - The ELF class of the symbol table
+    pub fn get_size(class: Class) -> u64 {
+        match class {
+            Class::ELF32 => SYMBOL_SIZE_32,
+            Class::ELF64 => SYMBOL_SIZE_64,
+        }
+    }
+}
+
+impl PackableClass for Symbol {
+    fn pack_class<W: io::Write + io::Seek>(
+        self,
+        w: &mut W,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<(), io::Error> {
+        self.name_offset.pack(w, big_endian)?;
+
+        match class {
+            Class::ELF32 => {
+                self.value.pack_class(w, big_endian, class)?;
+                self.size.pack_class(w, big_endian, class)?;
+                self.info.pack(w, big_endian)?;
+                self.other.pack(w, big_endian)?;
+                self.section_index.pack(w, big_endian)?;
+            }
+            Class::ELF64 => {
+                self.info.pack(w, big_endian)?;
+                self.other.pack(w, big_endian)?;
+                self.section_index.pack(w, big_endian)?;
+                self.value.pack_class(w, big_endian, class)?;
+                self.size.pack_class(w, big_endian, class)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl UnpackableClass for Symbol {
+    fn unpack_class<R: io::Read + io::Seek>(
+        r: &mut R,
+        big_endian: bool,
+        class: Class,
+    ) -> Result<Self, UnpackError> {
+        let name_offset = u32::unpack(r, big_endian)?;
+
+        Ok(match class {
+            Class::ELF32 => {
+                let value = u64::unpack_class(r, big_endian, class)?;
+                let size = u64::unpack_class(r, big_endian, class)?;
+
+                Self {
+                    name_offset,
+                    name: None,
+                    value,
+                    size,
+                    info: u8::unpack(r, big_endian)?,
+                    other: u8::unpack(r, big_endian)?,
+                    section_index: u16::unpack(r, big_endian)?,
+                }
+            }
+            Class::ELF64 => {
+                let info = u8::unpack(r, big_endian)?;
+                let other = u8::unpack(r, big_endian)?;
+                let section_index = u16::unpack(r, big_endian)?;
+
+                Self {
+                    name_offset,
+                    name: None,
+                    info,
+                    other,
+                    section_index,
+                    value: u64::unpack_class(r, big_endian, class)?,
+                    size: u64::unpack_class(r, big_endian, class)?,
+                }
+            }
+        })
+    }
+}
+
+/// The section a symbol resolves to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolSection<'a> {
+    /// The symbol is undefined
+    Undefined,
+    /// The symbol is absolute and belongs to no section
+    Abs,
+    /// The symbol is an unallocated common block
+    Common,
+    /// The symbol is defined in this section
+    Section(&'a SectionHeader),
+    /// The section index is reserved or out of bounds and cannot be resolved
+    Unresolved(SectionIndex),
+}
+
+/// A parsed symbol table (`SHT_SYMTAB` or `SHT_DYNSYM`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolTable {
+    /// The symbols in table order, including the null symbol at index `0`
+    pub symbols: Vec<Symbol>,
+    /// The section indices of the companion `SHT_SYMTAB_SHNDX` section in table order,
+    /// which hold the real index of symbols with [SectionIndex::XIndex].
+    /// Empty if there is no such section
+    pub extended_indices: Vec<u32>,
+}
+
+impl SymbolTable {
+    /// Parses the symbol table contained in `section`
+    /// # Arguments
+    /// * `section` - The symbol table section
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn from_section(
+        section: &SectionHeader,
+        class: Class,
+        big_endian: bool,
+    ) -> Result<SymbolTable, UnpackError> {
+        Ok(Self {
+            symbols: section
+                .iter_symbols(class, big_endian)
+                .collect::<Result<_, _>>()?,
+            extended_indices: Vec::new(),
+        })
+    }
+
+    /// Reads the extended section indices of the table from a `SHT_SYMTAB_SHNDX` section
+    /// # Arguments
+    /// * `section` - The section linked to the symbol table
+    /// * `big_endian` - Whether the file is big endian
+    pub fn load_extended_indices(
+        &mut self,
+        section: &SectionHeader,
+        big_endian: bool,
+    ) -> Result<(), UnpackError> {
+        let mut cursor = section.data.as_cursor();
+
+        self.extended_indices = (0..section.data.len() / 4)
+            .map(|_| u32::unpack(&mut cursor, big_endian))
+            .collect::<Result<_, _>>()?;
+
+        Ok(())
+    }
+
+    /// Returns the index of the section the symbol at `index` is defined in
+    ///
+    /// Symbols with [SectionIndex::XIndex] are resolved through [SymbolTable::extended_indices]
+    /// # Arguments
+    /// * `index` - The index of the symbol in the table
+    /// # Returns
+    /// `None` if there is no such symbol or its section index is reserved
+    pub fn section_index(&self, index: usize) -> Option<u32> {
+        match self.symbols.get(index)?.section() {
+            SectionIndex::Normal(section) => Some(section as u32),
+            SectionIndex::XIndex => self.extended_indices.get(index).copied(),
+            _ => None,
+        }
+    }
+
+    /// Sets the index of the section the symbol at `index` is defined in
+    ///
+    /// Indices from `0xff00` on collide with the reserved indices and are stored
+    /// in [SymbolTable::extended_indices], the symbol refers to them by [SectionIndex::XIndex]
+    /// # Arguments
+    /// * `index` - The index of the symbol in the table
+    /// * `section` - The index of the section
+    pub fn set_section_index(&mut self, index: usize, section: u32) {
+        let Some(symbol) = self.symbols.get_mut(index) else {
+            return;
+        };
+
+        if section < SHN_LORESERVE {
+            symbol.section_index = section as u16;
+            if let Some(extended) = self.extended_indices.get_mut(index) {
+                *extended = 0;
+            }
+            return;
+        }
+
+        symbol.section_index = SectionIndex::XIndex.value();
+        if self.extended_indices.len() < self.symbols.len() {
+            self.extended_indices.resize(self.symbols.len(), 0);
+        }
+        self.extended_indices[index] = section;
+    }
+
+    /// Packs the symbols into the data of a symbol table section
+    ///
+    /// If any symbol refers to its section by [SectionIndex::XIndex],
+    /// the data of the companion `SHT_SYMTAB_SHNDX` section is returned as well
+    /// # Arguments
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn pack_sections(
+        &self,
+        class: Class,
+        big_endian: bool,
+    ) -> Result<(Vec<u8>, Option<Vec<u8>>), io::Error> {
+        let mut symbols = Cursor::new(Vec::new());
+        for symbol in &self.symbols {
+            symbol.clone().pack_class(&mut symbols, big_endian, class)?;
+        }
+
+        if !self
+            .symbols
+            .iter()
+            .any(|s| s.section() == SectionIndex::XIndex)
+        {
+            return Ok((symbols.into_inner(), None));
+        }
+
+        let mut indices = Cursor::new(Vec::new());
+        for index in 0..self.symbols.len() {
+            let extended = self.extended_indices.get(index).copied().unwrap_or(0);
+            extended.pack(&mut indices, big_endian)?;
+        }
+
+        Ok((symbols.into_inner(), Some(indices.into_inner())))
+    }
+
+    /// Gets the symbol at `index`
+    /// # Arguments
+    /// * `index` - The index of the symbol in the table
+    pub fn get(&self, index: usize) -> Option<&Symbol> {
+        self.symbols.get(index)
+    }
+
+    /// Resolves the names of all symbols through `strings`
+    /// # Arguments
+    /// * `strings` - The string table linked to the symbol table
+    pub fn resolve_names(&mut self, strings: &StringTable) -> Result<(), UnpackError> {
+        for symbol in &mut self.symbols {
+            symbol.name = Some(strings.get(symbol.name_offset)?.to_owned());
+        }
+
+        Ok(())
+    }
+}
+
+/// An iterator over the symbols in a symbol table section
+pub struct SymbolIter<'a> {
+    cursor: Cursor<&'a [u8]>,
+    big_endian: bool,
+    class: Class,
+    entry_size: u64,
+    index: u64,
+    count: u64,
+}
+
+impl SectionHeader {
+    /// Iterates over the symbols contained in this section's data
+    ///
+    /// This includes the mandatory null symbol at index `0`, so the
+    /// yielded symbols line up with their symbol table indices
+    /// # Arguments
+    /// * `class` - The ELF class of the file
+    /// * `big_endian` - Whether the file is big endian
+    pub fn iter_symbols(&self, class: Class, big_endian: bool) -> SymbolIter<'_> {
+        let entry_size = match self.entry_size {
+            0 => Symbol::get_size(class),
+            size => size,
+        };
+
+        SymbolIter {
+            cursor: self.data.as_cursor(),
+            big_endian,
+            class,
+            entry_size,
+            index: 0,
+            count: self.data.blob.len() as u64 / entry_size,
+        }
+    }
+}
+
+impl Iterator for SymbolIter<'_> {
+    type Item = Result<Symbol, UnpackError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let offset = self.index * self.entry_size;
+        self.index += 1;
+
+        if let Err(e) = self.cursor.seek(SeekFrom::Start(offset)) {
+            return Some(Err(e.into()));
+        }
+
+        Some(Symbol::unpack_class(
+            &mut self.cursor,
+            self.big_endian,
+            self.class,
+        ))
+    }
+}
+
+#[cfg(feature = "std")]
+impl ELFFile {
+    /// Reads the symbol table at `index` along with the names of its symbols
+    ///
+    /// The data of the symbol table, its string table and its `SHT_SYMTAB_SHNDX`
+    /// section is read from `r` if not loaded
+    /// # Arguments
+    /// * `r` - The stream the file was loaded from
+    /// * `index` - The index of the symbol table section
+    pub fn read_symbol_table<R: Read + Seek>(
+        &self,
+        r: &mut R,
+        index: usize,
+    ) -> Result<SymbolTable, UnpackError> {
+        let class = self.header.ident.class;
+        let big_endian = self.header.ident.is_big_endian();
+
+        let section = self.section_with_data(r, index)?;
+        let strings = self.section_with_data(r, section.link as usize)?;
+
+        let mut table = SymbolTable::from_section(&section, class, big_endian)?;
+        table.resolve_names(&StringTable::from(&strings.data))?;
+
+        if let Some((shndx, _)) = self
+            .sections_of_type(SectionType::ExtendedSectionIndices)
+            .find(|(_, s)| s.link as usize == index)
+        {
+            let shndx = self.section_with_data(r, shndx)?;
+            table.load_extended_indices(&shndx, big_endian)?;
+        }
+
+        Ok(table)
+    }
+
+    /// Returns the defined global, weak and GNU unique symbols of the dynamic symbol table
+    /// along with their names
+    /// # Arguments
+    /// * `r` - The stream the file was loaded from
+    /// # Returns
+    /// An empty list if there is no dynamic symbol table
+    pub fn exported_symbols<R: Read + Seek>(&self, r: &mut R) -> Result<Vec<Symbol>, UnpackError> {
+        Ok(self
+            .dynamic_symbols(r)?
+            .into_iter()
+            .filter(|s| {
+                matches!(
+                    s.binding(),
+                    SymbolBinding::Global | SymbolBinding::Weak | SymbolBinding::GnuUnique
+                ) && s.section() != SectionIndex::Undefined
+            })
+            .collect())
+    }
+
+    /// Returns the undefined global symbols of the dynamic symbol table along with
+    /// their names, which are the symbols this file imports from its dependencies
+    /// # Arguments
+    /// * `r` - The stream the file was loaded from
+    /// # Returns
+    /// An empty list if there is no dynamic symbol table
+    pub fn imported_symbols<R: Read + Seek>(&self, r: &mut R) -> Result<Vec<Symbol>, UnpackError> {
+        Ok(self
+            .dynamic_symbols(r)?
+            .into_iter()
+            .filter(|s| {
+                s.binding() == SymbolBinding::Global && s.section() == SectionIndex::Undefined
+            })
+            .collect())
+    }
+
+    /// Returns the undefined global symbols of the dynamic symbol table,
+    /// see [ELFFile::imported_symbols]
+    /// # Arguments
+    /// * `r` - The stream the file was loaded from
+    pub fn undefined_symbols<R: Read + Seek>(&self, r: &mut R) -> Result<Vec<Symbol>, UnpackError> {
+        self.imported_symbols(r)
+    }
+
+    /// Finds the first symbol named `name`, searching the symbol table (`SHT_SYMTAB`)
+    /// before the dynamic symbol table (`SHT_DYNSYM`)
+    /// # Arguments
+    /// * `r` - The stream the file was loaded from
+    /// * `name` - The name of the symbol
+    /// # Returns
+    /// The symbol along with its name, `None` if there is no such symbol or no symbol table
+    pub fn find_symbol_by_name<R: Read + Seek>(
+        &self,
+        r: &mut R,
+        name: &str,
+    ) -> Result<Option<Symbol>, UnpackError> {
+        if name.is_empty() {
+            return Ok(None);
+        }
+
+        for ty in [SectionType::SymbolTable, SectionType::DynSymbol] {
+            let indices: Vec<usize> = self.sections_of_type(ty).map(|(i, _)| i).collect();
+
+            for index in indices {
+                let table = self.read_symbol_table(r, index)?;
+
+                if let Some(symbol) = table
+                    .symbols
+                    .into_iter()
+                    .find(|s| s.name.as_deref() == Some(name))
+                {
+                    return Ok(Some(symbol));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the symbols of the dynamic symbol table without the null symbol
+    fn dynamic_symbols<R: Read + Seek>(&self, r: &mut R) -> Result<Vec<Symbol>, UnpackError> {
+        let Some((index, _)) = self.sections_of_type(SectionType::DynSymbol).next() else {
+            return Ok(Vec::new());
+        };
+
+        Ok(self
+            .read_symbol_table(r, index)?
+            .symbols
+            .into_iter()
+            .skip(1)
+            .collect())
+    }
+}
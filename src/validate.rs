@@ -0,0 +1,174 @@
+use std::fmt::Display;
+
+use crate::{ELFFile, ProgramHeaderType, SectionIndex};
+
+/// A structural inconsistency of an ELF file that makes it unsafe to process
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ELFValidationError {
+    /// The header, program header or section header size does not match the class
+    InvalidHeaderSize,
+    /// The section header table overlaps the ELF header or exceeds the addressable range
+    SectionHeaderOffsetOutOfBounds,
+    /// The program header table overlaps the ELF header or exceeds the addressable range
+    ProgramHeaderOffsetOutOfBounds,
+    /// The data of a section exceeds the addressable range
+    SectionSizeOverflow {
+        /// The index of the section
+        index: usize,
+    },
+    /// The data of two sections starts at the same offset
+    DuplicateSectionOffset {
+        /// The index of the first section
+        a: usize,
+        /// The index of the second section
+        b: usize,
+    },
+    /// The index of the section name string table does not refer to a section
+    ShStrIndexOutOfRange,
+    /// A loadable segment occupies no memory
+    ZeroSizeLoadableSegment {
+        /// The index of the program header
+        index: usize,
+    },
+    /// The alignment of a section is not a power of two or its address is not aligned to it
+    UnalignedSection {
+        /// The index of the section
+        index: usize,
+    },
+    /// The data of two sections overlaps
+    OverlappingSections {
+        /// The index of the first section
+        a: usize,
+        /// The index of the second section
+        b: usize,
+    },
+}
+
+impl Display for ELFValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHeaderSize => write!(f, "invalid header size"),
+            Self::SectionHeaderOffsetOutOfBounds => {
+                write!(f, "section header table out of bounds")
+            }
+            Self::ProgramHeaderOffsetOutOfBounds => {
+                write!(f, "program header table out of bounds")
+            }
+            Self::SectionSizeOverflow { index } => {
+                write!(f, "size of section {} overflows", index)
+            }
+            Self::DuplicateSectionOffset { a, b } => {
+                write!(f, "sections {} and {} start at the same offset", a, b)
+            }
+            Self::ShStrIndexOutOfRange => write!(f, "section name string table index out of range"),
+            Self::ZeroSizeLoadableSegment { index } => {
+                write!(f, "loadable segment {} has no size", index)
+            }
+            Self::UnalignedSection { index } => write!(f, "section {} is not aligned", index),
+            Self::OverlappingSections { a, b } => {
+                write!(f, "the data of sections {} and {} overlaps", a, b)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ELFValidationError {}
+
+impl ELFFile {
+    /// Checks the structure of this file for inconsistencies
+    ///
+    /// As the size of the file is not known, offsets are only checked
+    /// for overlapping the ELF header or overflowing the addressable range
+    /// # Returns
+    /// All errors found, empty for a valid file
+    pub fn validate(&self) -> Vec<ELFValidationError> {
+        let mut errors = Vec::new();
+        let header_size = self.header.get_header_size();
+
+        if !self.header.has_valid_sizes() {
+            errors.push(ELFValidationError::InvalidHeaderSize);
+        }
+
+        if !Self::table_in_bounds(
+            self.header.sh_offset,
+            self.section_headers.len(),
+            self.header.get_section_header_size(),
+            header_size,
+        ) {
+            errors.push(ELFValidationError::SectionHeaderOffsetOutOfBounds);
+        }
+
+        if !Self::table_in_bounds(
+            self.header.ph_offset,
+            self.program_headers.len(),
+            self.header.get_program_header_size(),
+            header_size,
+        ) {
+            errors.push(ELFValidationError::ProgramHeaderOffsetOutOfBounds);
+        }
+
+        let str_index = match SectionIndex::from(self.header.sh_str_index) {
+            SectionIndex::XIndex => self.section_headers.first().map(|s| s.link as usize),
+            _ => Some(self.header.sh_str_index as usize),
+        };
+        match str_index {
+            Some(0) => {}
+            Some(index) if index < self.section_headers.len() => {}
+            _ => errors.push(ELFValidationError::ShStrIndexOutOfRange),
+        }
+
+        for (index, segment) in self.programs_of_type(ProgramHeaderType::Loadable) {
+            if segment.mem_size == 0 {
+                errors.push(ELFValidationError::ZeroSizeLoadableSegment { index });
+            }
+        }
+
+        let mut ranges = Vec::new();
+        for (index, section) in self.section_headers.iter().enumerate() {
+            let align = section.addr_align;
+            if align > 1 && (!align.is_power_of_two() || !section.address.is_multiple_of(align)) {
+                errors.push(ELFValidationError::UnalignedSection { index });
+            }
+
+            if !section.has_file_data() || section.size == 0 {
+                continue;
+            }
+
+            match section.offset.checked_add(section.size) {
+                Some(end) => ranges.push((section.offset, end, index)),
+                None => errors.push(ELFValidationError::SectionSizeOverflow { index }),
+            }
+        }
+
+        ranges.sort();
+        for (i, (start, end, a)) in ranges.iter().enumerate() {
+            for (other_start, _, b) in &ranges[i + 1..] {
+                if other_start >= end {
+                    break;
+                }
+
+                let (a, b) = (*a.min(b), *a.max(b));
+                errors.push(match other_start == start {
+                    true => ELFValidationError::DuplicateSectionOffset { a, b },
+                    false => ELFValidationError::OverlappingSections { a, b },
+                });
+            }
+        }
+
+        errors
+    }
+
+    /// Returns whether a table of `count` entries of `entry_size` bytes
+    /// at `offset` lies behind the ELF header and within the addressable range
+    fn table_in_bounds(offset: u64, count: usize, entry_size: u64, header_size: u64) -> bool {
+        if count == 0 {
+            return true;
+        }
+
+        offset >= header_size
+            && entry_size
+                .checked_mul(count as u64)
+                .and_then(|size| offset.checked_add(size))
+                .is_some()
+    }
+}
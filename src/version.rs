@@ -0,0 +1,398 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(feature = "std")]
+use crate::{io::Read, ELFFile, SectionIndex, SectionType, SymbolTable};
+use crate::{
+    io::{Cursor, Seek, SeekFrom},
+    Blob, StringTable, Symbol, UnpackError, Unpackable,
+};
+
+/// An entry of the GNU symbol version table (`.gnu.version`), one per dynamic symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VersionSymbol(pub u16);
+
+impl VersionSymbol {
+    /// `VER_NDX_LOCAL`: The symbol is local and not versioned
+    pub const LOCAL: u16 = 0;
+    /// `VER_NDX_GLOBAL`: The symbol is global and not versioned
+    pub const GLOBAL: u16 = 1;
+    /// The bit marking a version as hidden
+    pub const HIDDEN: u16 = 0x8000;
+
+    /// Returns the version index without the hidden bit, refers to
+    /// [VersionDef::index] or [VersionNeedAux::index]
+    pub fn index(&self) -> u16 {
+        self.0 & !Self::HIDDEN
+    }
+
+    /// Returns whether the version is hidden, so the symbol is not the default version
+    pub fn is_hidden(&self) -> bool {
+        self.0 & Self::HIDDEN != 0
+    }
+
+    /// Parses the version table contained in `blob`
+    /// # Arguments
+    /// * `blob` - The data of the `SHT_GNU_versym` section
+    /// * `big_endian` - Whether the file is big endian
+    pub fn parse_table(blob: &Blob, big_endian: bool) -> Result<Vec<VersionSymbol>, UnpackError> {
+        let mut r = blob.as_cursor();
+
+        (0..blob.len() / 2)
+            .map(|_| Ok(Self(u16::unpack(&mut r, big_endian)?)))
+            .collect()
+    }
+}
+
+/// A version defined by this file, from `.gnu.version_d`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionDef {
+    /// The version of the structure (`1`)
+    pub version: u16,
+    /// The flags of the version (`VER_FLG_BASE` for the file itself, `VER_FLG_WEAK`)
+    pub flags: u16,
+    /// The version index referenced by [VersionSymbol::index]
+    pub index: u16,
+    /// The ELF hash of the version name
+    pub hash: u32,
+    /// The name of the version followed by the names of its parent versions
+    pub names: Vec<String>,
+}
+
+impl VersionDef {
+    /// `VER_FLG_BASE`: The definition of the file itself
+    pub const FLAG_BASE: u16 = 0x1;
+
+    /// Returns the name of this version
+    pub fn name(&self) -> Option<&str> {
+        self.names.first().map(|n| n.as_str())
+    }
+
+    /// Parses the version definitions contained in `blob`
+    /// # Arguments
+    /// * `blob` - The data of the `SHT_GNU_verdef` section
+    /// * `big_endian` - Whether the file is big endian
+    /// * `strings` - The string table linked to the section
+    pub fn parse_table(
+        blob: &Blob,
+        big_endian: bool,
+        strings: &StringTable,
+    ) -> Result<Vec<VersionDef>, UnpackError> {
+        let mut r = blob.as_cursor();
+        let mut res = Vec::new();
+
+        for offset in chain_offsets(&mut r, big_endian, 0, 16)? {
+            r.seek(SeekFrom::Start(offset))?;
+
+            let version = u16::unpack(&mut r, big_endian)?;
+            let flags = u16::unpack(&mut r, big_endian)?;
+            let index = u16::unpack(&mut r, big_endian)?;
+            let _count = u16::unpack(&mut r, big_endian)?;
+            let hash = u32::unpack(&mut r, big_endian)?;
+            let aux = u32::unpack(&mut r, big_endian)?;
+
+            let mut names = Vec::new();
+            if aux != 0 {
+                for aux_offset in chain_offsets(&mut r, big_endian, offset + aux as u64, 4)? {
+                    r.seek(SeekFrom::Start(aux_offset))?;
+                    let name = u32::unpack(&mut r, big_endian)?;
+                    names.push(strings.get(name)?.to_owned());
+                }
+            }
+
+            res.push(Self {
+                version,
+                flags,
+                index,
+                hash,
+                names,
+            });
+        }
+
+        Ok(res)
+    }
+}
+
+/// The versions required from a dependency, from `.gnu.version_r`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionNeed {
+    /// The version of the structure (`1`)
+    pub version: u16,
+    /// The name of the dependency (e.g. `libc.so.6`)
+    pub file: String,
+    /// The versions required from the dependency
+    pub entries: Vec<VersionNeedAux>,
+}
+
+/// A single version required from a dependency
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionNeedAux {
+    /// The ELF hash of the version name
+    pub hash: u32,
+    /// The flags of the requirement (`VER_FLG_WEAK`)
+    pub flags: u16,
+    /// The version index referenced by [VersionSymbol::index]
+    pub index: u16,
+    /// The name of the version (e.g. `GLIBC_2.34`)
+    pub name: String,
+}
+
+impl VersionNeed {
+    /// Parses the version requirements contained in `blob`
+    /// # Arguments
+    /// * `blob` - The data of the `SHT_GNU_verneed` section
+    /// * `big_endian` - Whether the file is big endian
+    /// * `strings` - The string table linked to the section
+    pub fn parse_table(
+        blob: &Blob,
+        big_endian: bool,
+        strings: &StringTable,
+    ) -> Result<Vec<VersionNeed>, UnpackError> {
+        let mut r = blob.as_cursor();
+        let mut res = Vec::new();
+
+        for offset in chain_offsets(&mut r, big_endian, 0, 12)? {
+            r.seek(SeekFrom::Start(offset))?;
+
+            let version = u16::unpack(&mut r, big_endian)?;
+            let _count = u16::unpack(&mut r, big_endian)?;
+            let file = u32::unpack(&mut r, big_endian)?;
+            let aux = u32::unpack(&mut r, big_endian)?;
+
+            let mut entries = Vec::new();
+            if aux != 0 {
+                for aux_offset in chain_offsets(&mut r, big_endian, offset + aux as u64, 12)? {
+                    r.seek(SeekFrom::Start(aux_offset))?;
+
+                    let hash = u32::unpack(&mut r, big_endian)?;
+                    let flags = u16::unpack(&mut r, big_endian)?;
+                    let index = u16::unpack(&mut r, big_endian)?;
+                    let name = u32::unpack(&mut r, big_endian)?;
+
+                    entries.push(VersionNeedAux {
+                        hash,
+                        flags,
+                        index,
+                        name: strings.get(name)?.to_owned(),
+                    });
+                }
+            }
+
+            res.push(Self {
+                version,
+                file: strings.get(file)?.to_owned(),
+                entries,
+            });
+        }
+
+        Ok(res)
+    }
+}
+
+/// A dynamic symbol joined with its version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionedSymbol {
+    /// The symbol along with its name
+    pub symbol: Symbol,
+    /// The name of the version (e.g. `GLIBC_2.2.5`), `None` for unversioned symbols
+    pub version: Option<String>,
+    /// Whether the version is hidden, so the symbol can only be referenced explicitly
+    pub hidden: bool,
+    /// Whether this is the default version of a symbol defined by this file (`foo@@VERSION`)
+    pub is_default: bool,
+}
+
+/// Collects the offsets of a chain of version structures starting at `start`
+///
+/// Each structure stores the offset of the next one relative to itself
+/// at `next_field`, the chain ends at a relative offset of `0`. Chains
+/// that leave the data or run in circles are cut off
+fn chain_offsets(
+    r: &mut Cursor<&[u8]>,
+    big_endian: bool,
+    start: u64,
+    next_field: u64,
+) -> Result<Vec<u64>, UnpackError> {
+    let len = r.get_ref().len() as u64;
+    let mut offsets = Vec::new();
+    let mut offset = start;
+
+    while offset < len && offsets.len() as u64 <= len {
+        offsets.push(offset);
+
+        r.seek(SeekFrom::Start(offset + next_field))?;
+        let next = u32::unpack(r, big_endian)?;
+        if next == 0 {
+            break;
+        }
+
+        offset += next as u64;
+    }
+
+    Ok(offsets)
+}
+
+#[cfg(feature = "std")]
+impl ELFFile {
+    /// Returns the symbol version table (`.gnu.version`) with one entry per dynamic symbol
+    /// # Returns
+    /// An empty list if there is no symbol version table
+    pub fn version_symbols(&self) -> Result<Vec<VersionSymbol>, UnpackError> {
+        match self.sections_of_type(SectionType::GnuVersionSymbol).next() {
+            Some((_, section)) => {
+                VersionSymbol::parse_table(&section.data, self.header.ident.is_big_endian())
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the versions defined by this file (`.gnu.version_d`)
+    /// # Returns
+    /// An empty list if there are no version definitions
+    pub fn version_definitions(&self) -> Result<Vec<VersionDef>, UnpackError> {
+        let Some((_, section)) = self.sections_of_type(SectionType::GnuVersionDef).next() else {
+            return Ok(Vec::new());
+        };
+
+        let strings = self.linked_string_table(section.link)?;
+        VersionDef::parse_table(&section.data, self.header.ident.is_big_endian(), &strings)
+    }
+
+    /// Returns the versions this file requires from its dependencies (`.gnu.version_r`)
+    /// # Returns
+    /// An empty list if there are no version requirements
+    pub fn version_needs(&self) -> Result<Vec<VersionNeed>, UnpackError> {
+        let Some((_, section)) = self.sections_of_type(SectionType::GnuVersionNeed).next() else {
+            return Ok(Vec::new());
+        };
+
+        let strings = self.linked_string_table(section.link)?;
+        VersionNeed::parse_table(&section.data, self.header.ident.is_big_endian(), &strings)
+    }
+
+    /// Returns the dynamic symbols along with their names and versions
+    ///
+    /// `VER_NDX_LOCAL` and `VER_NDX_GLOBAL` symbols have no version. Other
+    /// version indices refer to a version defined by this file or required from a dependency
+    /// # Returns
+    /// An empty list if there is no dynamic symbol table
+    pub fn dynamic_symbols_with_versions(&self) -> Result<Vec<VersionedSymbol>, UnpackError> {
+        let Some((_, section)) = self.sections_of_type(SectionType::DynSymbol).next() else {
+            return Ok(Vec::new());
+        };
+
+        let mut table = SymbolTable::from_section(
+            section,
+            self.header.ident.class,
+            self.header.ident.is_big_endian(),
+        )?;
+        table.resolve_names(&self.linked_string_table(section.link)?)?;
+
+        let versions = self.version_symbols()?;
+        let definitions = self.version_definitions()?;
+        let needs = self.version_needs()?;
+
+        Ok(table
+            .symbols
+            .into_iter()
+            .enumerate()
+            .map(|(i, symbol)| {
+                let version = versions.get(i).copied().unwrap_or_default();
+
+                let (name, defined) = match version.index() {
+                    VersionSymbol::LOCAL | VersionSymbol::GLOBAL => (None, false),
+                    index => match definitions.iter().find(|d| d.index == index) {
+                        Some(definition) => (definition.name(), true),
+                        None => (
+                            needs
+                                .iter()
+                                .flat_map(|n| &n.entries)
+                                .find(|e| e.index == index)
+                                .map(|e| e.name.as_str()),
+                            false,
+                        ),
+                    },
+                };
+
+                VersionedSymbol {
+                    is_default: defined
+                        && !version.is_hidden()
+                        && symbol.section() != SectionIndex::Undefined,
+                    version: name.map(|v| v.to_owned()),
+                    hidden: version.is_hidden(),
+                    symbol,
+                }
+            })
+            .collect())
+    }
+
+    /// Returns the version names of the dynamic symbols, such as `GLIBC_2.17`
+    ///
+    /// The data of the version sections is read from `r` if not loaded
+    /// # Arguments
+    /// * `r` - The stream the file was loaded from
+    /// # Returns
+    /// A map from the index of each versioned dynamic symbol to its version name,
+    /// `VER_NDX_LOCAL` and `VER_NDX_GLOBAL` symbols are not contained
+    pub fn symbol_version_map<R: Read + Seek>(
+        &self,
+        r: &mut R,
+    ) -> Result<HashMap<u32, String>, UnpackError> {
+        let big_endian = self.header.ident.is_big_endian();
+
+        let Some((index, _)) = self.sections_of_type(SectionType::GnuVersionSymbol).next() else {
+            return Ok(HashMap::new());
+        };
+        let versions =
+            VersionSymbol::parse_table(&self.section_with_data(r, index)?.data, big_endian)?;
+
+        let mut names = HashMap::new();
+
+        if let Some((index, _)) = self.sections_of_type(SectionType::GnuVersionDef).next() {
+            let section = self.section_with_data(r, index)?;
+            let strings = self.section_with_data(r, section.link as usize)?;
+
+            for definition in VersionDef::parse_table(
+                &section.data,
+                big_endian,
+                &StringTable::from(&strings.data),
+            )? {
+                if let Some(name) = definition.name() {
+                    names.insert(definition.index, name.to_owned());
+                }
+            }
+        }
+
+        if let Some((index, _)) = self.sections_of_type(SectionType::GnuVersionNeed).next() {
+            let section = self.section_with_data(r, index)?;
+            let strings = self.section_with_data(r, section.link as usize)?;
+
+            for need in VersionNeed::parse_table(
+                &section.data,
+                big_endian,
+                &StringTable::from(&strings.data),
+            )? {
+                for entry in need.entries {
+                    names.insert(entry.index, entry.name);
+                }
+            }
+        }
+
+        Ok(versions
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.index() > VersionSymbol::GLOBAL)
+            .filter_map(|(i, v)| Some((i as u32, names.get(&v.index())?.clone())))
+            .collect())
+    }
+
+    /// Returns the string table at the section index `link`
+    fn linked_string_table(&self, link: u32) -> Result<StringTable<'_>, UnpackError> {
+        self.section_headers
+            .get(link as usize)
+            .and_then(|s| s.as_string_table())
+            .ok_or(UnpackError::MissingStringTable)
+    }
+}
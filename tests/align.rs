@@ -0,0 +1,77 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{align_down, align_up, Machine, ProgramHeaderType};
+
+#[test]
+fn align_up_at_boundaries() {
+    assert_eq!(align_up(0, 0x1000), Some(0));
+    assert_eq!(align_up(1, 0x1000), Some(0x1000));
+    assert_eq!(align_up(0x1000, 0x1000), Some(0x1000));
+    assert_eq!(align_up(0x1001, 0x1000), Some(0x2000));
+
+    assert_eq!(align_up(u64::MAX, 0), Some(u64::MAX));
+    assert_eq!(align_up(u64::MAX, 1), Some(u64::MAX));
+    assert_eq!(align_up(u64::MAX, 0x1000), None);
+    assert_eq!(align_up(u64::MAX - 0xfff, 0x1000), Some(u64::MAX - 0xfff));
+    assert_eq!(align_up(u64::MAX - 0x1000, 0x1000), Some(u64::MAX - 0xfff));
+    assert_eq!(align_up(u64::MAX, u64::MAX), Some(u64::MAX));
+    assert_eq!(align_up(1, u64::MAX), Some(u64::MAX));
+    assert_eq!(align_up(1, 1 << 63), Some(1 << 63));
+    assert_eq!(align_up((1 << 63) + 1, 1 << 63), None);
+
+    // Alignments need not be powers of two
+    assert_eq!(align_up(10, 3), Some(12));
+}
+
+#[test]
+fn align_down_at_boundaries() {
+    assert_eq!(align_down(0, 0x1000), 0);
+    assert_eq!(align_down(0xfff, 0x1000), 0);
+    assert_eq!(align_down(0x1fff, 0x1000), 0x1000);
+
+    assert_eq!(align_down(u64::MAX, 0), u64::MAX);
+    assert_eq!(align_down(u64::MAX, 1), u64::MAX);
+    assert_eq!(align_down(u64::MAX, 0x1000), u64::MAX - 0xfff);
+    assert_eq!(align_down(u64::MAX, u64::MAX), u64::MAX);
+    assert_eq!(align_down(u64::MAX - 1, u64::MAX), 0);
+    assert_eq!(align_down(u64::MAX, 1 << 63), 1 << 63);
+    assert_eq!(align_down(10, 3), 9);
+}
+
+#[test]
+fn page_sizes() {
+    assert_eq!(Machine::X86_64.common_page_size(), Some(0x1000));
+    assert_eq!(Machine::X86_64.max_page_size(), Some(0x1000));
+    assert_eq!(Machine::AArch64.common_page_size(), Some(0x1000));
+    assert_eq!(Machine::AArch64.max_page_size(), Some(0x10000));
+    assert_eq!(Machine::PowerPC64.max_page_size(), Some(0x10000));
+    assert_eq!(Machine::Other(0x1234).common_page_size(), None);
+    assert_eq!(Machine::Other(0x1234).max_page_size(), None);
+}
+
+#[test]
+fn loadable_segments_are_congruent() {
+    let elf = load_fixture("hello");
+    let page_size = elf.header.machine.max_page_size().unwrap();
+
+    for segment in &elf.program_headers {
+        if segment.ty == ProgramHeaderType::Loadable {
+            assert!(segment.is_congruent(page_size));
+            assert!(segment.is_congruent(segment.alignment));
+        }
+    }
+
+    let mut segment = elf.program_headers[3].clone();
+    assert!(segment.is_congruent(0));
+    segment.offset += 8;
+    assert!(!segment.is_congruent(page_size));
+    assert!(segment.is_congruent(1));
+    assert!(segment.is_congruent(8));
+
+    segment.offset = u64::MAX;
+    segment.virtual_addr = u64::MAX;
+    assert!(segment.is_congruent(u64::MAX));
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{Blob, ELFFile};
+
+#[test]
+fn find_bytes() {
+    let blob = Blob::from(b"abcabcab".to_vec());
+
+    assert_eq!(blob.find_bytes(b"bc"), Some(1));
+    assert_eq!(blob.find_bytes(b"ab"), Some(0));
+    assert_eq!(blob.find_bytes(b"cab"), Some(2));
+    assert_eq!(blob.find_bytes(b"abd"), None);
+    assert_eq!(blob.find_bytes(b"abcabcabc"), None);
+    assert_eq!(blob.find_bytes(b""), None);
+    assert_eq!(Blob::default().find_bytes(b"a"), None);
+}
+
+#[test]
+fn find_all_bytes_overlapping() {
+    let blob = Blob::from(b"aaaa".to_vec());
+    assert_eq!(blob.find_all_bytes(b"aa"), [0, 1, 2]);
+    assert_eq!(blob.find_all_bytes(b"a"), [0, 1, 2, 3]);
+    assert_eq!(blob.find_all_bytes(b"aaaaa"), Vec::<usize>::new());
+    assert_eq!(blob.find_all_bytes(b""), Vec::<usize>::new());
+
+    let blob = Blob::from(b"abcabcab".to_vec());
+    assert_eq!(blob.find_all_bytes(b"ab"), [0, 3, 6]);
+}
+
+#[test]
+fn replace_first() {
+    let mut blob = Blob::from(b"abcabc".to_vec());
+
+    assert!(blob.replace_first(b"bc", b"xy"));
+    assert_eq!(blob.blob, b"axyabc");
+    assert!(blob.replace_first(b"bc", b"zz"));
+    assert_eq!(blob.blob, b"axyazz");
+    assert!(!blob.replace_first(b"bc", b"zz"));
+
+    // Replacements must not change the length of the data
+    assert!(!blob.replace_first(b"xy", b"x"));
+    assert!(!blob.replace_first(b"xy", b"xyz"));
+    assert!(!blob.replace_first(b"", b""));
+    assert_eq!(blob.blob, b"axyazz");
+}
+
+#[test]
+fn replace_all_overlapping() {
+    // Matches are replaced from the start and do not overlap
+    let mut blob = Blob::from(b"aaaaa".to_vec());
+    assert_eq!(blob.replace_all(b"aa", b"bb"), 2);
+    assert_eq!(blob.blob, b"bbbba");
+
+    let mut blob = Blob::from(b"abab".to_vec());
+    assert_eq!(blob.replace_all(b"aba", b"bab"), 1);
+    assert_eq!(blob.blob, b"babb");
+}
+
+#[test]
+fn replace_all_with_different_length() {
+    let mut blob = Blob::from(b"abcabc".to_vec());
+
+    assert_eq!(blob.replace_all(b"bc", b"b"), 0);
+    assert_eq!(blob.replace_all(b"bc", b"bcd"), 0);
+    assert_eq!(blob.replace_all(b"", b""), 0);
+    assert_eq!(blob.replace_all(b"xy", b"zz"), 0);
+    assert_eq!(blob.blob, b"abcabc");
+}
+
+#[test]
+fn replace_in_section() {
+    let mut elf = load_fixture("hello");
+    let index = elf.section_index_by_name(".comment").unwrap();
+    let comment = &mut elf.section_headers[index].data;
+
+    let count = comment.find_all_bytes(b"GCC").len();
+    assert!(count > 0);
+    assert_eq!(comment.replace_all(b"GCC", b"gcc"), count);
+
+    let elf = ELFFile::from_bytes_fully(&elf.to_bytes().unwrap()).unwrap();
+    let comment = &elf.section_headers[index].data;
+    assert_eq!(comment.find_bytes(b"GCC"), None);
+    assert_eq!(comment.find_all_bytes(b"gcc").len(), count);
+}
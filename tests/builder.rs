@@ -0,0 +1,152 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use elfsmith::{
+    Blob, Class, ELFBuilder, ELFFile, ELFType, Endianness, Machine, ProgramHeader,
+    ProgramHeaderType, SectionFlags, SectionHeader, SectionType, SegmentFlags,
+};
+
+/// Returns a section header of `ty` with everything the builder computes left empty
+fn section(ty: SectionType, flags: SectionFlags, size: u64, addr_align: u64) -> SectionHeader {
+    SectionHeader {
+        name: 0,
+        ty,
+        flags,
+        address: 0,
+        offset: 0,
+        size,
+        link: 0,
+        info: 0,
+        addr_align,
+        entry_size: 0,
+        data: Blob::default(),
+    }
+}
+
+/// Builds an executable with a `.text`, `.bss` and `.data` section and a `PT_PHDR` for `class`
+fn build(class: Class, machine: Machine) -> ELFFile {
+    let mut builder = ELFBuilder::new(class, Endianness::Little, machine);
+    builder
+        .set_type(ELFType::Executable)
+        .set_entry_point(0x401000)
+        .add_program_header(ProgramHeader {
+            ty: ProgramHeaderType::ProgramHeaderTable,
+            flags: SegmentFlags::READ,
+            offset: 0,
+            virtual_addr: 0x400040,
+            physical_addr: 0x400040,
+            file_size: 0,
+            mem_size: 0,
+            alignment: 8,
+            data: Blob::default(),
+        })
+        .add_section(
+            ".text",
+            section(
+                SectionType::ProgramData,
+                SectionFlags::ALLOC | SectionFlags::EXECINSTR,
+                0,
+                16,
+            ),
+            vec![0xc3; 5],
+        )
+        .add_section(
+            ".bss",
+            section(
+                SectionType::NoBits,
+                SectionFlags::ALLOC | SectionFlags::WRITE,
+                0x1000,
+                32,
+            ),
+            Vec::new(),
+        )
+        .add_section(
+            ".data",
+            section(
+                SectionType::ProgramData,
+                SectionFlags::ALLOC | SectionFlags::WRITE,
+                0,
+                8,
+            ),
+            vec![1, 2, 3],
+        );
+
+    let mut bytes = Cursor::new(Vec::new());
+    builder.build(&mut bytes).unwrap();
+
+    ELFFile::from_bytes_fully(&bytes.into_inner()).unwrap()
+}
+
+#[test]
+fn built_files_reload() {
+    for (class, machine) in [
+        (Class::ELF32, Machine::X86),
+        (Class::ELF64, Machine::X86_64),
+    ] {
+        let elf = build(class, machine);
+        let header_size = elf.header.get_header_size();
+        let ph_size = elf.header.get_program_header_size();
+
+        assert_eq!(elf.header.ident.class, class);
+        assert_eq!(elf.header.entry_point, 0x401000);
+        assert_eq!(elf.elf_type(), ELFType::Executable);
+        assert_eq!(elf.validate(), Vec::new());
+
+        // The null section and `.shstrtab` are added around the sections
+        let names: Vec<_> = (0..elf.section_headers.len())
+            .map(|i| elf.section_name(i).unwrap())
+            .collect();
+        assert_eq!(names, ["", ".text", ".bss", ".data", ".shstrtab"]);
+        assert_eq!(elf.header.sh_str_index, 4);
+        assert_eq!(elf.section_headers[4].ty, SectionType::StringTable);
+
+        let phdr = &elf.program_headers[0];
+        assert_eq!(elf.header.ph_offset, header_size);
+        assert_eq!(phdr.offset, header_size);
+        assert_eq!(phdr.file_size, ph_size);
+        assert_eq!(phdr.mem_size, ph_size);
+
+        let text = &elf.section_headers[1];
+        assert_eq!(text.offset, (header_size + ph_size).next_multiple_of(16));
+        assert_eq!(text.size, 5);
+        assert_eq!(text.data.blob, [0xc3; 5]);
+
+        // `SHT_NOBITS` sections keep their size, but the next section does not skip them
+        let bss = &elf.section_headers[2];
+        assert_eq!(bss.offset, (text.offset + 5).next_multiple_of(32));
+        assert_eq!(bss.size, 0x1000);
+        assert!(bss.data.blob.is_empty());
+
+        let data = &elf.section_headers[3];
+        assert_eq!(data.offset, (text.offset + 5).next_multiple_of(8));
+        assert_eq!(data.data.blob, [1, 2, 3]);
+
+        let shstrtab = &elf.section_headers[4];
+        assert_eq!(shstrtab.offset, data.offset + 3);
+        let word_size = match class {
+            Class::ELF32 => 4,
+            Class::ELF64 => 8,
+        };
+        assert_eq!(
+            elf.header.sh_offset,
+            (shstrtab.offset + shstrtab.size).next_multiple_of(word_size)
+        );
+    }
+}
+
+#[test]
+fn built_file_round_trips() {
+    for (class, machine) in [
+        (Class::ELF32, Machine::X86),
+        (Class::ELF64, Machine::X86_64),
+    ] {
+        let elf = build(class, machine);
+        assert_eq!(
+            ELFFile::from_bytes_fully(&elf.to_bytes().unwrap()).unwrap(),
+            elf
+        );
+    }
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::{Cursor, ErrorKind};
+
+use common::load_fixture;
+use elfsmith::{
+    Class, DynamicEntry, DynamicTag, ELFFile, PackableClass, RelaEntry, UnpackableClass,
+};
+
+/// Packs `value` for `class`
+fn pack<T: PackableClass>(value: T, class: Class) -> std::io::Result<Vec<u8>> {
+    let mut packed = Cursor::new(Vec::new());
+    value.pack_class(&mut packed, false, class)?;
+
+    Ok(packed.into_inner())
+}
+
+#[test]
+fn words_of_32_bit_files() {
+    assert_eq!(pack(u32::MAX as u64, Class::ELF32).unwrap(), [0xff; 4]);
+    assert_eq!(pack(u32::MAX as u64 + 1, Class::ELF64).unwrap().len(), 8);
+
+    let error = pack(u32::MAX as u64 + 1, Class::ELF32).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn oversized_section_of_32_bit_file() {
+    let mut elf = load_fixture("small32.o");
+    let index = elf.section_index_by_name(".data").unwrap();
+
+    elf.section_headers[index].size = 0x1_0000_0010;
+    assert_eq!(elf.to_bytes().unwrap_err().kind(), ErrorKind::InvalidInput);
+
+    elf.section_headers[index].size = 4;
+    elf.section_headers[index].addr_align = 1 << 32;
+    assert_eq!(elf.to_bytes().unwrap_err().kind(), ErrorKind::InvalidInput);
+
+    elf.section_headers[index].addr_align = 4;
+    assert_eq!(
+        ELFFile::from_bytes_fully(&elf.to_bytes().unwrap()).unwrap(),
+        load_fixture("small32.o")
+    );
+}
+
+#[test]
+fn oversized_dynamic_value() {
+    let entry = DynamicEntry {
+        tag: DynamicTag::StrSize,
+        value: 1 << 32,
+    };
+
+    assert_eq!(
+        pack(entry, Class::ELF32).unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+    assert!(pack(entry, Class::ELF64).is_ok());
+}
+
+#[test]
+fn negative_addend_of_32_bit_file() {
+    let entry = RelaEntry {
+        offset: 0x10,
+        info: 0x102,
+        addend: -4,
+    };
+
+    let packed = pack(entry, Class::ELF32).unwrap();
+    assert_eq!(packed[8..], (-4i32).to_le_bytes());
+    assert_eq!(
+        RelaEntry::unpack_class(&mut Cursor::new(packed), false, Class::ELF32).unwrap(),
+        entry
+    );
+
+    for addend in [i32::MIN as i64 - 1, i32::MAX as i64 + 1] {
+        let entry = RelaEntry { addend, ..entry };
+        assert_eq!(
+            pack(entry, Class::ELF32).unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! Helpers shared by the integration tests
+#![allow(dead_code)]
+
+use std::{fs, io::Cursor, path::PathBuf};
+
+use elfsmith::{ELFFile, Packable, Unpackable};
+
+/// Returns the path of the fixture `name`, see `tests/fixtures/build.sh`
+pub fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+/// Returns the contents of the fixture `name`
+pub fn fixture_bytes(name: &str) -> Vec<u8> {
+    fs::read(fixture_path(name)).unwrap()
+}
+
+/// Loads the fixture `name` including the data of all sections and segments
+pub fn load_fixture(name: &str) -> ELFFile {
+    ELFFile::from_bytes_fully(&fixture_bytes(name)).unwrap()
+}
+
+/// Packs `value` and unpacks it again
+/// # Returns
+/// The packed bytes and the unpacked value
+pub fn round_trip<T: Packable + Unpackable>(value: &T, big_endian: bool) -> (Vec<u8>, T) {
+    let mut packed = Cursor::new(Vec::new());
+    value.pack(&mut packed, big_endian).unwrap();
+
+    packed.set_position(0);
+    let unpacked = T::unpack(&mut packed, big_endian).unwrap();
+
+    (packed.into_inner(), unpacked)
+}
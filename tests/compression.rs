@@ -0,0 +1,159 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{CompressionType, DecompressError, ELFFile};
+
+/// Returns the uncompressed `.debug_info` of `debug.o`
+fn debug_info() -> Vec<u8> {
+    let elf = load_fixture("debug.o");
+    let index = elf.section_index_by_name(".debug_info").unwrap();
+
+    elf.decompressed_section_data(index).unwrap()
+}
+
+/// Returns the index of the section `name` in `elf`
+fn section(elf: &ELFFile, name: &str) -> usize {
+    elf.section_index_by_name(name).unwrap()
+}
+
+#[test]
+fn uncompressed_section_is_unchanged() {
+    let elf = load_fixture("debug.o");
+    let index = section(&elf, ".debug_info");
+
+    assert!(!elf.section_headers[index].flags.is_compressed());
+    assert_eq!(debug_info(), elf.section_headers[index].data.to_vec());
+}
+
+#[test]
+fn zlib_compression_header() {
+    let elf = load_fixture("debug_zlib.o");
+    let section = &elf.section_headers[section(&elf, ".debug_info")];
+    let header = section
+        .compression_header(elf.header.ident.class, false)
+        .unwrap()
+        .unwrap();
+
+    assert!(section.flags.is_compressed());
+    assert_eq!(header.ty, CompressionType::Zlib);
+    assert_eq!(header.size, debug_info().len() as u64);
+    assert_eq!(header.addr_align, 1);
+    assert_eq!(
+        section.decompressed_size(elf.header.ident.class, false),
+        Some(header.size)
+    );
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn zlib_section() {
+    let elf = load_fixture("debug_zlib.o");
+    let index = section(&elf, ".debug_info");
+
+    assert_eq!(elf.decompressed_section_data(index).unwrap(), debug_info());
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn zdebug_section() {
+    let elf = load_fixture("debug_zdebug.o");
+    let index = section(&elf, ".zdebug_info");
+
+    assert!(elf.section_headers[index].data.starts_with(b"ZLIB"));
+    assert_eq!(elf.decompressed_section_data(index).unwrap(), debug_info());
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn zlib_size_mismatch() {
+    let mut elf = load_fixture("debug_zlib.o");
+    let index = section(&elf, ".debug_info");
+
+    // Record one byte less than the stream contains in `ch_size`
+    let size = debug_info().len() as u64 - 1;
+    elf.section_headers[index].data.blob[8..16].copy_from_slice(&size.to_le_bytes());
+
+    assert!(matches!(
+        elf.decompressed_section_data(index),
+        Err(DecompressError::SizeMismatch { expected, got }) if expected == size && got == size + 1
+    ));
+}
+
+#[cfg(not(feature = "flate2"))]
+#[test]
+fn zlib_without_feature() {
+    for (file, name) in [
+        ("debug_zlib.o", ".debug_info"),
+        ("debug_zdebug.o", ".zdebug_info"),
+    ] {
+        let elf = load_fixture(file);
+        assert!(matches!(
+            elf.decompressed_section_data(section(&elf, name)),
+            Err(DecompressError::MissingFeature { feature: "flate2" })
+        ));
+    }
+}
+
+#[test]
+fn unsupported_compression() {
+    let mut elf = load_fixture("debug_zlib.o");
+    let index = section(&elf, ".debug_info");
+    elf.section_headers[index].data.blob[0..4].copy_from_slice(&7u32.to_le_bytes());
+
+    assert!(matches!(
+        elf.decompressed_section_data(index),
+        Err(DecompressError::UnsupportedCompression { ty: 7 })
+    ));
+}
+
+#[test]
+fn zstd_compression_header() {
+    let elf = load_fixture("debug_zstd.o");
+    let header = elf.section_headers[section(&elf, ".debug_info")]
+        .compression_header(elf.header.ident.class, false)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(header.ty, CompressionType::Zstd);
+    assert_eq!(header.size, debug_info().len() as u64);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd_section() {
+    let elf = load_fixture("debug_zstd.o");
+
+    for name in [".debug_info", ".debug_abbrev", ".debug_line"] {
+        let index = section(&elf, name);
+        let expected = load_fixture("debug.o").section_headers[index].data.to_vec();
+
+        assert_eq!(elf.decompressed_section_data(index).unwrap(), expected);
+    }
+}
+
+#[cfg(not(feature = "zstd"))]
+#[test]
+fn zstd_without_feature() {
+    let elf = load_fixture("debug_zstd.o");
+
+    assert!(matches!(
+        elf.decompressed_section_data(section(&elf, ".debug_info")),
+        Err(DecompressError::MissingFeature { feature: "zstd" })
+    ));
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_decompressed_data() {
+    let elf = load_fixture("debug.o");
+    let section = &elf.section_headers[section(&elf, ".debug_info")];
+
+    assert_eq!(
+        section
+            .decompressed_data(elf.header.ident.class, false)
+            .unwrap(),
+        debug_info()
+    );
+}
@@ -0,0 +1,209 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{AuxvEntry, AuxvType, Class, CoreInfo, Machine, MappedFile, MappedFiles, PrStatus};
+
+/// Returns the core information of `crash.core`
+fn core_info() -> CoreInfo {
+    load_fixture("crash.core").core_info().unwrap().unwrap()
+}
+
+#[test]
+fn faulting_thread() {
+    let info = core_info();
+
+    assert_eq!(info.threads.len(), 1);
+    let thread = &info.threads[0];
+
+    // SIGSEGV
+    assert_eq!(thread.signal, 11);
+    assert_eq!(thread.current_signal, 11);
+    assert_eq!(thread.pid, 20072);
+    assert_eq!(thread.ppid, 20065);
+    assert_eq!(thread.machine, Machine::X86_64);
+    assert_eq!(thread.registers.len(), 27 * 8);
+    assert!(thread.fp_valid);
+}
+
+#[test]
+fn process_info() {
+    let process = core_info().process.unwrap();
+
+    assert_eq!(process.pid, 20072);
+    assert_eq!(process.ppid, 20065);
+    assert_eq!(process.pgrp, 20065);
+    assert_eq!(process.state_name, 'R');
+    assert!(!process.zombie);
+    assert_eq!(process.uid, 0);
+    assert_eq!(process.filename, "crash");
+    assert_eq!(process.args, "./crash");
+}
+
+#[test]
+fn not_a_core_dump() {
+    assert_eq!(load_fixture("crash").core_info().unwrap(), None);
+}
+
+#[test]
+fn prstatus_32() {
+    // An i386 `struct elf_prstatus`: The registers start at 72 and `pr_fpvalid` follows at 140
+    let mut desc = vec![0u8; 144];
+    desc[0..4].copy_from_slice(&11i32.to_le_bytes());
+    desc[12..14].copy_from_slice(&11u16.to_le_bytes());
+    desc[24..28].copy_from_slice(&1234i32.to_le_bytes());
+    desc[28..32].copy_from_slice(&1000i32.to_le_bytes());
+    desc[72..76].copy_from_slice(&0xdeadbeefu32.to_le_bytes());
+    desc[140..144].copy_from_slice(&1i32.to_le_bytes());
+
+    let status = PrStatus::from_desc(&desc, false, Class::ELF32, Machine::X86).unwrap();
+
+    assert_eq!(status.signal, 11);
+    assert_eq!(status.current_signal, 11);
+    assert_eq!(status.pid, 1234);
+    assert_eq!(status.ppid, 1000);
+    assert_eq!(status.registers.len(), 17 * 4);
+    assert_eq!(status.registers[..4], 0xdeadbeefu32.to_le_bytes());
+    assert!(status.fp_valid);
+    assert_eq!(status.registers_x86_64(), None);
+}
+
+#[test]
+fn truncated_prstatus() {
+    assert!(PrStatus::from_desc(&[0; 20], false, Class::ELF64, Machine::X86_64).is_err());
+}
+
+#[test]
+fn executable_mapping() {
+    let files = core_info().mapped_files.unwrap();
+
+    assert_eq!(files.page_size, 0x1000);
+    assert_eq!(files.files.len(), 2);
+
+    let text = &files.files[1];
+    assert!(
+        text.path.ends_with("/tests/fixtures/crash"),
+        "{}",
+        text.path
+    );
+    assert_eq!(text.start, 0x401000);
+    assert_eq!(text.end, 0x402000);
+    assert_eq!(text.page_offset, 1);
+}
+
+#[test]
+fn truncated_mapped_files() {
+    // Two mappings, cut off within the path of the second one
+    let mut desc = Vec::new();
+    for word in [2u32, 0x1000, 0x8000, 0x9000, 0, 0x9000, 0xa000, 1] {
+        desc.extend_from_slice(&word.to_le_bytes());
+    }
+    desc.extend_from_slice(b"/bin/a\0/bin/b");
+
+    let files = MappedFiles::from_desc(&desc, false, Class::ELF32).unwrap();
+
+    assert_eq!(files.page_size, 0x1000);
+    assert_eq!(
+        files.files,
+        [MappedFile {
+            start: 0x8000,
+            end: 0x9000,
+            page_offset: 0,
+            path: "/bin/a".into()
+        }]
+    );
+
+    // Cut off within the triplets
+    let files = MappedFiles::from_desc(&desc[..28], false, Class::ELF32).unwrap();
+    assert!(files.files.is_empty());
+}
+
+#[test]
+fn auxiliary_vector() {
+    let info = core_info();
+    let elf = load_fixture("crash");
+
+    assert_eq!(
+        info.auxv_value(AuxvType::Entry),
+        Some(elf.header.entry_point)
+    );
+    assert_eq!(info.auxv_value(AuxvType::PageSize), Some(0x1000));
+    assert_eq!(info.auxv_value(AuxvType::Phdr), Some(0x400040));
+    assert_eq!(info.auxv_value(AuxvType::Base), Some(0));
+    assert_eq!(info.auxv_value(AuxvType::Other(0x1000)), None);
+
+    // `AT_SYSINFO_EHDR` is not one of the named types
+    assert_eq!(info.auxv()[0].ty, AuxvType::Other(33));
+    assert!(info.auxv().iter().all(|e| e.ty != AuxvType::Null));
+}
+
+#[test]
+fn auxiliary_vector_32() {
+    let mut desc = Vec::new();
+    for word in [9u32, 0x8048000, 6, 0x1000, 0, 0, 9, 0] {
+        desc.extend_from_slice(&word.to_be_bytes());
+    }
+
+    assert_eq!(
+        AuxvEntry::parse_vector(&desc, true, Class::ELF32).unwrap(),
+        [
+            AuxvEntry {
+                ty: AuxvType::Entry,
+                value: 0x8048000
+            },
+            AuxvEntry {
+                ty: AuxvType::PageSize,
+                value: 0x1000
+            }
+        ]
+    );
+}
+
+#[test]
+fn faulting_registers() {
+    let info = core_info();
+    let registers = info.threads[0].registers_x86_64().unwrap();
+
+    // The fault happens at the store after the two byte `xor` at the entry point
+    assert_eq!(registers.rip, 0x401002);
+    assert_eq!(registers.rax, 0);
+    assert_eq!(registers.rsp, 0x7fffffffe1e0);
+    assert_eq!(registers.cs, 0x33);
+    assert_eq!(registers.ss, 0x2b);
+    assert_eq!(registers.orig_rax, u64::MAX);
+
+    // The stack pointer is within the stack the kernel set up
+    let stack = info.auxv_value(AuxvType::Random).unwrap();
+    assert!(registers.rsp < stack);
+}
+
+#[test]
+fn registers_of_other_machines() {
+    let mut thread = core_info().threads.remove(0);
+    thread.machine = Machine::AArch64;
+    assert_eq!(thread.registers_x86_64(), None);
+
+    // The raw registers stay available, a too small area has no typed view
+    thread.machine = Machine::X86_64;
+    thread.registers.truncate(26 * 8);
+    assert_eq!(thread.registers_x86_64(), None);
+}
+
+#[test]
+fn prstatus_64_offsets() {
+    // An x86_64 `struct elf_prstatus`: The registers start at 112 and `pr_fpvalid` follows at 328
+    let mut desc = vec![0u8; 336];
+    desc[32..36].copy_from_slice(&42i32.to_le_bytes());
+    desc[112..120].copy_from_slice(&0x15u64.to_le_bytes());
+    desc[112 + 16 * 8..112 + 17 * 8].copy_from_slice(&0x401000u64.to_le_bytes());
+    desc[328..332].copy_from_slice(&1i32.to_le_bytes());
+
+    let status = PrStatus::from_desc(&desc, false, Class::ELF64, Machine::X86_64).unwrap();
+    let registers = status.registers_x86_64().unwrap();
+
+    assert_eq!(status.pid, 42);
+    assert_eq!(registers.r15, 0x15);
+    assert_eq!(registers.rip, 0x401000);
+    assert!(status.fp_valid);
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::{fixture_bytes, load_fixture};
+use elfsmith::{debuglink_crc32, DebugLink};
+
+#[test]
+fn debuglink_of_stripped_file() {
+    let elf = load_fixture("hello_debuglink");
+    let link = elf.debuglink().unwrap();
+
+    assert_eq!(link.filename, "hello.debug");
+    assert_eq!(link.crc32, 0x2844b057);
+
+    // The checksum verifies the debug file the link refers to
+    assert_eq!(debuglink_crc32(&fixture_bytes(&link.filename)), link.crc32);
+    assert_ne!(debuglink_crc32(&fixture_bytes("hello")), link.crc32);
+}
+
+#[test]
+fn no_debuglink() {
+    assert_eq!(load_fixture("hello").debuglink(), None);
+}
+
+#[test]
+fn crc32() {
+    assert_eq!(debuglink_crc32(b""), 0);
+    assert_eq!(debuglink_crc32(b"123456789"), 0xcbf43926);
+}
+
+#[test]
+fn padded_name() {
+    let data = b"a.dbg\0\0\0\x12\x34\x56\x78";
+
+    assert_eq!(
+        DebugLink::parse(data, true),
+        Some(DebugLink {
+            filename: "a.dbg".into(),
+            crc32: 0x12345678
+        })
+    );
+    assert_eq!(DebugLink::parse(data, false).unwrap().crc32, 0x78563412);
+}
+
+#[test]
+fn truncated_debuglink() {
+    assert_eq!(DebugLink::parse(b"a.dbg\0\0\0\x12\x34\x56", false), None);
+    assert_eq!(DebugLink::parse(b"a.dbg", false), None);
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{HeaderField, SectionFlags, SectionType};
+
+#[test]
+fn identical_files() {
+    let old = load_fixture("hello");
+    let new = load_fixture("hello");
+    let diff = old.diff(&new);
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "No differences\n");
+}
+
+#[test]
+fn injected_section() {
+    let old = load_fixture("hello.o");
+    let mut new = old.clone();
+    let index = new
+        .inject_section(
+            ".elfsmith",
+            SectionType::ProgramData,
+            SectionFlags::default(),
+            b"injected".to_vec(),
+            8,
+        )
+        .unwrap();
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.added_sections, [index]);
+    assert!(diff.removed_sections.is_empty());
+    assert_eq!(
+        diff.header_changes,
+        [
+            HeaderField::SectionHeaderOffset,
+            HeaderField::SectionHeaderCount
+        ]
+    );
+
+    // The name is appended to the section names
+    let names = old.header.sh_str_index as usize;
+    assert_eq!(diff.modified_sections, [names]);
+    assert_eq!(
+        diff.to_string(),
+        format!(
+            "Header:\n  ~ Start of section headers\n  ~ Number of section headers\n\
+             Sections:\n  + [{:>2}] .elfsmith\n  ~ [{:>2}] .shstrtab\n",
+            index, names
+        )
+    );
+}
+
+#[test]
+fn patched_section() {
+    let old = load_fixture("hello");
+    let mut new = old.clone();
+    let index = new.section_index_by_name(".comment").unwrap();
+    let size = new.section_headers[index].data.len();
+    new.patch_section_data(index, vec![0; size]).unwrap();
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.modified_sections, [index]);
+    assert!(diff.added_sections.is_empty() && diff.removed_sections.is_empty());
+    assert!(diff.header_changes.is_empty());
+    assert!(diff.modified_programs.is_empty());
+    assert_eq!(
+        diff.to_string(),
+        format!("Sections:\n  ~ [{:>2}] .comment\n", index)
+    );
+}
@@ -0,0 +1,385 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::{io::Cursor, path::PathBuf};
+
+use common::load_fixture;
+use elfsmith::{
+    Class, DynamicEntry, DynamicFlags, DynamicFlags1, DynamicSection, DynamicTag, ELFFile, Machine,
+    MipsDynamicTag, PackableClass, ProgramHeaderType, StringTable, UnpackError,
+};
+
+/// Parses the `.dynamic` section of `elf`
+fn dynamic_section(elf: &ELFFile) -> DynamicSection {
+    let section = elf.find_section(".dynamic").unwrap();
+
+    DynamicSection::from_blob(
+        &section.data,
+        elf.header.ident.class,
+        elf.header.ident.is_big_endian(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn needed_entries_in_order() {
+    let elf = load_fixture("hello_now");
+    let dynamic = dynamic_section(&elf);
+    let strings = StringTable::from(&elf.find_section(".dynstr").unwrap().data);
+
+    let needed: Vec<_> = dynamic
+        .entries
+        .iter()
+        .filter(|e| e.tag == DynamicTag::Needed)
+        .map(|e| strings.get(e.value as u32).unwrap())
+        .collect();
+    assert_eq!(needed, ["libm.so.6", "libc.so.6"]);
+
+    assert_eq!(elf.needed_libraries().unwrap(), ["libm.so.6", "libc.so.6"]);
+    assert_eq!(
+        load_fixture("hello").needed_libraries().unwrap(),
+        ["libc.so.6"]
+    );
+}
+
+#[test]
+fn entries_stop_at_null() {
+    let elf = load_fixture("hello_now");
+    let dynamic = dynamic_section(&elf);
+
+    // readelf reports 28 entries including the terminating DT_NULL
+    assert_eq!(dynamic.entries.len(), 27);
+    assert!(dynamic.entries.iter().all(|e| e.tag != DynamicTag::Null));
+    assert_eq!(elf.iter_dynamic().unwrap().count(), 27);
+}
+
+#[test]
+fn find_entries_by_tag() {
+    let elf = load_fixture("hello_now");
+    let dynamic = dynamic_section(&elf);
+
+    let strtab = dynamic.find(DynamicTag::StrTab).unwrap();
+    assert_eq!(strtab.value, elf.find_section(".dynstr").unwrap().address);
+    assert_eq!(
+        dynamic.find(DynamicTag::SymTab).unwrap().value,
+        elf.find_section(".dynsym").unwrap().address
+    );
+    assert_eq!(dynamic.find(DynamicTag::PltRelSize).unwrap().value, 48);
+    assert_eq!(dynamic.find(DynamicTag::SoName), None);
+
+    assert_eq!(elf.find_dynamic(DynamicTag::Init).unwrap(), Some(0x1000));
+    assert_eq!(
+        elf.find_dynamic(DynamicTag::Other(0x6ffffff9)).unwrap(),
+        Some(3)
+    );
+}
+
+#[test]
+fn entries_repack_identically() {
+    let elf = load_fixture("hello_now");
+    let section = elf.find_section(".dynamic").unwrap();
+    let class = elf.header.ident.class;
+
+    let mut packed = Cursor::new(Vec::new());
+    for entry in &dynamic_section(&elf).entries {
+        entry.pack_class(&mut packed, false, class).unwrap();
+    }
+
+    let size = packed.get_ref().len();
+    assert_eq!(size as u64, 27 * DynamicEntry::get_size(class));
+    assert_eq!(packed.get_ref()[..], section.data.blob[..size]);
+}
+
+#[test]
+fn flags_of_binary_linked_with_now() {
+    let elf = load_fixture("hello_now");
+
+    let flags = elf.dynamic_flags().unwrap();
+    assert_eq!(flags, DynamicFlags::BIND_NOW);
+    assert_eq!(flags.to_string(), "BIND_NOW");
+
+    let flags_1 = elf.dynamic_flags_1().unwrap();
+    assert!(flags_1.contains(DynamicFlags1::NOW));
+    assert!(flags_1.contains(DynamicFlags1::PIE));
+    assert_eq!(flags_1.to_string(), "NOW PIE");
+
+    assert!(elf.bind_now().unwrap());
+    assert!(elf.is_pie_flagged().unwrap());
+}
+
+#[test]
+fn flags_of_lazily_bound_binary() {
+    let elf = load_fixture("hello");
+
+    assert_eq!(elf.dynamic_flags().unwrap(), DynamicFlags::default());
+    assert!(!elf.dynamic_flags_1().unwrap().bind_now());
+    assert!(!elf.bind_now().unwrap());
+    assert!(elf.is_pie_flagged().unwrap());
+}
+
+#[test]
+fn unknown_flags_are_kept() {
+    let flags = DynamicFlags(DynamicFlags::ORIGIN.0 | DynamicFlags::TEXTREL.0 | 0x100);
+    assert_eq!(flags.to_string(), "ORIGIN TEXTREL 0x100");
+    assert_eq!(flags.0, 0x105);
+
+    let flags_1 = DynamicFlags1(DynamicFlags1::NODELETE.0 | 0x8000_0000);
+    assert_eq!(flags_1.to_string(), "NODELETE 0x80000000");
+    assert!(!flags_1.is_pie_flagged());
+
+    let entry = DynamicEntry {
+        tag: DynamicTag::Flags1,
+        value: flags_1.0,
+    };
+    let mut packed = Cursor::new(Vec::new());
+    entry.pack_class(&mut packed, true, Class::ELF32).unwrap();
+    assert_eq!(packed.get_ref()[4..], 0x8000_0008u32.to_be_bytes());
+}
+
+#[test]
+fn mips_tags_of_mipsel_object() {
+    let elf = load_fixture("mipsel.so");
+    assert_eq!(elf.header.ident.class, Class::ELF32);
+    assert!(!elf.header.ident.is_big_endian());
+    assert_eq!(elf.header.machine, Machine::Mips);
+
+    let entries: Vec<DynamicEntry> = elf
+        .iter_dynamic()
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let tags: Vec<_> = entries
+        .iter()
+        .filter_map(|e| match e.tag {
+            DynamicTag::Mips(tag) => Some((tag, e.value)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        tags,
+        [
+            (MipsDynamicTag::RldVersion, 1),
+            (MipsDynamicTag::Flags, 2),
+            (MipsDynamicTag::BaseAddress, 0),
+            (MipsDynamicTag::LocalGotNo, 4),
+            (MipsDynamicTag::SymTabNo, 1),
+            (MipsDynamicTag::GotSym, 1),
+            (MipsDynamicTag::RldMapRel, 0x1000),
+        ]
+    );
+    assert_eq!(tags[3].0.name(), "MIPS_LOCAL_GOTNO");
+    assert_eq!(tags[6].0.name(), "MIPS_RLD_MAP_REL");
+
+    assert_eq!(
+        elf.find_dynamic(DynamicTag::Mips(MipsDynamicTag::GotSym))
+            .unwrap(),
+        Some(1)
+    );
+    assert_eq!(elf.soname().unwrap().as_deref(), Some("libmips.so"));
+}
+
+#[test]
+fn mips_tags_need_the_mips_machine() {
+    let elf = load_fixture("mipsel.so");
+
+    // Without a machine, the processor specific tags stay unknown
+    let dynamic = dynamic_section(&elf);
+    assert_eq!(dynamic.entries[6].tag, DynamicTag::Other(0x7000000a));
+    assert_eq!(
+        dynamic.entries[6].tag.for_machine(Machine::Mips),
+        DynamicTag::Mips(MipsDynamicTag::LocalGotNo)
+    );
+    assert_eq!(
+        dynamic.entries[6].tag.for_machine(Machine::X86_64),
+        DynamicTag::Other(0x7000000a)
+    );
+    assert_eq!(
+        DynamicTag::Other(0x7000ffff).for_machine(Machine::Mips),
+        DynamicTag::Other(0x7000ffff)
+    );
+    assert_eq!(
+        DynamicTag::Mips(MipsDynamicTag::LocalGotNo).value(),
+        0x7000000a
+    );
+}
+
+#[test]
+fn needed_libraries_of_executables_and_libraries() {
+    assert_eq!(
+        load_fixture("hello").needed_libraries().unwrap(),
+        ["libc.so.6"]
+    );
+    assert_eq!(
+        load_fixture("libgnu.so").needed_libraries().unwrap(),
+        ["libc.so.6"]
+    );
+
+    // Resolved through DT_STRTAB, which works without section headers
+    assert_eq!(
+        load_fixture("hello_sstripped").needed_libraries().unwrap(),
+        ["libc.so.6"]
+    );
+}
+
+#[test]
+fn needed_libraries_of_static_binary() {
+    let elf = load_fixture("static");
+
+    assert!(elf.iter_dynamic().is_none());
+    assert_eq!(elf.needed_libraries().unwrap(), Vec::<String>::new());
+}
+
+/// Replaces the first dynamic entry with `tag` in the `PT_DYNAMIC` segment of `elf`
+fn replace_dynamic_entry(elf: &mut ELFFile, tag: DynamicTag, entry: DynamicEntry) {
+    let segment = elf
+        .program_headers
+        .iter_mut()
+        .find(|p| p.ty == ProgramHeaderType::Dynamic)
+        .unwrap();
+
+    let index = segment
+        .iter_dynamic(Class::ELF64, false)
+        .position(|e| e.unwrap().tag == tag)
+        .unwrap();
+
+    let mut packed = Cursor::new(Vec::new());
+    entry.pack_class(&mut packed, false, Class::ELF64).unwrap();
+    segment.data.blob[index * 16..index * 16 + 16].copy_from_slice(packed.get_ref());
+}
+
+#[test]
+fn soname_of_shared_objects() {
+    assert_eq!(
+        load_fixture("libgnu.so").soname().unwrap().as_deref(),
+        Some("libgnu.so.1")
+    );
+    assert_eq!(
+        load_fixture("mipsel.so").soname().unwrap().as_deref(),
+        Some("libmips.so")
+    );
+
+    // A shared object without a dynamic table
+    assert_eq!(load_fixture("aarch64.so").soname().unwrap(), None);
+}
+
+#[test]
+fn soname_of_executables() {
+    assert_eq!(load_fixture("hello").soname().unwrap(), None);
+    assert_eq!(load_fixture("static").soname().unwrap(), None);
+    assert_eq!(load_fixture("hello.o").soname().unwrap(), None);
+}
+
+#[test]
+fn soname_with_corrupt_string_offset() {
+    let mut elf = load_fixture("libgnu.so");
+    replace_dynamic_entry(
+        &mut elf,
+        DynamicTag::SoName,
+        DynamicEntry {
+            tag: DynamicTag::SoName,
+            value: 0x10000,
+        },
+    );
+
+    assert!(matches!(
+        elf.soname(),
+        Err(UnpackError::InvalidString { offset: 0x10000 })
+    ));
+}
+
+#[test]
+fn runpath_entries() {
+    let elf = load_fixture("hello_runpath");
+
+    assert_eq!(
+        elf.runpath().unwrap().as_deref(),
+        Some("/opt/foo:/opt/bar:$ORIGIN/lib")
+    );
+    assert_eq!(elf.rpath().unwrap(), None);
+    assert_eq!(
+        elf.runpath_entries().unwrap(),
+        [
+            PathBuf::from("/opt/foo"),
+            PathBuf::from("/opt/bar"),
+            PathBuf::from("$ORIGIN/lib")
+        ]
+    );
+    assert!(elf.rpath_entries().unwrap().is_empty());
+    assert_eq!(
+        elf.effective_library_search_path().unwrap(),
+        elf.runpath_entries().unwrap()
+    );
+}
+
+#[test]
+fn rpath_entries() {
+    let elf = load_fixture("hello_rpath");
+
+    assert_eq!(elf.runpath().unwrap(), None);
+    assert_eq!(
+        elf.rpath().unwrap().as_deref(),
+        Some("/opt/foo:/opt/bar:$ORIGIN/lib")
+    );
+    assert_eq!(elf.rpath_entries().unwrap().len(), 3);
+    assert_eq!(
+        elf.effective_library_search_path().unwrap(),
+        elf.rpath_entries().unwrap()
+    );
+
+    let hello = load_fixture("hello");
+    assert!(hello.effective_library_search_path().unwrap().is_empty());
+}
+
+#[test]
+fn runpath_takes_precedence() {
+    let mut elf = load_fixture("hello_runpath");
+    let runpath = elf.find_dynamic(DynamicTag::RunPath).unwrap().unwrap();
+
+    // Turn DT_DEBUG into a DT_RPATH pointing at `/opt/bar:$ORIGIN/lib`
+    replace_dynamic_entry(
+        &mut elf,
+        DynamicTag::Other(0x15),
+        DynamicEntry {
+            tag: DynamicTag::RPath,
+            value: runpath + 9,
+        },
+    );
+    assert_eq!(
+        elf.rpath().unwrap().as_deref(),
+        Some("/opt/bar:$ORIGIN/lib")
+    );
+
+    assert_eq!(elf.effective_library_search_path().unwrap().len(), 3);
+    assert_eq!(
+        elf.effective_library_search_path().unwrap()[0],
+        PathBuf::from("/opt/foo")
+    );
+}
+
+#[test]
+fn origin_expansion() {
+    let elf = load_fixture("hello_runpath");
+
+    let mut tokens = Vec::new();
+    let path = elf
+        .effective_library_search_path_with(|token| {
+            tokens.push(token.to_owned());
+            (token == "ORIGIN").then(|| "/usr/local/bin".to_owned())
+        })
+        .unwrap();
+
+    assert_eq!(tokens, ["ORIGIN"]);
+    assert_eq!(
+        path,
+        [
+            PathBuf::from("/opt/foo"),
+            PathBuf::from("/opt/bar"),
+            PathBuf::from("/usr/local/bin/lib")
+        ]
+    );
+
+    // Unknown tokens are kept
+    let path = elf.effective_library_search_path_with(|_| None).unwrap();
+    assert_eq!(path[2], PathBuf::from("$ORIGIN/lib"));
+}
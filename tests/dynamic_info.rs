@@ -0,0 +1,70 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{Class, DynamicInfo, RelocationTable};
+
+#[test]
+fn sstripped_file() {
+    let elf = load_fixture("hello_sstripped");
+    assert!(elf.section_headers.is_empty());
+    assert_eq!(elf.header.sh_offset, 0);
+
+    let info = elf.dynamic_info().unwrap().unwrap();
+
+    assert_eq!(info.needed, ["libc.so.6"]);
+    assert_eq!(info.soname, None);
+
+    let names: Vec<&str> = info
+        .symbols
+        .iter()
+        .map(|s| s.name.as_deref().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "",
+            "__libc_start_main",
+            "_ITM_deregisterTMCloneTable",
+            "printf",
+            "__gmon_start__",
+            "_ITM_registerTMCloneTable",
+            "__cxa_finalize"
+        ]
+    );
+
+    assert_eq!(info.relocations.as_ref().map(|r| r.len()), Some(8));
+    let Some(RelocationTable::Rela(plt)) = &info.plt_relocations else {
+        panic!("expected RELA PLT relocations");
+    };
+    assert_eq!(plt.len(), 2);
+    assert!(plt
+        .iter()
+        .any(|r| r.offset == 0x4000 && r.symbol_index(Class::ELF64) == 3));
+}
+
+#[test]
+fn matches_section_view() {
+    let stripped = load_fixture("hello_sstripped").dynamic_info().unwrap();
+
+    assert_eq!(stripped, load_fixture("hello").dynamic_info().unwrap());
+}
+
+#[test]
+fn shared_object_without_sections() {
+    let mut elf = load_fixture("libgnu.so");
+    elf.section_headers.clear();
+
+    let info = DynamicInfo::from_segments(&elf).unwrap().unwrap();
+
+    // Sized through the GNU hash table
+    assert_eq!(info.symbols.len(), 11);
+    assert_eq!(info.soname.as_deref(), Some("libgnu.so.1"));
+    assert_eq!(info.symbols[6].name.as_deref(), Some("lib_add"));
+}
+
+#[test]
+fn no_dynamic_table() {
+    assert_eq!(load_fixture("static").dynamic_info().unwrap(), None);
+}
@@ -0,0 +1,340 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{fixture_bytes, load_fixture};
+use elfsmith::{
+    Blob, Class, ELFFile, ProgramHeader, ProgramHeaderType, SectionFlags, SectionHeader,
+    SectionIndex, SectionType, SegmentFlags, SymbolSection, SymbolTable, UnpackError,
+};
+
+/// The index of the section `main` is moved to, beyond `SHN_LORESERVE`
+const HIGH_INDEX: usize = 0xff10;
+
+/// Returns a section header of type `ty` at `offset` holding `data`, named at `name` in the section names
+fn section(ty: SectionType, name: usize, offset: u64, data: Vec<u8>) -> SectionHeader {
+    SectionHeader {
+        name: name as u32,
+        ty,
+        flags: SectionFlags::default(),
+        address: 0,
+        offset,
+        size: data.len() as u64,
+        link: 0,
+        info: 0,
+        addr_align: 1,
+        entry_size: 0,
+        data: Blob::from(data),
+    }
+}
+
+/// Builds an object with more than `SHN_LORESERVE` sections from `hello.o`
+///
+/// Empty sections are inserted up to [HIGH_INDEX], where `.high` is placed and
+/// `main` is moved to it through a new `SHT_SYMTAB_SHNDX` section. The section
+/// names are moved to the end, so their index needs `SHN_XINDEX` as well
+fn many_sections() -> ELFFile {
+    let mut elf = load_fixture("hello.o");
+    let mut end = fixture_bytes("hello.o").len() as u64;
+
+    let symtab = elf.section_index_by_name(".symtab").unwrap();
+    let mut table = elf
+        .read_symbol_table(&mut Cursor::new(fixture_bytes("hello.o")), symtab)
+        .unwrap();
+    let main = table
+        .symbols
+        .iter()
+        .position(|s| s.name.as_deref() == Some("main"))
+        .unwrap();
+
+    // Move the section names out of the way of the new sections
+    let old_names = elf.header.sh_str_index as usize;
+    let mut names = elf.section_headers[old_names].data.to_vec();
+    elf.section_headers[old_names] = section(SectionType::ProgramData, 0, 0, Vec::new());
+
+    while elf.section_headers.len() < HIGH_INDEX {
+        elf.section_headers
+            .push(section(SectionType::ProgramData, 0, 0, Vec::new()));
+    }
+
+    let mut append = |elf: &mut ELFFile, mut section: SectionHeader| {
+        end = end.next_multiple_of(8);
+        section.offset = end;
+        end += section.size;
+        elf.section_headers.push(section);
+    };
+
+    append(
+        &mut elf,
+        section(SectionType::ProgramData, names.len(), 0, b"high".to_vec()),
+    );
+    names.extend_from_slice(b".high\0");
+
+    table.set_section_index(main, HIGH_INDEX as u32);
+    let (symbols, indices) = table.pack_sections(Class::ELF64, false).unwrap();
+    elf.section_headers[symtab].data = Blob::from(symbols);
+
+    let mut shndx = section(
+        SectionType::ExtendedSectionIndices,
+        names.len(),
+        0,
+        indices.unwrap(),
+    );
+    shndx.link = symtab as u32;
+    shndx.entry_size = 4;
+    append(&mut elf, shndx);
+    names.extend_from_slice(b".symtab_shndx\0");
+
+    let names_index = elf.section_headers.len();
+    let name = names.len();
+    names.extend_from_slice(b".shstrtab\0");
+    append(&mut elf, section(SectionType::StringTable, name, 0, names));
+    elf.header.sh_str_index = SectionIndex::XIndex.value();
+    elf.section_headers[0].link = names_index as u32;
+
+    elf.header.sh_offset = end.next_multiple_of(8);
+    elf
+}
+
+/// Returns the index of `main` in the symbol table of `elf`
+fn main_symbol(elf: &ELFFile, bytes: &[u8]) -> (SymbolTable, usize) {
+    let symtab = elf.section_index_by_name(".symtab").unwrap();
+    let table = elf
+        .read_symbol_table(&mut Cursor::new(bytes), symtab)
+        .unwrap();
+    let main = table
+        .symbols
+        .iter()
+        .position(|s| s.name.as_deref() == Some("main"))
+        .unwrap();
+
+    (table, main)
+}
+
+#[test]
+fn symbol_in_high_section() {
+    let bytes = many_sections().to_bytes().unwrap();
+    let elf = ELFFile::from_bytes_fully(&bytes).unwrap();
+    let (table, main) = main_symbol(&elf, &bytes);
+
+    assert_eq!(table.symbols[main].section(), SectionIndex::XIndex);
+    assert_eq!(table.section_index(main), Some(HIGH_INDEX as u32));
+    assert_eq!(table.extended_indices.len(), table.symbols.len());
+
+    let SymbolSection::Section(section) = elf.section_for_table_symbol(&table, main) else {
+        panic!("main is not resolved to a section");
+    };
+    assert_eq!(section.data.to_vec(), b"high");
+
+    // Without the extended indices, the index cannot be resolved
+    assert_eq!(
+        elf.section_for_symbol(&table.symbols[main]),
+        SymbolSection::Unresolved(SectionIndex::XIndex)
+    );
+}
+
+#[test]
+fn no_extended_indices_needed() {
+    let bytes = fixture_bytes("hello.o");
+    let elf = load_fixture("hello.o");
+    let (mut table, main) = main_symbol(&elf, &bytes);
+
+    assert!(table.extended_indices.is_empty());
+    let symtab = elf.find_section(".symtab").unwrap();
+    assert_eq!(
+        table.pack_sections(Class::ELF64, false).unwrap(),
+        (symtab.data.to_vec(), None)
+    );
+
+    // The highest index not colliding with the reserved indices is stored in the symbol
+    table.set_section_index(main, 0xfeff);
+    assert_eq!(table.symbols[main].section_index, 0xfeff);
+    assert_eq!(table.pack_sections(Class::ELF64, false).unwrap().1, None);
+
+    table.set_section_index(main, 0xff00);
+    assert_eq!(table.symbols[main].section(), SectionIndex::XIndex);
+    let (_, indices) = table.pack_sections(Class::ELF64, false).unwrap();
+    let indices = indices.unwrap();
+    assert_eq!(indices.len(), table.symbols.len() * 4);
+    assert_eq!(indices[main * 4..main * 4 + 4], 0xff00u32.to_le_bytes());
+
+    // Moving the symbol back clears its extended index
+    table.set_section_index(main, 1);
+    assert_eq!(table.section_index(main), Some(1));
+    assert_eq!(table.extended_indices[main], 0);
+}
+
+#[test]
+fn extended_section_numbering() {
+    let elf = many_sections();
+    let count = elf.section_headers.len();
+    let bytes = elf.to_bytes().unwrap();
+
+    // `e_shnum` is 0 and `e_shstrndx` is `SHN_XINDEX`
+    assert_eq!(bytes[0x3c..0x3e], [0, 0]);
+    assert_eq!(bytes[0x3e..0x40], [0xff, 0xff]);
+
+    // The real values are stored in the `sh_size` and `sh_link` of the null section
+    let table = elf.header.sh_offset as usize;
+    assert_eq!(
+        bytes[table + 0x20..table + 0x28],
+        (count as u64).to_le_bytes()
+    );
+    assert_eq!(
+        bytes[table + 0x28..table + 0x2c],
+        (count as u32 - 1).to_le_bytes()
+    );
+
+    let reloaded = ELFFile::from_bytes_fully(&bytes).unwrap();
+    assert_eq!(reloaded.section_headers.len(), count);
+    assert_eq!(reloaded.section_headers[0].size, count as u64);
+    assert_eq!(reloaded.header.sh_str_index, SectionIndex::XIndex.value());
+    assert_eq!(reloaded.section_name(HIGH_INDEX).unwrap(), ".high");
+    assert_eq!(
+        reloaded.section_index_by_name(".text"),
+        elf.section_index_by_name(".text")
+    );
+    assert_eq!(reloaded.to_bytes().unwrap(), bytes);
+}
+
+#[test]
+fn read_extended_section_headers() {
+    let bytes = many_sections().to_bytes().unwrap();
+    let elf = ELFFile::from_bytes(&bytes).unwrap();
+
+    let headers = elf
+        .header
+        .read_section_headers(&mut Cursor::new(&bytes))
+        .unwrap();
+    assert_eq!(headers.len(), HIGH_INDEX + 3);
+    assert_eq!(headers[HIGH_INDEX].size, 4);
+}
+
+#[test]
+fn regular_section_numbering() {
+    let elf = load_fixture("hello.o");
+    let bytes = elf.to_bytes().unwrap();
+
+    // Small counts stay in the header, the null section is untouched
+    assert_eq!(
+        bytes[0x3c..0x3e],
+        (elf.section_headers.len() as u16).to_le_bytes()
+    );
+    assert_eq!(bytes[0x3e..0x40], elf.header.sh_str_index.to_le_bytes());
+    assert_eq!(elf.section_headers[0].size, 0);
+    assert_eq!(elf.section_headers[0].link, 0);
+}
+
+#[test]
+fn truncated_extended_section_headers() {
+    let bytes = many_sections().to_bytes().unwrap();
+
+    assert!(ELFFile::from_bytes(&bytes[..bytes.len() - 64]).is_err());
+}
+
+/// The program header count of [many_segments], above the `PN_XNUM` escape value
+const SEGMENT_COUNT: usize = 0x10010;
+
+/// Builds a core dump with more than `0xfffe` program headers from `crash.core`
+///
+/// Empty `PT_LOAD` segments are appended like the mappings of a huge process.
+/// The program header table moves to the end of the file, followed by a section
+/// header table with only the null section to record the real count in
+fn many_segments() -> ELFFile {
+    let mut elf = load_fixture("crash.core");
+    let end = fixture_bytes("crash.core").len() as u64;
+
+    let mapping = ProgramHeader {
+        ty: ProgramHeaderType::Loadable,
+        flags: SegmentFlags::READ,
+        offset: end,
+        virtual_addr: 0,
+        physical_addr: 0,
+        file_size: 0,
+        mem_size: 0x1000,
+        alignment: 0x1000,
+        data: Blob::default(),
+    };
+    while elf.program_headers.len() < SEGMENT_COUNT {
+        let mut mapping = mapping.clone();
+        mapping.virtual_addr = 0x10000000 + elf.program_headers.len() as u64 * 0x1000;
+        elf.program_headers.push(mapping);
+    }
+
+    elf.header.ph_offset = end;
+    elf.header.sh_offset = end + SEGMENT_COUNT as u64 * 56;
+    elf.section_headers
+        .push(section(SectionType::Null, 0, 0, Vec::new()));
+
+    elf
+}
+
+#[test]
+fn extended_program_header_count() {
+    let bytes = many_segments().to_bytes().unwrap();
+
+    // `e_phnum` is `PN_XNUM` and the real count is the `sh_info` of the null section
+    assert_eq!(bytes[0x38..0x3a], [0xff, 0xff]);
+    let table = usize::try_from(u64::from_le_bytes(bytes[0x28..0x30].try_into().unwrap())).unwrap();
+    assert_eq!(
+        bytes[table + 0x2c..table + 0x30],
+        (SEGMENT_COUNT as u32).to_le_bytes()
+    );
+
+    let elf = ELFFile::from_bytes_fully(&bytes).unwrap();
+    assert_eq!(elf.header.program_header_count(), 0xffff);
+    assert_eq!(elf.program_headers.len(), SEGMENT_COUNT);
+    assert_eq!(
+        elf.program_headers[SEGMENT_COUNT - 1].virtual_addr,
+        0x10000000 + (SEGMENT_COUNT as u64 - 1) * 0x1000
+    );
+
+    // The notes are still found through the first program header
+    let info = elf.core_info().unwrap().unwrap();
+    assert_eq!(info.threads[0].signal, 11);
+}
+
+#[test]
+fn largest_regular_program_header_count() {
+    let mut elf = many_segments();
+    elf.program_headers.truncate(0xfffe);
+    let bytes = elf.to_bytes().unwrap();
+
+    assert_eq!(bytes[0x38..0x3a], 0xfffeu16.to_le_bytes());
+    assert_eq!(
+        ELFFile::from_bytes(&bytes).unwrap().program_headers.len(),
+        0xfffe
+    );
+}
+
+#[test]
+fn program_header_count_without_sections() {
+    let mut elf = many_segments();
+    elf.section_headers.clear();
+
+    // There is no null section to record the count in
+    let error = elf.to_bytes().unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    // A `PN_XNUM` without a section header table cannot be resolved
+    let mut bytes = many_segments().to_bytes().unwrap();
+    bytes[0x28..0x30].fill(0);
+    assert!(matches!(
+        ELFFile::from_bytes(&bytes),
+        Err(UnpackError::InvalidSectionIndex { index: 0 })
+    ));
+}
+
+#[test]
+fn section_header_size_of_core_dump() {
+    // Core dumps come without section headers and a section header size of `0`
+    let bytes = fixture_bytes("crash.core");
+    assert_eq!(bytes[0x3a..0x3c], [0, 0]);
+
+    // Adding the null section for `PN_XNUM` records the size of a section header
+    let bytes = many_segments().to_bytes().unwrap();
+    assert_eq!(bytes[0x3a..0x3c], 64u16.to_le_bytes());
+    assert_eq!(bytes[0x36..0x38], 56u16.to_le_bytes());
+}
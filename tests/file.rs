@@ -0,0 +1,325 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::{fixture_bytes, load_fixture};
+use elfsmith::{Blob, ELFFile, ProgramHeaderType, SectionType, UnpackError};
+
+#[test]
+fn reloaded_file_is_equal() {
+    let elf = load_fixture("hello");
+    let reloaded = ELFFile::from_bytes_fully(&elf.to_bytes().unwrap()).unwrap();
+
+    assert!(elf == reloaded);
+    assert_eq!(elf.header, reloaded.header);
+    assert_eq!(elf.header.ident, reloaded.header.ident);
+    assert_eq!(elf.section_headers, reloaded.section_headers);
+    assert_eq!(elf.program_headers, reloaded.program_headers);
+}
+
+#[test]
+fn changed_file_is_not_equal() {
+    let elf = load_fixture("hello");
+
+    let mut changed = elf.clone();
+    changed.header.entry_point += 1;
+    assert_ne!(elf.header, changed.header);
+    assert!(elf != changed);
+
+    let mut changed = elf.clone();
+    changed.section_headers[1].flags.0 ^= 0x1;
+    assert_ne!(elf.section_headers[1], changed.section_headers[1]);
+    assert!(elf != changed);
+
+    let mut changed = elf.clone();
+    changed.program_headers[0].alignment *= 2;
+    assert_ne!(elf.program_headers[0], changed.program_headers[0]);
+    assert!(elf != changed);
+
+    let mut changed = elf.clone();
+    changed.find_section_mut(".text").unwrap().data.blob[0] ^= 0xff;
+    assert!(elf != changed);
+
+    assert!(elf != load_fixture("hello.o"));
+}
+
+#[test]
+fn blobs_compare_bytes() {
+    let bytes = fixture_bytes("hello");
+
+    assert_eq!(Blob::new(bytes.clone()), Blob::new(bytes.clone()));
+    assert_ne!(Blob::new(bytes[1..].to_vec()), Blob::new(bytes));
+    assert_eq!(Blob::default(), Blob::new(Vec::new()));
+}
+
+#[test]
+fn section_names() {
+    let elf = load_fixture("hello");
+
+    assert_eq!(elf.section_name(0).unwrap(), "");
+    assert_eq!(elf.section_name(6).unwrap(), ".dynsym");
+    assert_eq!(elf.section_name(15).unwrap(), ".text");
+    assert_eq!(elf.section_name(30).unwrap(), ".shstrtab");
+    assert!(matches!(
+        elf.section_name(31),
+        Err(UnpackError::InvalidSectionIndex { index: 31 })
+    ));
+
+    let named = elf.named_sections().unwrap();
+    assert_eq!(named.len(), elf.section_headers.len());
+    assert_eq!(named[6].0, ".dynsym");
+    assert_eq!(named[6].1.ty, SectionType::DynSymbol);
+    assert_eq!(named[15].0, ".text");
+}
+
+#[test]
+fn section_names_of_lazily_loaded_file() {
+    let elf = ELFFile::from_bytes(&fixture_bytes("hello")).unwrap();
+
+    // Only the section name string table is loaded
+    assert!(elf.find_section(".text").unwrap().data.is_empty());
+    assert_eq!(elf.section_name(15).unwrap(), ".text");
+
+    let mut elf = elf;
+    elf.section_headers[30].data = Blob::default();
+    assert!(matches!(
+        elf.section_name(15),
+        Err(UnpackError::SectionNotLoaded { index: 30 })
+    ));
+}
+
+#[test]
+fn output_uses_section_names() {
+    let elf = load_fixture("hello");
+
+    let display = elf.to_string();
+    assert!(display.contains("[15] .text"));
+    assert!(display.contains("[ 6] .dynsym"));
+
+    let debug = format!("{:?}", elf);
+    assert!(debug.contains(".text"));
+    assert!(debug.contains(".dynsym"));
+}
+
+#[test]
+fn find_sections_by_name() {
+    let mut elf = load_fixture("hello");
+
+    assert_eq!(elf.section_index_by_name(".dynamic"), Some(22));
+    assert_eq!(
+        elf.find_section(".comment").unwrap().ty,
+        SectionType::ProgramData
+    );
+    assert_eq!(
+        elf.find_section(".gnu.version").unwrap().ty,
+        SectionType::GnuVersionSymbol
+    );
+    assert_eq!(elf.find_section_index_by_name(".text"), Some(15));
+    assert_eq!(elf.find_section_by_name(".text"), elf.find_section(".text"));
+
+    elf.find_section_mut(".comment").unwrap().addr_align = 8;
+    assert_eq!(elf.section_headers[27].addr_align, 8);
+    elf.find_section_by_name_mut(".comment").unwrap().addr_align = 1;
+    assert_eq!(elf.section_headers[27].addr_align, 1);
+}
+
+#[test]
+fn missing_section_names() {
+    let elf = load_fixture("hello");
+
+    assert_eq!(elf.section_index_by_name(".debug_info"), None);
+    assert_eq!(elf.find_section(".tex"), None);
+    assert_eq!(elf.find_section(".text."), None);
+    assert!(elf.find_section_all(".debug_info").is_empty());
+
+    // The null section has the empty name, but is never found
+    assert_eq!(elf.section_index_by_name(""), None);
+    assert!(elf.find_section_all("").is_empty());
+}
+
+#[test]
+fn duplicate_section_names() {
+    let mut elf = load_fixture("hello");
+    let data = elf.section_headers[25].name;
+    elf.section_headers[26].name = data;
+
+    let all = elf.find_section_all(".data");
+    let indices: Vec<_> = all.iter().map(|(i, _)| *i).collect();
+    assert_eq!(indices, [25, 26]);
+    assert_eq!(all[1].1.ty, SectionType::NoBits);
+
+    assert_eq!(elf.section_index_by_name(".data"), Some(25));
+    assert_eq!(elf.section_index_by_name(".bss"), None);
+}
+
+#[test]
+fn corrupt_section_name_index() {
+    // e_shstrndx of ELF64 files is at 0x3e
+    let mut bytes = fixture_bytes("hello");
+    bytes[0x3e..0x40].copy_from_slice(&500u16.to_le_bytes());
+
+    let elf = ELFFile::from_bytes_fully(&bytes).unwrap();
+    assert_eq!(elf.header.sh_str_index, 500);
+    assert_eq!(elf.find_section(".text"), None);
+    assert!(elf.find_section_all(".text").is_empty());
+    assert!(matches!(
+        elf.section_name(15),
+        Err(UnpackError::MissingStringTable)
+    ));
+    assert!(elf.named_sections().is_err());
+
+    // Pointing at a section that is not a string table
+    bytes[0x3e..0x40].copy_from_slice(&15u16.to_le_bytes());
+    let elf = ELFFile::from_bytes_fully(&bytes).unwrap();
+    assert_eq!(elf.find_section(".text"), None);
+    assert_eq!(elf.section_index_by_name(".dynsym"), None);
+}
+
+/// Returns the offset of the ELF64 section header at `index` in `bytes`
+fn section_header_offset(bytes: &[u8], index: usize) -> usize {
+    u64::from_le_bytes(bytes[0x28..0x30].try_into().unwrap()) as usize + index * 64
+}
+
+#[test]
+fn oversized_section_names() {
+    let mut bytes = fixture_bytes("static");
+    let index = u16::from_le_bytes(bytes[0x3e..0x40].try_into().unwrap()) as usize;
+
+    // The `sh_size` of the section names, far beyond the end of the file
+    let size = section_header_offset(&bytes, index) + 0x20;
+    bytes[size..size + 8].copy_from_slice(&0x7fff_ffff_ffffu64.to_le_bytes());
+
+    assert!(matches!(
+        ELFFile::from_bytes(&bytes),
+        Err(UnpackError::IO(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+
+    // Data ending just past the file is rejected as well
+    let offset = u64::from_le_bytes(bytes[size - 8..size].try_into().unwrap());
+    let remaining = bytes.len() as u64 - offset;
+    bytes[size..size + 8].copy_from_slice(&(remaining + 1).to_le_bytes());
+    assert!(ELFFile::from_bytes(&bytes).is_err());
+
+    bytes[size..size + 8].copy_from_slice(&remaining.to_le_bytes());
+    assert!(ELFFile::from_bytes(&bytes).is_ok());
+}
+
+#[test]
+fn interpreter_of_dynamic_executable() {
+    let elf = load_fixture("hello");
+    assert_eq!(
+        elf.interpreter().unwrap().as_deref(),
+        Some("/lib64/ld-linux-x86-64.so.2")
+    );
+
+    // Through the segment when there are no sections
+    let elf = load_fixture("hello_sstripped");
+    assert_eq!(
+        elf.interpreter().unwrap().as_deref(),
+        Some("/lib64/ld-linux-x86-64.so.2")
+    );
+}
+
+#[test]
+fn interpreter_from_section() {
+    let mut elf = load_fixture("hello");
+    elf.program_headers
+        .retain(|p| p.ty != ProgramHeaderType::Interpreter);
+
+    assert_eq!(
+        elf.interpreter().unwrap().as_deref(),
+        Some("/lib64/ld-linux-x86-64.so.2")
+    );
+}
+
+#[test]
+fn missing_interpreter() {
+    assert_eq!(load_fixture("static").interpreter().unwrap(), None);
+    assert_eq!(load_fixture("libgnu.so").interpreter().unwrap(), None);
+    assert_eq!(load_fixture("hello.o").interpreter().unwrap(), None);
+}
+
+#[test]
+fn unterminated_interpreter() {
+    let mut elf = load_fixture("hello");
+    let segment = elf
+        .program_headers
+        .iter_mut()
+        .find(|p| p.ty == ProgramHeaderType::Interpreter)
+        .unwrap();
+    segment.data.blob.pop();
+
+    assert!(matches!(
+        elf.interpreter(),
+        Err(UnpackError::InvalidString { offset: 0 })
+    ));
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_get_interpreter() {
+    let elf = load_fixture("hello");
+    assert_eq!(elf.get_interpreter(), Some("/lib64/ld-linux-x86-64.so.2"));
+    assert_eq!(load_fixture("static").get_interpreter(), None);
+}
+
+#[test]
+fn fully_load_truncated_file() {
+    let bytes = fixture_bytes("hello");
+
+    for len in [bytes.len() - 1, bytes.len() / 2, 0x1000, 0x40] {
+        assert!(ELFFile::from_bytes_fully(&bytes[..len]).is_err(), "{}", len);
+    }
+}
+
+#[test]
+fn fully_load_oversized_section() {
+    let mut bytes = fixture_bytes("hello");
+    let index = load_fixture("hello")
+        .section_index_by_name(".comment")
+        .unwrap();
+
+    // The `sh_size` of `.comment`, which is only read by a full load
+    let size = section_header_offset(&bytes, index) + 0x20;
+    let len = bytes.len() as u64;
+    bytes[size..size + 8].copy_from_slice(&len.to_le_bytes());
+
+    assert!(ELFFile::from_bytes(&bytes).is_ok());
+    assert!(matches!(
+        ELFFile::from_bytes_fully(&bytes),
+        Err(UnpackError::IO(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+}
+
+#[test]
+fn fully_load_oversized_segment() {
+    let mut bytes = fixture_bytes("static");
+
+    // The `p_filesz` of the first program header
+    let phoff = u64::from_le_bytes(bytes[0x20..0x28].try_into().unwrap()) as usize;
+    bytes[phoff + 0x20..phoff + 0x28].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    assert!(ELFFile::from_bytes(&bytes).is_ok());
+    assert!(ELFFile::from_bytes_fully(&bytes).is_err());
+}
+
+#[test]
+fn overlapping_sections_are_not_written() {
+    let mut elf = load_fixture("hello");
+    let text = elf.section_index_by_name(".text").unwrap();
+    let data = elf.section_index_by_name(".data").unwrap();
+
+    elf.section_headers[data].offset = elf.section_headers[text].offset;
+    let error = elf.to_bytes().unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn section_beyond_address_space_is_not_written() {
+    let mut elf = load_fixture("hello");
+    let text = elf.section_index_by_name(".text").unwrap();
+
+    elf.section_headers[text].offset = u64::MAX - 1;
+    let error = elf.to_bytes().unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
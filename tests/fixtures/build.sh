@@ -0,0 +1,79 @@
+#!/bin/sh
+# Rebuilds the test fixtures from the sources in src/
+#
+# Requires gcc, g++ and binutils for x86_64 as well as llvm-mc and
+# yaml2obj (LLVM 14 or newer) for the other architectures.
+# The checked-in fixtures were built on Debian 12 (gcc 12, binutils 2.40)
+
+set -eu
+
+cd "$(dirname "$0")"
+
+LLVM_MC=${LLVM_MC:-llvm-mc}
+YAML2OBJ=${YAML2OBJ:-yaml2obj}
+CFLAGS="-O1 -fcommon -fno-asynchronous-unwind-tables"
+
+# A dynamically linked PIE executable and its object file
+gcc $CFLAGS -c src/hello.c -o hello.o
+gcc $CFLAGS src/hello.c -o hello
+
+# Linked with immediate binding and an additional dependency on libm
+gcc $CFLAGS -Wl,-z,now -Wl,--no-as-needed src/hello.c -lm -o hello_now
+
+# With a library search path in DT_RUNPATH and in DT_RPATH
+gcc $CFLAGS -Wl,-rpath,'/opt/foo:/opt/bar:$ORIGIN/lib' src/hello.c -o hello_runpath
+gcc $CFLAGS -Wl,--disable-new-dtags -Wl,-rpath,'/opt/foo:/opt/bar:$ORIGIN/lib' \
+    src/hello.c -o hello_rpath
+
+# With CET enabled, the crt objects lack the property so the linker has to force it
+gcc $CFLAGS -fcf-protection=full -Wl,-z,ibt,-z,shstk src/hello.c -o hello_cet
+
+# With a 16 byte MD5 build id and without a build id
+gcc $CFLAGS -Wl,--build-id=md5 src/hello.c -o hello_md5
+gcc $CFLAGS -Wl,--build-id=none src/hello.c -o hello_nobuildid
+
+# Without section headers, only reachable through the program headers
+python3 src/sstrip.py hello hello_sstripped
+
+# The debug information split off into a separate file referenced by a debug link
+objcopy --only-keep-debug hello hello.debug
+objcopy --strip-debug --add-gnu-debuglink=hello.debug hello hello_debuglink
+
+# A static executable without libc
+gcc -static -nostdlib src/start.S -o static
+
+# A core dump of a static executable crashing with SIGSEGV, without anonymous
+# mappings and address space randomization. Requires the core pattern "core"
+gcc -static -nostdlib src/crash.S -o crash
+rm -f core
+(ulimit -c unlimited; echo 0 > /proc/self/coredump_filter; setarch -R ./crash) || true
+mv core crash.core
+
+# A shared library with a GNU hash table and versioned symbols
+gcc $CFLAGS -shared -fPIC -Wl,--hash-style=gnu -Wl,-soname,libgnu.so.1 \
+    -Wl,--version-script,src/lib.map src/lib.c -o libgnu.so
+
+# The same library with only a SysV hash table and without symbol versions
+gcc $CFLAGS -shared -fPIC -Wl,--hash-style=sysv -Wl,-soname,libsysv.so.1 \
+    src/lib.c -o libsysv.so
+
+# An object with debug information, compressed with zlib, in legacy .zdebug sections and with zstd
+gcc $CFLAGS -g -fdebug-prefix-map="$PWD"=. -c src/hello.c -o debug.o
+objcopy --compress-debug-sections=zlib debug.o debug_zlib.o
+objcopy --compress-debug-sections=zlib-gnu debug.o debug_zdebug.o
+objcopy --compress-debug-sections=zstd debug.o debug_zstd.o
+
+# A C++ object with a COMDAT group for a template instance, not inlined
+g++ -O0 -fno-asynchronous-unwind-tables -c src/tmpl.cpp -o tmpl.o
+
+# A 32 bit x86 object
+gcc $CFLAGS -m32 -fno-pic -c src/small.c -o small32.o
+
+# A small aarch64 shared object with dynamic relocations
+$YAML2OBJ src/aarch64.yaml -o aarch64.so
+
+# A riscv64 object with paired PC relative relocations
+$LLVM_MC -triple riscv64 -mattr=+relax -filetype=obj src/riscv64.s -o riscv64.o
+
+# A little endian 32 bit MIPS shared object with MIPS specific dynamic tags
+$YAML2OBJ src/mipsel.yaml -o mipsel.so
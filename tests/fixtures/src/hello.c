@@ -0,0 +1,16 @@
+#include <stdio.h>
+
+int counter;
+static int hidden_local = 3;
+
+__attribute__((weak)) int weak_fn(void) { return 1; }
+__attribute__((visibility("hidden"))) int hid(void) { return hidden_local; }
+
+static int ifunc_impl(void) { return 2; }
+static void *ifunc_resolve(void) { return ifunc_impl; }
+int ifunc_fn(void) __attribute__((ifunc("ifunc_resolve")));
+
+int main(void) {
+    printf("hi %d\n", weak_fn() + hid() + counter + ifunc_fn());
+    return 0;
+}
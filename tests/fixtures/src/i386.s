@@ -0,0 +1,6 @@
+    .text
+    .globl f
+f:
+    call ext
+    movl $data, %eax
+    ret
@@ -0,0 +1,7 @@
+#include <stdio.h>
+
+int lib_value = 42;
+
+int lib_add(int a, int b) { return a + b; }
+int lib_mul(int a, int b) { return a * b; }
+void lib_print(void) { puts("lib"); }
@@ -0,0 +1,21 @@
+    .text
+    .globl f
+f:
+.Lpcrel_hi0:
+    auipc a0, %pcrel_hi(value)
+    addi a0, a0, %pcrel_lo(.Lpcrel_hi0)
+.Lpcrel_hi1:
+    auipc a1, %pcrel_hi(other)
+    lw a1, %pcrel_lo(.Lpcrel_hi1)(a1)
+.Lpcrel_hi2:
+    auipc a2, %pcrel_hi(value)
+    sw a1, %pcrel_lo(.Lpcrel_hi2)(a2)
+    call ext
+    ret
+
+    .data
+    .globl value
+value:
+    .word 1
+other:
+    .word 2
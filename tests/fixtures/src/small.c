@@ -0,0 +1,6 @@
+/* Built without any headers so it compiles for targets without a libc */
+extern int ext(int);
+
+int data = 42;
+
+int main(void) { return ext(data) + 1; }
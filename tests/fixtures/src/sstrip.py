@@ -0,0 +1,23 @@
+#!/usr/bin/env python3
+"""Removes the section header table and everything after the last segment
+from a little endian ELF64 file, like sstrip from ELFkickers does"""
+
+import struct
+import sys
+
+src, dst = sys.argv[1], sys.argv[2]
+data = bytearray(open(src, "rb").read())
+
+ph_offset, = struct.unpack_from("<Q", data, 0x20)
+ph_size, ph_count = struct.unpack_from("<HH", data, 0x36)
+
+end = ph_offset + ph_size * ph_count
+for i in range(ph_count):
+    _, _, offset, _, _, file_size, _, _ = struct.unpack_from("<IIQQQQQQ", data, ph_offset + i * ph_size)
+    end = max(end, offset + file_size)
+
+# e_shoff, e_shentsize, e_shnum and e_shstrndx
+struct.pack_into("<Q", data, 0x28, 0)
+struct.pack_into("<HHH", data, 0x3a, 0, 0, 0)
+
+open(dst, "wb").write(data[:end])
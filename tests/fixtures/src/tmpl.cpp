@@ -0,0 +1,5 @@
+extern int ext(int);
+
+template <typename T> T twice(T a) { return ext(a) + a; }
+
+int use_twice() { return twice(1); }
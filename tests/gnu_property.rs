@@ -0,0 +1,88 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{Aarch64FeatureFlags, Class, GnuProperty, Machine, UnpackError, X86FeatureFlags};
+
+#[test]
+fn cet_enabled_binary() {
+    let properties = load_fixture("hello_cet").gnu_properties().unwrap();
+
+    let features = properties
+        .iter()
+        .find_map(|p| match p {
+            GnuProperty::X86Features(flags) => Some(*flags),
+            _ => None,
+        })
+        .unwrap();
+    assert!(features.ibt());
+    assert!(features.shstk());
+    assert!(features.contains(X86FeatureFlags(
+        X86FeatureFlags::IBT.0 | X86FeatureFlags::SHSTK.0
+    )));
+
+    assert!(properties.contains(&GnuProperty::X86IsaNeeded(0x1)));
+}
+
+#[test]
+fn binary_without_cet() {
+    let properties = load_fixture("hello").gnu_properties().unwrap();
+
+    assert_eq!(properties, [GnuProperty::X86IsaNeeded(0x1)]);
+    assert_eq!(load_fixture("small32.o").gnu_properties().unwrap(), []);
+}
+
+#[test]
+fn aarch64_features_depend_on_machine() {
+    // GNU_PROPERTY_AARCH64_FEATURE_1_AND with BTI and PAC, padded to 8 bytes
+    let desc = [0x00, 0x00, 0x00, 0xc0, 4, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0];
+
+    let properties = GnuProperty::parse(&desc, false, Class::ELF64, Machine::AArch64).unwrap();
+    assert_eq!(properties.len(), 1);
+    let GnuProperty::Aarch64Features(flags) = properties[0] else {
+        panic!("expected AArch64 features");
+    };
+    assert!(flags.bti() && flags.pac());
+    assert!(!flags.contains(Aarch64FeatureFlags::GCS));
+
+    let properties = GnuProperty::parse(&desc, false, Class::ELF64, Machine::X86_64).unwrap();
+    assert_eq!(
+        properties,
+        [GnuProperty::Other {
+            ty: 0xc0000000,
+            data: vec![3, 0, 0, 0]
+        }]
+    );
+}
+
+#[test]
+fn properties_of_32_bit_files_are_4_byte_aligned() {
+    // GNU_PROPERTY_X86_FEATURE_1_AND with IBT, then GNU_PROPERTY_X86_ISA_1_NEEDED
+    let desc = [
+        0x02, 0x00, 0x00, 0xc0, 4, 0, 0, 0, 1, 0, 0, 0, 0x02, 0x80, 0x00, 0xc0, 4, 0, 0, 0, 1, 0,
+        0, 0,
+    ];
+
+    let properties = GnuProperty::parse(&desc, false, Class::ELF32, Machine::X86).unwrap();
+    assert_eq!(
+        properties,
+        [
+            GnuProperty::X86Features(X86FeatureFlags::IBT),
+            GnuProperty::X86IsaNeeded(1)
+        ]
+    );
+}
+
+#[test]
+fn truncated_property_fails() {
+    let desc = [0x02, 0x00, 0x00, 0xc0, 0xff, 0xff, 0xff, 0xff, 1, 0, 0, 0];
+
+    assert!(matches!(
+        GnuProperty::parse(&desc, false, Class::ELF64, Machine::X86_64),
+        Err(UnpackError::Truncated {
+            size: 0xffffffff,
+            remaining: 4
+        })
+    ));
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{fixture_bytes, load_fixture};
+use elfsmith::{ELFFile, GroupFlags, SectionFlags, SectionGroup, UnpackError};
+
+/// Returns the section groups of the fixture `name`
+fn section_groups(elf: &ELFFile, name: &str) -> Result<Vec<SectionGroup>, UnpackError> {
+    elf.section_groups(&mut Cursor::new(fixture_bytes(name)))
+}
+
+#[test]
+fn template_instance_group() {
+    let elf = load_fixture("tmpl.o");
+    let groups = section_groups(&elf, "tmpl.o").unwrap();
+
+    assert_eq!(groups.len(), 1);
+    let group = &groups[0];
+
+    assert!(group.flags.is_comdat());
+    assert_eq!(group.signature(), Some("_Z5twiceIiET_S0_"));
+    assert_eq!(elf.section_name(group.section_index).unwrap(), ".group");
+
+    let text = elf.section_index_by_name(".text._Z5twiceIiET_S0_").unwrap();
+    let rela = elf
+        .section_index_by_name(".rela.text._Z5twiceIiET_S0_")
+        .unwrap();
+    assert_eq!(group.members, [text as u32, rela as u32]);
+
+    for index in [text, rela] {
+        assert!(group.contains(index));
+        assert!(elf.section_headers[index]
+            .flags
+            .contains(SectionFlags::GROUP));
+    }
+
+    // The non-template function is not part of the group
+    assert!(!group.contains(elf.section_index_by_name(".text").unwrap()));
+}
+
+#[test]
+fn lazily_loaded_group() {
+    let bytes = fixture_bytes("tmpl.o");
+    let elf = ELFFile::from_bytes(&bytes).unwrap();
+
+    assert_eq!(
+        section_groups(&elf, "tmpl.o").unwrap(),
+        section_groups(&load_fixture("tmpl.o"), "tmpl.o").unwrap()
+    );
+}
+
+#[test]
+fn no_groups() {
+    assert!(section_groups(&load_fixture("hello.o"), "hello.o")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn invalid_signature_index() {
+    let mut elf = load_fixture("tmpl.o");
+    let index = elf.section_index_by_name(".group").unwrap();
+    elf.section_headers[index].info = 1000;
+
+    assert!(matches!(
+        section_groups(&elf, "tmpl.o"),
+        Err(UnpackError::InvalidSymbolIndex { index: 1000 })
+    ));
+}
+
+#[test]
+fn group_flags() {
+    assert!(!GroupFlags(0).is_comdat());
+    assert!(GroupFlags(0x1).contains(GroupFlags::COMDAT));
+    assert!(GroupFlags(0x0ff00001).is_comdat());
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{fixture_bytes, load_fixture};
+use elfsmith::{
+    elf_hash, gnu_hash, ELFFile, GnuHashTable, SectionType, StringTable, SymbolTable, SysvHashTable,
+};
+
+/// Returns the dynamic symbol table of the fixture `name`
+fn dynamic_symbols(elf: &ELFFile, name: &str) -> SymbolTable {
+    let (index, _) = elf.sections_of_type(SectionType::DynSymbol).next().unwrap();
+
+    elf.read_symbol_table(&mut Cursor::new(fixture_bytes(name)), index)
+        .unwrap()
+}
+
+#[test]
+fn gnu_hash_function() {
+    assert_eq!(gnu_hash(b""), 0x1505);
+    assert_eq!(gnu_hash(b"printf"), 0x156b2bb8);
+}
+
+#[test]
+fn gnu_hash_lookup_in_shared_object() {
+    let elf = load_fixture("libgnu.so");
+    let section = elf.find_section(".gnu.hash").unwrap();
+    let table = GnuHashTable::from_blob(&section.data, elf.header.ident.class, false).unwrap();
+    let symbols = dynamic_symbols(&elf, "libgnu.so");
+    let strings = StringTable::from(&elf.find_section(".dynstr").unwrap().data);
+
+    // The undefined symbols come first and are not covered by the table
+    assert_eq!(table.symbol_offset, 6);
+
+    for (name, index) in [
+        ("lib_add", 6),
+        ("lib_mul", 7),
+        ("lib_print", 8),
+        ("LIBGNU_1.0", 9),
+        ("lib_value", 10),
+    ] {
+        assert_eq!(table.lookup_symbol(name, &symbols, &strings), Some(index));
+        assert_eq!(table.lookup(name), Some(index));
+        assert!(table.may_contain(gnu_hash(name.as_bytes())));
+    }
+}
+
+#[test]
+fn gnu_hash_lookup_of_absent_symbols() {
+    let elf = load_fixture("libgnu.so");
+    let section = elf.find_section(".gnu.hash").unwrap();
+    let table = GnuHashTable::from_blob(&section.data, elf.header.ident.class, false).unwrap();
+    let symbols = dynamic_symbols(&elf, "libgnu.so");
+    let strings = StringTable::from(&elf.find_section(".dynstr").unwrap().data);
+
+    for name in ["lib_sub", "puts", "__cxa_finalize", "", "lib_add2"] {
+        assert_eq!(
+            table.lookup_symbol(name, &symbols, &strings),
+            None,
+            "{}",
+            name
+        );
+    }
+
+    // An empty table contains nothing
+    let mut empty = table;
+    empty.buckets.clear();
+    empty.bloom.clear();
+    assert_eq!(empty.lookup("lib_add"), None);
+    assert!(!empty.may_contain(gnu_hash(b"lib_add")));
+}
+
+#[test]
+fn gnu_hash_symbol_count() {
+    let elf = load_fixture("libgnu.so");
+    let section = elf.find_section(".gnu.hash").unwrap();
+    let table = GnuHashTable::from_blob(&section.data, elf.header.ident.class, false).unwrap();
+
+    assert_eq!(table.symbol_count(), 11);
+    assert_eq!(elf.dynamic_symbol_count().unwrap(), Some(11));
+    assert_eq!(
+        load_fixture("hello_sstripped")
+            .dynamic_symbol_count()
+            .unwrap(),
+        Some(7)
+    );
+    assert_eq!(load_fixture("static").dynamic_symbol_count().unwrap(), None);
+}
+
+#[test]
+fn elf_hash_function() {
+    assert_eq!(elf_hash(b""), 0);
+    assert_eq!(elf_hash(b"printf"), 0x077905a6);
+    assert_eq!(elf_hash(b"lib_add"), 0x02f857d4);
+}
+
+#[test]
+fn sysv_hash_lookup_in_shared_object() {
+    let elf = load_fixture("libsysv.so");
+    assert!(elf.find_section(".gnu.hash").is_none());
+
+    let section = elf.find_section(".hash").unwrap();
+    assert_eq!(section.ty, SectionType::Hash);
+    let table = SysvHashTable::from_blob(&section.data, false).unwrap();
+    let symbols = dynamic_symbols(&elf, "libsysv.so");
+    let strings = StringTable::from(&elf.find_section(".dynstr").unwrap().data);
+
+    for (name, index) in [
+        ("lib_mul", 3),
+        ("lib_print", 4),
+        ("lib_add", 6),
+        ("lib_value", 7),
+        // Undefined symbols are part of SysV hash tables
+        ("puts", 2),
+    ] {
+        assert_eq!(table.lookup(name, &symbols, &strings), Some(index));
+    }
+
+    for name in ["lib_sub", "", "lib_add2", "LIBGNU_1.0"] {
+        assert_eq!(table.lookup(name, &symbols, &strings), None, "{}", name);
+    }
+}
+
+#[test]
+fn sysv_hash_symbol_count() {
+    let elf = load_fixture("libsysv.so");
+    let section = elf.find_section(".hash").unwrap();
+    let table = SysvHashTable::from_blob(&section.data, false).unwrap();
+
+    assert_eq!(table.symbol_count(), 10);
+    assert_eq!(elf.dynamic_symbol_count().unwrap(), Some(10));
+
+    // Without section headers, the table is found through the segments
+    let mut stripped = elf.clone();
+    stripped.section_headers.clear();
+    assert_eq!(stripped.dynamic_symbol_count().unwrap(), Some(10));
+}
+
+#[test]
+fn symbol_count_after_loading_sections() {
+    let bytes = fixture_bytes("libsysv.so");
+    let mut elf = ELFFile::from_bytes(&bytes).unwrap();
+
+    // The sections are used once their data is loaded, even though the segments are not
+    for name in [".dynamic", ".hash"] {
+        let index = elf.section_index_by_name(name).unwrap();
+        elf.load_section(index, &mut Cursor::new(&bytes)).unwrap();
+    }
+
+    assert_eq!(elf.dynamic_symbol_count().unwrap(), Some(10));
+}
+
+#[test]
+fn cyclic_sysv_chain() {
+    let elf = load_fixture("libsysv.so");
+    let mut table =
+        SysvHashTable::from_blob(&elf.find_section(".hash").unwrap().data, false).unwrap();
+    let symbols = dynamic_symbols(&elf, "libsysv.so");
+    let strings = StringTable::from(&elf.find_section(".dynstr").unwrap().data);
+
+    // Link every symbol to the first one of the bucket of `missing`
+    let start = table.buckets[elf_hash(b"missing") as usize % table.buckets.len()];
+    for chain in table.chains.iter_mut() {
+        *chain = start.max(1);
+    }
+
+    assert_eq!(table.lookup("missing", &symbols, &strings), None);
+}
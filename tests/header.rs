@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::{fixture_bytes, load_fixture, round_trip};
+use elfsmith::{Class, ELFFile, Machine, OsAbi};
+
+#[test]
+fn machine_of_real_binary() {
+    let elf = load_fixture("hello");
+
+    assert_eq!(elf.header.machine, Machine::X86_64);
+    assert_eq!(elf.header.machine.name(), "Advanced Micro Devices X86-64");
+    assert_eq!(elf.header.machine.pointer_width_hint(), Some(Class::ELF64));
+}
+
+#[test]
+fn machines_round_trip() {
+    let machines = [
+        (Machine::None, 0),
+        (Machine::X86, 3),
+        (Machine::Mips, 8),
+        (Machine::Arm, 40),
+        (Machine::X86_64, 62),
+        (Machine::AArch64, 183),
+        (Machine::RiscV, 243),
+        (Machine::LoongArch, 258),
+        (Machine::Other(0x1234), 0x1234),
+        (Machine::Other(0xffff), 0xffff),
+    ];
+
+    for (machine, value) in machines {
+        for big_endian in [false, true] {
+            let (packed, unpacked) = round_trip(&machine, big_endian);
+
+            let expected = match big_endian {
+                true => u16::to_be_bytes(value),
+                false => u16::to_le_bytes(value),
+            };
+            assert_eq!(packed, expected, "{:?}", machine);
+            assert_eq!(unpacked, machine);
+        }
+    }
+
+    assert_eq!(Machine::Other(0x1234).name(), "<unknown>");
+    assert_eq!(Machine::Other(0x1234).pointer_width_hint(), None);
+}
+
+#[test]
+fn gnu_os_abi_of_real_binary() {
+    // The ifunc in the fixture makes the linker mark it as ELFOSABI_GNU
+    let elf = load_fixture("hello");
+
+    assert_eq!(elf.header.ident.os_abi, OsAbi::Gnu);
+    assert_eq!(elf.header.ident.os_abi.to_string(), "UNIX - GNU");
+}
+
+#[test]
+fn unusual_os_abis_round_trip() {
+    for value in [0u8, 3, 9, 64, 97, 255, 0x42, 200] {
+        let mut bytes = fixture_bytes("hello.o");
+        bytes[7] = value;
+
+        let elf = ELFFile::from_bytes_fully(&bytes).unwrap();
+        assert_eq!(elf.header.ident.os_abi.value(), value);
+        assert_eq!(elf.to_bytes().unwrap(), bytes);
+    }
+
+    let (packed, unpacked) = round_trip(&OsAbi::Other(0x42), false);
+    assert_eq!(packed, [0x42]);
+    assert_eq!(unpacked, OsAbi::Other(0x42));
+    assert_eq!(OsAbi::Other(0x42).to_string(), "<unknown: 42>");
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{Class, ELFFile, SectionFlags, SectionHeader, SectionType, SectionWarning};
+
+/// Loads `name` and corrupts the section named `section` with `corrupt`
+/// # Returns
+/// The corrupted file and the index of the corrupted section
+fn corrupted<F: FnOnce(&mut SectionHeader)>(
+    name: &str,
+    section: &str,
+    corrupt: F,
+) -> (ELFFile, usize) {
+    let mut elf = load_fixture(name);
+    let index = elf.section_index_by_name(section).unwrap();
+    corrupt(&mut elf.section_headers[index]);
+
+    (elf, index)
+}
+
+#[test]
+fn real_binaries_are_clean() {
+    for name in ["hello", "hello.o", "hello_now", "small32.o"] {
+        assert_eq!(load_fixture(name).lint(), [], "{}", name);
+    }
+}
+
+#[test]
+fn symbol_table_entry_size() {
+    let (elf, index) = corrupted("hello", ".symtab", |s| s.entry_size = 16);
+
+    assert_eq!(
+        elf.lint(),
+        [(
+            index,
+            SectionWarning::EntrySizeMismatch {
+                expected: 24,
+                got: 16
+            }
+        )]
+    );
+}
+
+#[test]
+fn relocation_entry_sizes() {
+    let (elf, index) = corrupted("hello", ".rela.dyn", |s| s.entry_size = 12);
+    assert_eq!(
+        elf.lint(),
+        [(
+            index,
+            SectionWarning::EntrySizeMismatch {
+                expected: 24,
+                got: 12
+            }
+        )]
+    );
+
+    let (elf, index) = corrupted("small32.o", ".rel.text", |s| s.entry_size = 16);
+    assert_eq!(
+        elf.lint(),
+        [(
+            index,
+            SectionWarning::EntrySizeMismatch {
+                expected: 8,
+                got: 16
+            }
+        )]
+    );
+
+    let elf = load_fixture("small32.o");
+    assert_eq!(elf.section_headers[index].validate(Class::ELF32), []);
+}
+
+#[test]
+fn dynamic_entry_size() {
+    let (elf, index) = corrupted("hello", ".dynamic", |s| s.entry_size = 8);
+
+    assert_eq!(
+        elf.lint(),
+        [(
+            index,
+            SectionWarning::EntrySizeMismatch {
+                expected: 16,
+                got: 8
+            }
+        )]
+    );
+}
+
+#[test]
+fn executable_string_table() {
+    let (elf, index) = corrupted("hello", ".dynstr", |s| {
+        s.flags = s.flags | SectionFlags::EXECINSTR
+    });
+
+    assert_eq!(elf.lint(), [(index, SectionWarning::ExecutableStringTable)]);
+}
+
+#[test]
+fn nobits_with_data() {
+    let (elf, index) = corrupted("hello", ".bss", |s| s.data.blob = vec![0; 8]);
+
+    assert_eq!(elf.lint(), [(index, SectionWarning::NoBitsWithData)]);
+}
+
+#[test]
+fn invalid_alignment() {
+    let (elf, index) = corrupted("hello", ".text", |s| s.addr_align = 24);
+    assert_eq!(
+        elf.lint(),
+        [(index, SectionWarning::InvalidAlignment { align: 24 })]
+    );
+
+    let (elf, index) = corrupted("hello", ".text", |s| s.addr_align = 0x100);
+    assert_eq!(
+        elf.lint(),
+        [(
+            index,
+            SectionWarning::MisalignedAddress {
+                address: 0x1060,
+                align: 0x100
+            }
+        )]
+    );
+}
+
+#[test]
+fn invalid_link() {
+    let (elf, index) = corrupted("hello", ".symtab", |s| s.link = 1000);
+
+    assert_eq!(
+        elf.lint(),
+        [(index, SectionWarning::InvalidLink { link: 1000 })]
+    );
+}
+
+#[test]
+fn unexpected_link_type() {
+    let text = load_fixture("hello")
+        .section_index_by_name(".text")
+        .unwrap() as u32;
+    let (elf, index) = corrupted("hello", ".rela.dyn", |s| s.link = text);
+
+    assert_eq!(
+        elf.lint(),
+        [(
+            index,
+            SectionWarning::UnexpectedLinkType {
+                link: text,
+                ty: SectionType::ProgramData
+            }
+        )]
+    );
+}
+
+#[test]
+fn multiple_warnings_for_one_section() {
+    let (elf, index) = corrupted("hello", ".dynstr", |s| {
+        s.flags = s.flags | SectionFlags::EXECINSTR;
+        s.addr_align = 3;
+    });
+
+    let warnings = elf.section_headers[index].validate(Class::ELF64);
+    assert_eq!(
+        warnings,
+        [
+            SectionWarning::ExecutableStringTable,
+            SectionWarning::InvalidAlignment { align: 3 }
+        ]
+    );
+    assert_eq!(elf.lint().len(), 2);
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use common::load_fixture;
+use elfsmith::{ELFFile, ProgramHeaderType, UnpackError};
+
+/// Lays out the `PT_LOAD` segments of the fixture `name` like a loader at `base`
+fn memory_image(name: &str, base: u64) -> Vec<u8> {
+    let elf = load_fixture(name);
+    let mut image = vec![0u8; base as usize];
+
+    for (_, program) in elf.programs_of_type(ProgramHeaderType::Loadable) {
+        let start = (base + program.virtual_addr) as usize;
+        let end = start + program.mem_size as usize;
+        if image.len() < end {
+            image.resize(end, 0);
+        }
+
+        image[start..start + program.data.len()].copy_from_slice(&program.data);
+    }
+
+    image
+}
+
+#[test]
+fn load_executable_image() {
+    let base = 0x10000;
+    let image = memory_image("hello", base);
+    let elf = ELFFile::load_from_memory_image(&mut Cursor::new(&image), base).unwrap();
+    let file = load_fixture("hello");
+
+    assert_eq!(elf.header.entry_point, file.header.entry_point);
+    assert_eq!(elf.header.sh_offset, 0);
+    assert!(elf.section_headers.is_empty());
+    assert_eq!(elf.program_headers.len(), file.program_headers.len());
+
+    // The segments are read by address, `PT_LOAD` data equals the file contents
+    for (memory, file) in elf.program_headers.iter().zip(&file.program_headers) {
+        assert_eq!(memory.ty, file.ty);
+        if memory.ty == ProgramHeaderType::Loadable {
+            assert_eq!(memory.data, file.data);
+        }
+    }
+
+    assert_eq!(
+        elf.interpreter().unwrap().as_deref(),
+        Some("/lib64/ld-linux-x86-64.so.2")
+    );
+}
+
+#[test]
+fn dynamic_info_of_image() {
+    let base = 0x7f0000;
+    let image = memory_image("libgnu.so", base);
+    let elf = ELFFile::load_from_memory_image(&mut Cursor::new(&image), base).unwrap();
+    let info = elf.dynamic_info().unwrap().unwrap();
+
+    assert_eq!(info.needed, ["libc.so.6"]);
+    assert_eq!(info.soname.as_deref(), Some("libgnu.so.1"));
+    assert_eq!(info.symbols.len(), 11);
+    assert_eq!(info.symbols[10].name.as_deref(), Some("lib_value"));
+    assert_eq!(
+        info,
+        load_fixture("libgnu.so").dynamic_info().unwrap().unwrap()
+    );
+}
+
+#[test]
+fn truncated_image() {
+    let base = 0x1000;
+    let mut image = memory_image("hello", base);
+    image.truncate(base as usize + 0x1000);
+
+    assert!(ELFFile::load_from_memory_image(&mut Cursor::new(&image), base).is_err());
+}
+
+#[test]
+fn oversized_segment_in_image() {
+    let base = 0x1000;
+    let mut image = memory_image("hello", base);
+
+    // The `p_filesz` of the first program header, read from the mapped header
+    let phoff = base as usize + 0x40;
+    image[phoff + 0x20..phoff + 0x28].copy_from_slice(&0x7fff_ffff_ffffu64.to_le_bytes());
+
+    assert!(matches!(
+        ELFFile::load_from_memory_image(&mut Cursor::new(&image), base),
+        Err(UnpackError::IO(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+}
@@ -0,0 +1,104 @@
+//! Parses the headers through [elfsmith::io] only, so this runs with and without the `std` feature
+
+use elfsmith::{
+    io::{Cursor, Error, ErrorKind, Read, Seek, SeekFrom},
+    Class, Header, Machine, Packable, ProgramHeaderType, SectionType, Unpackable,
+};
+
+/// Returns the contents of the fixture `name`
+fn fixture_bytes(name: &str) -> Vec<u8> {
+    std::fs::read(format!(
+        "{}/tests/fixtures/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    ))
+    .unwrap()
+}
+
+/// A stream that is read in small chunks, like a flash device without a memory mapping
+struct Device<'a> {
+    data: &'a [u8],
+    position: u64,
+}
+
+impl Read for Device<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let start = (self.position as usize).min(self.data.len());
+        let len = buf.len().min(16).min(self.data.len() - start);
+
+        buf[..len].copy_from_slice(&self.data[start..start + len]);
+        self.position += len as u64;
+
+        Ok(len)
+    }
+}
+
+impl Seek for Device<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, Error> {
+        match pos {
+            SeekFrom::Start(position) => self.position = position,
+            _ => return Err(Error::new(ErrorKind::InvalidInput, "unsupported seek")),
+        }
+
+        Ok(self.position)
+    }
+}
+
+#[test]
+fn headers_from_cursor() {
+    let bytes = fixture_bytes("hello");
+    let mut r = Cursor::new(&bytes[..]);
+    let header = Header::unpack(&mut r, false).unwrap();
+
+    assert_eq!(header.ident.class, Class::ELF64);
+    assert_eq!(header.machine, Machine::X86_64);
+    assert_eq!(header.entry_point, 0x1060);
+
+    let programs = header.read_program_headers(&mut r).unwrap();
+    assert_eq!(programs.len(), 13);
+    assert_eq!(programs[1].ty, ProgramHeaderType::Interpreter);
+
+    let sections = header.read_section_headers(&mut r).unwrap();
+    assert_eq!(sections.len(), 31);
+    assert_eq!(sections[6].ty, SectionType::DynSymbol);
+}
+
+#[test]
+fn headers_from_custom_stream() {
+    let bytes = fixture_bytes("small32.o");
+    let mut device = Device {
+        data: &bytes,
+        position: 0,
+    };
+    let header = Header::unpack(&mut device, false).unwrap();
+
+    assert_eq!(header.ident.class, Class::ELF32);
+    assert!(header.read_program_headers(&mut device).unwrap().is_empty());
+    assert_eq!(
+        header.read_section_headers(&mut device).unwrap(),
+        header
+            .read_section_headers(&mut Cursor::new(&bytes[..]))
+            .unwrap()
+    );
+}
+
+#[test]
+fn pack_header() {
+    let bytes = fixture_bytes("hello");
+    let header = Header::unpack(&mut Cursor::new(&bytes[..]), false).unwrap();
+
+    let mut packed = Cursor::new(Vec::new());
+    header.clone().pack(&mut packed, false).unwrap();
+    assert_eq!(packed.get_ref()[..], bytes[..64]);
+
+    let mut buf = [0u8; 64];
+    header.pack(&mut Cursor::new(&mut buf[..]), false).unwrap();
+    assert_eq!(buf[..], bytes[..64]);
+}
+
+#[test]
+fn truncated_header() {
+    let bytes = fixture_bytes("hello");
+
+    assert!(Header::unpack(&mut Cursor::new(&bytes[..40]), false).is_err());
+}
@@ -0,0 +1,239 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{
+    AbiTag, AbiTagOs, Blob, Note, NoteIter, NoteSection, NoteType, ProgramHeaderType, UnpackError,
+};
+
+const HELLO_BUILD_ID: [u8; 20] = [
+    0xd6, 0x9c, 0x76, 0xc4, 0x53, 0xe2, 0x84, 0x50, 0x6f, 0x52, 0xea, 0xdb, 0xf2, 0x08, 0x62, 0x3a,
+    0xb5, 0xfc, 0x41, 0xb6,
+];
+
+/// Parses the notes of the section named `name` in the `hello` fixture
+fn section_notes(name: &str) -> Vec<Note> {
+    load_fixture("hello")
+        .find_section(name)
+        .unwrap()
+        .iter_notes(false)
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+#[test]
+fn build_id_note() {
+    let notes = section_notes(".note.gnu.build-id");
+    assert_eq!(notes.len(), 1);
+
+    let note = &notes[0];
+    assert_eq!(note.name, b"GNU");
+    assert_eq!(note.name_str(), Some("GNU"));
+    assert_eq!(note.ty, 3);
+    assert_eq!(note.gnu_type(), Some(NoteType::GnuBuildId));
+    assert_eq!(note.desc, HELLO_BUILD_ID);
+}
+
+#[test]
+fn abi_tag_note() {
+    let notes = section_notes(".note.ABI-tag");
+    assert_eq!(notes.len(), 1);
+
+    let note = &notes[0];
+    assert_eq!(note.gnu_type(), Some(NoteType::GnuAbiTag));
+    assert_eq!(note.desc, [0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn eight_byte_aligned_property_note() {
+    let elf = load_fixture("hello");
+    let section = elf.find_section(".note.gnu.property").unwrap();
+    assert_eq!(section.addr_align, 8);
+
+    let notes: Vec<Note> = section.iter_notes(false).collect::<Result<_, _>>().unwrap();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].gnu_type(), Some(NoteType::GnuProperty));
+    assert_eq!(notes[0].desc.len(), 16);
+}
+
+#[test]
+fn alignment_decides_padding() {
+    let mut data = Vec::new();
+    for value in [6u32, 4, 1] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(b"Linux\0\0\0\0\0\0\0");
+    data.extend_from_slice(&[0xaa, 0xbb, 0xcc, 0xdd, 0, 0, 0, 0]);
+    let blob = Blob::new(data);
+
+    let notes: Vec<Note> = NoteIter::new(&blob, false, 8)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].name_str(), Some("Linux"));
+    assert_eq!(notes[0].gnu_type(), None);
+    assert_eq!(notes[0].desc, [0xaa, 0xbb, 0xcc, 0xdd]);
+
+    // With 4 byte alignment, the descriptor is read from the name padding
+    let misaligned: Vec<Note> = NoteIter::new(&blob, false, 4)
+        .take(1)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(misaligned[0].desc, [0, 0, 0, 0]);
+}
+
+#[test]
+fn find_notes_in_section() {
+    let elf = load_fixture("hello");
+    let section = elf.find_section(".note.gnu.build-id").unwrap();
+
+    let notes = NoteSection::from_blob(&section.data, false).unwrap();
+    assert_eq!(notes.find("GNU", 3).unwrap().desc, HELLO_BUILD_ID);
+    assert_eq!(notes.find("GNU", 1), None);
+    assert_eq!(notes.find("Go", 3), None);
+}
+
+#[test]
+fn truncated_notes_fail() {
+    let elf = load_fixture("hello");
+    let data = &elf.find_section(".note.gnu.build-id").unwrap().data.blob;
+
+    // Cut within the descriptor
+    let truncated = Blob::new(data[..data.len() - 4].to_vec());
+    assert!(matches!(
+        NoteSection::parse(&truncated, false),
+        Err(UnpackError::Truncated {
+            size: 20,
+            remaining: 16
+        })
+    ));
+
+    // Cut within the header
+    let truncated = Blob::new(data[..6].to_vec());
+    let mut iter = NoteIter::new(&truncated, false, 4);
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+
+    // A descriptor size far beyond the data
+    let mut corrupt = data.clone();
+    corrupt[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(matches!(
+        NoteSection::parse(&Blob::new(corrupt), false),
+        Err(UnpackError::Truncated {
+            size: 0xffffffff,
+            remaining: 20
+        })
+    ));
+}
+
+#[test]
+fn notes_of_stripped_binary() {
+    let elf = load_fixture("hello_sstripped");
+    assert_eq!(elf.header.sh_offset, 0);
+    assert!(elf.section_headers.is_empty());
+
+    let notes = elf.notes().unwrap();
+    let types: Vec<_> = notes.iter().map(|n| n.gnu_type()).collect();
+    assert_eq!(
+        types,
+        [
+            Some(NoteType::GnuProperty),
+            Some(NoteType::GnuBuildId),
+            Some(NoteType::GnuAbiTag)
+        ]
+    );
+    assert_eq!(notes[1].desc, HELLO_BUILD_ID);
+
+    // The segments chained together hold the same notes as the sections
+    assert_eq!(notes, load_fixture("hello").notes().unwrap());
+}
+
+#[test]
+fn notes_of_note_segments() {
+    let elf = load_fixture("hello");
+
+    let segments: Vec<Vec<Note>> = elf
+        .program_headers
+        .iter()
+        .filter(|p| p.ty == ProgramHeaderType::Note)
+        .map(|p| p.iter_notes(false).collect::<Result<_, _>>().unwrap())
+        .collect();
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].len(), 1);
+    assert_eq!(segments[1].len(), 2);
+}
+
+#[test]
+fn sha1_build_id() {
+    let elf = load_fixture("hello");
+
+    assert_eq!(elf.build_id().unwrap(), HELLO_BUILD_ID);
+    assert_eq!(
+        elf.build_id_hex().as_deref(),
+        Some("d69c76c453e284506f52eadbf208623ab5fc41b6")
+    );
+    assert_eq!(
+        load_fixture("hello_sstripped").build_id().unwrap(),
+        HELLO_BUILD_ID
+    );
+}
+
+#[test]
+fn md5_build_id() {
+    let elf = load_fixture("hello_md5");
+
+    assert_eq!(elf.build_id().unwrap().len(), 16);
+    assert_eq!(
+        elf.build_id_hex().as_deref(),
+        Some("426985340caeb53a44531cde1dcc5d9f")
+    );
+}
+
+#[test]
+fn missing_build_id() {
+    let elf = load_fixture("hello_nobuildid");
+
+    assert!(elf.find_section(".note.gnu.build-id").is_none());
+    assert_eq!(elf.build_id(), None);
+    assert_eq!(elf.build_id_hex(), None);
+    assert_eq!(load_fixture("small32.o").build_id(), None);
+}
+
+#[test]
+fn abi_tag_of_glibc_binary() {
+    let tag = load_fixture("hello").abi_tag().unwrap();
+
+    assert_eq!(
+        tag,
+        AbiTag {
+            os: AbiTagOs::Linux,
+            major: 3,
+            minor: 2,
+            patch: 0
+        }
+    );
+    assert_eq!(load_fixture("hello_sstripped").abi_tag(), Some(tag));
+    assert_eq!(load_fixture("hello.o").abi_tag(), None);
+}
+
+#[test]
+fn abi_tag_follows_endianness() {
+    let little = [3, 0, 0, 0, 5, 0, 0, 0, 10, 0, 0, 0, 1, 0, 0, 0];
+    let big = [0, 0, 0, 3, 0, 0, 0, 5, 0, 0, 0, 10, 0, 0, 0, 1];
+    let expected = AbiTag {
+        os: AbiTagOs::FreeBsd,
+        major: 5,
+        minor: 10,
+        patch: 1,
+    };
+
+    assert_eq!(AbiTag::from_desc(&little, false).unwrap(), expected);
+    assert_eq!(AbiTag::from_desc(&big, true).unwrap(), expected);
+
+    let unknown = AbiTag::from_desc(&big, false).unwrap();
+    assert_eq!(unknown.os, AbiTagOs::Other(0x03000000));
+    assert_eq!(unknown.os.value(), 0x03000000);
+
+    assert!(AbiTag::from_desc(&little[..12], false).is_err());
+}
@@ -0,0 +1,200 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{ELFError, ELFFile, PatchError, ProgramHeaderType, SectionFlags, SectionType};
+
+/// Writes `elf` and loads it again
+fn reload(elf: &ELFFile) -> ELFFile {
+    ELFFile::from_bytes_fully(&elf.to_bytes().unwrap()).unwrap()
+}
+
+#[test]
+fn patch_entry_point() {
+    let mut elf = load_fixture("hello");
+    elf.patch_entry_point(0x1163);
+
+    assert_eq!(reload(&elf).header.entry_point, 0x1163);
+}
+
+#[test]
+fn patch_loadable_segment() {
+    let mut elf = load_fixture("hello");
+    let (index, program) = elf
+        .programs_of_type(ProgramHeaderType::Loadable)
+        .find(|(_, p)| p.flags.is_executable())
+        .unwrap();
+    let main = (0x1163 - program.virtual_addr) as usize;
+
+    // Replace the start of `main` with `int3`
+    let mut data = program.data.to_vec();
+    data[main..main + 4].fill(0xcc);
+    elf.patch_program_data(index, data.clone()).unwrap();
+
+    let bytes = elf.to_bytes().unwrap();
+    assert_eq!(bytes[0x1163..0x1167], [0xcc; 4]);
+
+    let patched = ELFFile::from_bytes_fully(&bytes).unwrap();
+    assert_eq!(patched.program_headers[index].data.to_vec(), data);
+
+    // The sections within the segment show the new bytes as well
+    let text = patched.find_section(".text").unwrap();
+    let main = (0x1163 - text.address) as usize;
+    assert_eq!(text.data[main..main + 4], [0xcc; 4]);
+}
+
+#[test]
+fn patch_section_in_segment() {
+    let mut elf = load_fixture("hello");
+    let index = elf.section_index_by_name(".text").unwrap();
+    let mut data = elf.section_headers[index].data.to_vec();
+    data[0x103..0x107].fill(0x90);
+    elf.patch_section_data(index, data).unwrap();
+
+    let patched = reload(&elf);
+    let (_, program) = patched
+        .programs_of_type(ProgramHeaderType::Loadable)
+        .find(|(_, p)| p.flags.is_executable())
+        .unwrap();
+
+    assert_eq!(program.data[0x163..0x167], [0x90; 4]);
+}
+
+#[test]
+fn patch_section_size() {
+    let mut elf = load_fixture("hello");
+    let index = elf.section_index_by_name(".comment").unwrap();
+
+    elf.patch_section_data(index, b"patched\0".to_vec())
+        .unwrap();
+    let patched = reload(&elf);
+    assert_eq!(patched.section_headers[index].size, 8);
+    assert_eq!(patched.section_headers[index].data.to_vec(), b"patched\0");
+
+    // `.comment` is followed by `.symtab` at the next 8 byte boundary
+    let available = elf.section_headers[index + 1].offset - elf.section_headers[index].offset;
+    assert_eq!(
+        elf.patch_section_data(index, vec![0; available as usize + 1]),
+        Err(PatchError::SizeMismatch {
+            size: available + 1,
+            available
+        })
+    );
+    elf.patch_section_data(index, vec![1; available as usize])
+        .unwrap();
+}
+
+#[test]
+fn patch_errors() {
+    let mut elf = load_fixture("hello");
+    let sections = elf.section_headers.len();
+    let programs = elf.program_headers.len();
+
+    assert_eq!(
+        elf.patch_section_data(sections, Vec::new()),
+        Err(PatchError::InvalidSectionIndex { index: sections })
+    );
+    assert_eq!(
+        elf.patch_program_data(programs, Vec::new()),
+        Err(PatchError::InvalidProgramIndex { index: programs })
+    );
+
+    let bss = elf.section_index_by_name(".bss").unwrap();
+    assert_eq!(
+        elf.patch_section_data(bss, vec![0; 8]),
+        Err(PatchError::NoBitsSection { index: bss })
+    );
+
+    // Segments cannot grow
+    let size = elf.program_headers[0].file_size;
+    assert_eq!(
+        elf.patch_program_data(0, vec![0; size as usize + 1]),
+        Err(PatchError::SizeMismatch {
+            size: size + 1,
+            available: size
+        })
+    );
+}
+
+#[test]
+fn inject_section() {
+    for fixture in ["static", "libgnu.so", "hello.o"] {
+        let original = load_fixture(fixture);
+        let mut elf = original.clone();
+
+        let index = elf
+            .inject_section(
+                ".elfsmith",
+                SectionType::ProgramData,
+                SectionFlags::default(),
+                b"injected".to_vec(),
+                16,
+            )
+            .unwrap();
+        assert_eq!(index, original.section_headers.len());
+
+        let elf = reload(&elf);
+        assert_eq!(elf.validate(), Vec::new(), "{}", fixture);
+        assert_eq!(elf.section_index_by_name(".elfsmith"), Some(index));
+
+        let section = &elf.section_headers[index];
+        assert_eq!(section.data.blob, b"injected");
+        assert_eq!(section.offset % 16, 0);
+
+        // The existing sections keep their names and contents, the names only grow
+        let names = original.header.sh_str_index as usize;
+        for (index, old) in original.section_headers.iter().enumerate() {
+            let new = &elf.section_headers[index];
+            assert_eq!(
+                elf.section_name(index).ok(),
+                original.section_name(index).ok()
+            );
+            match index == names {
+                true => assert!(new.data.blob.starts_with(&old.data.blob)),
+                false => assert_eq!(new.data, old.data, "{} of {}", index, fixture),
+            }
+        }
+    }
+}
+
+#[test]
+fn inject_nobits_section() {
+    let mut elf = load_fixture("hello.o");
+    let index = elf
+        .inject_section(
+            ".elfsmith.bss",
+            SectionType::NoBits,
+            SectionFlags::default(),
+            vec![0; 0x100],
+            8,
+        )
+        .unwrap();
+
+    let elf = reload(&elf);
+    assert_eq!(elf.validate(), Vec::new());
+    assert_eq!(elf.section_name(index).unwrap(), ".elfsmith.bss");
+    assert_eq!(elf.section_headers[index].size, 0x100);
+    assert!(elf.section_headers[index].data.blob.is_empty());
+}
+
+#[test]
+fn inject_section_with_invalid_alignment() {
+    let mut elf = load_fixture("hello.o");
+    let sections = elf.section_headers.len();
+
+    let result = elf.inject_section(
+        ".elfsmith",
+        SectionType::ProgramData,
+        SectionFlags::default(),
+        Vec::new(),
+        12,
+    );
+    assert!(matches!(
+        result,
+        Err(ELFError::Patch(PatchError::InvalidAlignment {
+            alignment: 12
+        }))
+    ));
+    assert_eq!(elf.section_headers.len(), sections);
+}
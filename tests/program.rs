@@ -0,0 +1,130 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::{load_fixture, round_trip};
+use elfsmith::{ProgramHeaderType, SegmentFlags};
+
+#[test]
+fn program_header_types_round_trip() {
+    let types = [
+        (ProgramHeaderType::Unused, 0x0),
+        (ProgramHeaderType::Loadable, 0x1),
+        (ProgramHeaderType::Dynamic, 0x2),
+        (ProgramHeaderType::Interpreter, 0x3),
+        (ProgramHeaderType::Note, 0x4),
+        (ProgramHeaderType::SharedLibrary, 0x5),
+        (ProgramHeaderType::ProgramHeaderTable, 0x6),
+        (ProgramHeaderType::ThreadLocalStorage, 0x7),
+        (ProgramHeaderType::GnuEhFrame, 0x6474e550),
+        (ProgramHeaderType::GnuStack, 0x6474e551),
+        (ProgramHeaderType::GnuRelro, 0x6474e552),
+        (ProgramHeaderType::GnuProperty, 0x6474e553),
+        (ProgramHeaderType::SunwBss, 0x6ffffffa),
+        (ProgramHeaderType::SunwStack, 0x6ffffffb),
+        (ProgramHeaderType::Other(0x6474e554), 0x6474e554),
+        (ProgramHeaderType::Other(0x70000001), 0x70000001),
+    ];
+
+    for (ty, value) in types {
+        for big_endian in [false, true] {
+            let (packed, unpacked) = round_trip(&ty, big_endian);
+
+            let expected = match big_endian {
+                true => u32::to_be_bytes(value),
+                false => u32::to_le_bytes(value),
+            };
+            assert_eq!(packed, expected, "{:?}", ty);
+            assert_eq!(unpacked, ty);
+        }
+    }
+}
+
+#[test]
+fn gnu_segments_of_glibc_binary() {
+    let elf = load_fixture("hello");
+
+    let types: Vec<String> = elf
+        .program_headers
+        .iter()
+        .map(|p| p.ty.to_string())
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "PHDR",
+            "INTERP",
+            "LOAD",
+            "LOAD",
+            "LOAD",
+            "LOAD",
+            "DYNAMIC",
+            "NOTE",
+            "NOTE",
+            "GNU_PROPERTY",
+            "GNU_EH_FRAME",
+            "GNU_STACK",
+            "GNU_RELRO"
+        ]
+    );
+
+    assert_eq!(elf.programs_of_type(ProgramHeaderType::GnuStack).count(), 1);
+    assert_eq!(elf.programs_of_type(ProgramHeaderType::GnuRelro).count(), 1);
+    assert_eq!(ProgramHeaderType::Other(0x60000001).to_string(), "LOOS+1");
+}
+
+#[test]
+fn segment_flags_of_real_binary() {
+    let elf = load_fixture("hello");
+
+    let loads: Vec<_> = elf
+        .program_headers
+        .iter()
+        .filter(|p| p.ty == ProgramHeaderType::Loadable)
+        .map(|p| p.flags)
+        .collect();
+    assert_eq!(
+        loads,
+        [
+            SegmentFlags::READ,
+            SegmentFlags(SegmentFlags::READ.0 | SegmentFlags::EXECUTE.0),
+            SegmentFlags::READ,
+            SegmentFlags(SegmentFlags::READ.0 | SegmentFlags::WRITE.0),
+        ]
+    );
+
+    let display: Vec<_> = loads.iter().map(|f| f.to_string()).collect();
+    assert_eq!(display, ["R  ", "R E", "R  ", "RW "]);
+    assert!(!loads[0].is_executable() && !loads[0].is_writable());
+    assert!(loads[1].is_readable() && loads[1].is_executable());
+}
+
+#[test]
+fn segment_flags_setters_preserve_unknown_bits() {
+    let flags = SegmentFlags(0x10000000 | 0x00100000 | SegmentFlags::READ.0);
+
+    let flags = flags.with_writable(true).with_executable(true);
+    assert_eq!(flags.raw(), 0x10100007);
+    assert_eq!(flags.to_string(), "RWE");
+
+    let flags = flags.with_readable(false).with_writable(false);
+    assert_eq!(flags.raw(), 0x10100001);
+    assert_eq!(flags.to_string(), "  E");
+
+    for big_endian in [false, true] {
+        let (packed, unpacked) = round_trip(&flags, big_endian);
+        assert_eq!(packed.len(), 4);
+        assert_eq!(unpacked, flags);
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_segment_flag_accessors() {
+    for value in 0..8 {
+        let flags = SegmentFlags(value);
+        assert_eq!(flags.readable(), flags.is_readable());
+        assert_eq!(flags.writable(), flags.is_writable());
+        assert_eq!(flags.executable(), flags.is_executable());
+    }
+}
@@ -0,0 +1,253 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{fixture_bytes, load_fixture};
+use elfsmith::{
+    Aarch64RelocationType, Class, Machine, RelEntry, RelaEntry, RelocationTable, RelocationType,
+    RiscVRelocationType, SectionType, X86_64RelocationType,
+};
+
+#[test]
+fn rela_dyn_of_pie() {
+    let elf = load_fixture("hello");
+    let section = elf.find_section(".rela.dyn").unwrap();
+    assert_eq!(section.ty, SectionType::Rela);
+
+    let entries: Vec<RelaEntry> = section
+        .iter_rela(Class::ELF64, false)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(entries.len(), 8);
+    assert_eq!(section.entry_size, RelaEntry::get_size(Class::ELF64));
+
+    let first = entries[0];
+    assert_eq!(
+        (first.offset, first.info, first.addend),
+        (0x3dd0, 8, 0x1140)
+    );
+    assert_eq!(first.symbol_index(Class::ELF64), 0);
+    assert_eq!(first.relocation_type(Class::ELF64), 8);
+
+    let glob_dat = entries[3];
+    assert_eq!(glob_dat.offset, 0x3fc0);
+    assert_eq!(glob_dat.symbol_index(Class::ELF64), 1);
+    assert_eq!(glob_dat.relocation_type(Class::ELF64), 6);
+}
+
+#[test]
+fn negative_addends_of_object() {
+    let elf = load_fixture("hello.o");
+    let section = elf.find_section(".rela.text").unwrap();
+
+    let RelocationTable::Rela(entries) =
+        RelocationTable::from_section(section, Class::ELF64, false).unwrap()
+    else {
+        panic!("expected a table with addends");
+    };
+    assert_eq!(entries.len(), 5);
+    assert!(entries.iter().all(|e| e.addend == -4));
+    assert_eq!(entries[1].symbol_index(Class::ELF64), 9);
+    assert_eq!(entries[1].relocation_type(Class::ELF64), 2);
+}
+
+#[test]
+fn rel_text_of_32_bit_object() {
+    let elf = load_fixture("small32.o");
+    let section = elf.find_section(".rel.text").unwrap();
+    assert_eq!(section.ty, SectionType::Rel);
+    assert_eq!(section.entry_size, RelEntry::get_size(Class::ELF32));
+
+    let table = RelocationTable::from_section(section, Class::ELF32, false).unwrap();
+    assert_eq!(table.len(), 2);
+
+    let RelocationTable::Rel(entries) = table else {
+        panic!("expected a table without addends");
+    };
+
+    // R_386_32 against `data` and R_386_PC32 against `ext`
+    assert_eq!((entries[0].offset, entries[0].info), (0x13, 0x301));
+    assert_eq!(entries[0].symbol_index(Class::ELF32), 3);
+    assert_eq!(entries[0].relocation_type(Class::ELF32), 1);
+    assert_eq!(entries[1].symbol_index(Class::ELF32), 4);
+    assert_eq!(entries[1].relocation_type(Class::ELF32), 2);
+}
+
+#[test]
+fn x86_64_dynamic_relocation_types() {
+    let elf = load_fixture("hello");
+    let machine = elf.header.machine;
+
+    let types = |name| -> Vec<RelocationType> {
+        elf.find_section(name)
+            .unwrap()
+            .iter_rela(Class::ELF64, false)
+            .map(|e| e.unwrap().typed(machine, Class::ELF64))
+            .collect()
+    };
+
+    let plt = types(".rela.plt");
+    assert_eq!(
+        plt,
+        [
+            RelocationType::X86_64(X86_64RelocationType::JumpSlot),
+            RelocationType::X86_64(X86_64RelocationType::IRelative),
+        ]
+    );
+    assert_eq!(plt[0].name(), "R_X86_64_JUMP_SLOT");
+
+    let dyn_types = types(".rela.dyn");
+    assert_eq!(
+        dyn_types[..3],
+        [RelocationType::X86_64(X86_64RelocationType::Relative); 3]
+    );
+    assert_eq!(dyn_types[0].name(), "R_X86_64_RELATIVE");
+    assert_eq!(dyn_types[3].name(), "R_X86_64_GLOB_DAT");
+}
+
+#[test]
+fn x86_64_relocation_values() {
+    for (ty, value) in [
+        (X86_64RelocationType::Abs64, 1),
+        (X86_64RelocationType::Pc32, 2),
+        (X86_64RelocationType::Plt32, 4),
+        (X86_64RelocationType::JumpSlot, 7),
+        (X86_64RelocationType::Relative, 8),
+        (X86_64RelocationType::GotPcRel, 9),
+        (X86_64RelocationType::IRelative, 37),
+    ] {
+        assert_eq!(u32::from(ty), value);
+        assert_eq!(X86_64RelocationType::from(value), ty);
+    }
+
+    let unknown = X86_64RelocationType::from(0x1234);
+    assert_eq!(unknown, X86_64RelocationType::Other(0x1234));
+    assert_eq!(u32::from(unknown), 0x1234);
+    assert_eq!(RelocationType::new(Machine::X86_64, 0x1234).value(), 0x1234);
+}
+
+#[test]
+fn aarch64_dynamic_relocation_types() {
+    let elf = load_fixture("aarch64.so");
+    assert_eq!(elf.header.machine, Machine::AArch64);
+
+    let typed = |name| -> Vec<(RelaEntry, RelocationType)> {
+        elf.find_section(name)
+            .unwrap()
+            .iter_rela(Class::ELF64, false)
+            .map(|e| e.unwrap())
+            .map(|e| (e, e.typed(Machine::AArch64, Class::ELF64)))
+            .collect()
+    };
+
+    let dyn_types: Vec<_> = typed(".rela.dyn").into_iter().map(|(_, t)| t).collect();
+    assert_eq!(
+        dyn_types,
+        [
+            RelocationType::Aarch64(Aarch64RelocationType::Relative),
+            RelocationType::Aarch64(Aarch64RelocationType::GlobDat),
+            RelocationType::Aarch64(Aarch64RelocationType::Abs64),
+            RelocationType::Aarch64(Aarch64RelocationType::IRelative),
+        ]
+    );
+    let names: Vec<_> = dyn_types.iter().map(|t| t.name()).collect();
+    assert_eq!(
+        names,
+        [
+            "R_AARCH64_RELATIVE",
+            "R_AARCH64_GLOB_DAT",
+            "R_AARCH64_ABS64",
+            "R_AARCH64_IRELATIVE"
+        ]
+    );
+
+    let plt = typed(".rela.plt");
+    let (entry, ty) = plt[0];
+    assert_eq!(ty, RelocationType::Aarch64(Aarch64RelocationType::JumpSlot));
+    assert_eq!(entry.symbol_index(Class::ELF64), 2);
+    assert_eq!(ty.value(), 1026);
+}
+
+#[test]
+fn unknown_aarch64_relocations_are_kept() {
+    let unknown = Aarch64RelocationType::from(0x7ff);
+    assert_eq!(unknown, Aarch64RelocationType::Other(0x7ff));
+    assert_eq!(unknown.value(), 0x7ff);
+    assert_eq!(
+        RelocationType::new(Machine::AArch64, 0x7ff),
+        RelocationType::Aarch64(unknown)
+    );
+    assert_eq!(Aarch64RelocationType::from(1027).value(), 1027);
+}
+
+#[test]
+fn riscv64_relocations_of_object() {
+    let elf = load_fixture("riscv64.o");
+    assert_eq!(elf.header.machine, Machine::RiscV);
+
+    let entries: Vec<RelaEntry> = elf
+        .find_section(".rela.text")
+        .unwrap()
+        .iter_rela(Class::ELF64, false)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let types: Vec<_> = entries
+        .iter()
+        .map(|e| e.typed(Machine::RiscV, Class::ELF64))
+        .filter(|t| *t != RelocationType::RiscV(RiscVRelocationType::Relax))
+        .map(|t| t.name())
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "R_RISCV_PCREL_HI20",
+            "R_RISCV_PCREL_LO12_I",
+            "R_RISCV_PCREL_HI20",
+            "R_RISCV_PCREL_LO12_I",
+            "R_RISCV_PCREL_HI20",
+            "R_RISCV_PCREL_LO12_S",
+            "R_RISCV_CALL",
+        ]
+    );
+    assert_eq!(entries.len(), 14);
+}
+
+#[test]
+fn riscv64_pcrel_pairs() {
+    let elf = load_fixture("riscv64.o");
+    let index = elf.section_index_by_name(".symtab").unwrap();
+    let symbols = elf
+        .read_symbol_table(&mut Cursor::new(fixture_bytes("riscv64.o")), index)
+        .unwrap();
+    let entries: Vec<RelaEntry> = elf
+        .find_section(".rela.text")
+        .unwrap()
+        .iter_rela(Class::ELF64, false)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    let pairs: Vec<(u64, u64)> = entries
+        .iter()
+        .filter_map(|lo| {
+            lo.riscv_pcrel_hi20(&entries, &symbols, Class::ELF64)
+                .map(|hi| (lo.offset, hi.offset))
+        })
+        .collect();
+    assert_eq!(pairs, [(0x4, 0x0), (0xc, 0x8), (0x14, 0x10)]);
+
+    // The HI20 relocations reference the actual targets
+    let hi = entries[8].symbol_index(Class::ELF64) as usize;
+    assert_eq!(symbols.symbols[hi].name.as_deref(), Some("value"));
+
+    // Relocations other than PCREL_LO12 have no pair
+    assert_eq!(
+        entries[0].riscv_pcrel_hi20(&entries, &symbols, Class::ELF64),
+        None
+    );
+    assert_eq!(
+        entries[12].riscv_pcrel_hi20(&entries, &symbols, Class::ELF64),
+        None
+    );
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{fixture_bytes, load_fixture};
+use elfsmith::{Class, PackableClass, SectionFlags, SectionHeader, SectionType, UnpackableClass};
+
+#[test]
+fn section_types_of_real_binary() {
+    let elf = load_fixture("hello");
+
+    let ty = |name| elf.find_section(name).unwrap().ty;
+    assert_eq!(ty(".text"), SectionType::ProgramData);
+    assert_eq!(ty(".symtab"), SectionType::SymbolTable);
+    assert_eq!(ty(".strtab"), SectionType::StringTable);
+    assert_eq!(ty(".bss"), SectionType::NoBits);
+    assert_eq!(ty(".dynsym"), SectionType::DynSymbol);
+    assert_eq!(ty(".gnu.hash"), SectionType::GnuHash);
+    assert_eq!(ty(".gnu.version"), SectionType::GnuVersionSymbol);
+    assert_eq!(elf.section_headers[0].ty, SectionType::Null);
+}
+
+#[test]
+fn section_headers_repack_identically() {
+    let bytes = fixture_bytes("hello");
+    let elf = load_fixture("hello");
+    let class = elf.header.ident.class;
+    let size = elf.header.get_section_header_size() as usize;
+
+    for (index, section) in elf.section_headers.iter().enumerate() {
+        let offset = elf.header.sh_offset as usize + index * size;
+
+        let mut packed = Cursor::new(Vec::new());
+        section
+            .clone()
+            .pack_class(&mut packed, false, class)
+            .unwrap();
+        assert_eq!(packed.get_ref()[..], bytes[offset..offset + size]);
+    }
+}
+
+#[test]
+fn unknown_section_type_round_trips() {
+    let mut section = load_fixture("hello").section_headers[1].clone();
+    section.ty = SectionType::Other(0x6fff4700);
+    section.data = Default::default();
+
+    let mut packed = Cursor::new(Vec::new());
+    section
+        .clone()
+        .pack_class(&mut packed, false, Class::ELF64)
+        .unwrap();
+    assert_eq!(packed.get_ref()[4..8], 0x6fff4700u32.to_le_bytes());
+
+    packed.set_position(0);
+    let unpacked = SectionHeader::unpack_class(&mut packed, false, Class::ELF64).unwrap();
+    assert_eq!(unpacked, section);
+}
+
+#[test]
+fn load_and_write_round_trip() {
+    for name in ["hello", "hello.o"] {
+        let bytes = fixture_bytes(name);
+        let elf = load_fixture(name);
+
+        assert_eq!(elf.to_bytes().unwrap(), bytes, "{}", name);
+    }
+}
+
+#[test]
+fn section_flags_of_real_binary() {
+    let elf = load_fixture("hello");
+
+    let flags = |name| elf.find_section(name).unwrap().flags;
+    assert_eq!(
+        flags(".text"),
+        SectionFlags::ALLOC | SectionFlags::EXECINSTR
+    );
+    assert_eq!(flags(".data"), SectionFlags::WRITE | SectionFlags::ALLOC);
+    assert_eq!(flags(".rodata"), SectionFlags::ALLOC);
+    assert_eq!(
+        flags(".comment"),
+        SectionFlags::MERGE | SectionFlags::STRINGS
+    );
+    assert_eq!(flags(".symtab"), SectionFlags::default());
+
+    assert_eq!(flags(".text").to_string(), "AX");
+    assert_eq!(flags(".data").to_string(), "WA");
+    assert_eq!(flags(".comment").to_string(), "MS");
+    assert!(flags(".bss").is_writable() && !flags(".bss").is_executable());
+}
+
+#[test]
+fn section_flags_display_like_readelf() {
+    let wax = SectionFlags::WRITE | SectionFlags::ALLOC | SectionFlags::EXECINSTR;
+    assert_eq!(wax.to_string(), "WAX");
+    assert_eq!(
+        (SectionFlags::ALLOC | SectionFlags::TLS | SectionFlags::GROUP).to_string(),
+        "AGT"
+    );
+    assert_eq!(SectionFlags(0x01000000).to_string(), "o");
+    assert_eq!(SectionFlags(0x10000000).to_string(), "p");
+    assert_eq!(SectionFlags(0x8).to_string(), "x");
+    assert_eq!(SectionFlags::default().to_string(), "");
+}
+
+#[test]
+fn section_flags_round_trip() {
+    let flags = SectionFlags::WRITE | SectionFlags::ALLOC | SectionFlags(0x10000008);
+
+    for (class, size) in [(Class::ELF32, 4), (Class::ELF64, 8)] {
+        for big_endian in [false, true] {
+            let mut packed = Cursor::new(Vec::new());
+            flags.pack_class(&mut packed, big_endian, class).unwrap();
+            assert_eq!(packed.get_ref().len(), size);
+
+            packed.set_position(0);
+            let unpacked = SectionFlags::unpack_class(&mut packed, big_endian, class).unwrap();
+            assert_eq!(unpacked, flags);
+            assert_eq!(unpacked.raw(), 0x1000000b);
+        }
+    }
+}
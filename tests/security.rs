@@ -0,0 +1,48 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{ProgramHeaderType, SecurityProperties};
+
+#[test]
+fn properties_of_fixtures() {
+    let fixtures = [
+        ("hello", true, true, true, false),
+        ("hello_now", true, true, true, true),
+        ("libgnu.so", true, true, true, false),
+        ("static", false, false, false, false),
+    ];
+
+    for (fixture, nx_stack, relro, pie, bind_now) in fixtures {
+        let elf = load_fixture(fixture);
+
+        assert_eq!(elf.has_nx_stack(), nx_stack, "{}", fixture);
+        assert_eq!(elf.has_relro(), relro, "{}", fixture);
+        assert_eq!(elf.has_pie(), pie, "{}", fixture);
+        assert_eq!(elf.has_bind_now(), bind_now, "{}", fixture);
+        assert_eq!(
+            elf.security_properties(),
+            SecurityProperties {
+                nx_stack,
+                relro,
+                pie,
+                bind_now
+            }
+        );
+    }
+}
+
+#[test]
+fn executable_stack() {
+    let mut elf = load_fixture("hello");
+    let stack = elf
+        .program_headers
+        .iter_mut()
+        .find(|p| p.ty == ProgramHeaderType::GnuStack)
+        .unwrap();
+    stack.flags = stack.flags.with_executable(true);
+
+    assert!(!elf.has_nx_stack());
+    assert!(!elf.security_properties().nx_stack);
+}
@@ -0,0 +1,59 @@
+#![cfg(all(feature = "std", feature = "serde"))]
+
+mod common;
+
+use common::{fixture_bytes, load_fixture};
+use elfsmith::{Blob, ELFFile, Header, ProgramHeader, SectionHeader};
+
+#[test]
+fn file_round_trip() {
+    let elf = load_fixture("hello");
+
+    let json = serde_json::to_string(&elf).unwrap();
+    let deserialized: ELFFile = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized, elf);
+    assert_eq!(deserialized.to_bytes().unwrap(), fixture_bytes("hello"));
+}
+
+#[test]
+fn header_round_trip() {
+    let elf = load_fixture("libgnu.so");
+
+    let json = serde_json::to_value(&elf.header).unwrap();
+    assert_eq!(serde_json::from_value::<Header>(json).unwrap(), elf.header);
+
+    for program in &elf.program_headers {
+        let json = serde_json::to_string(program).unwrap();
+        assert_eq!(
+            &serde_json::from_str::<ProgramHeader>(&json).unwrap(),
+            program
+        );
+    }
+
+    for section in &elf.section_headers {
+        let json = serde_json::to_string(section).unwrap();
+        assert_eq!(
+            &serde_json::from_str::<SectionHeader>(&json).unwrap(),
+            section
+        );
+    }
+}
+
+#[test]
+fn blob_as_hex() {
+    let blob = Blob::from(vec![0x7f, b'E', b'L', b'F', 0x00, 0xff]);
+
+    assert_eq!(serde_json::to_string(&blob).unwrap(), "\"7f454c4600ff\"");
+    assert_eq!(
+        serde_json::from_str::<Blob>("\"7f454c4600ff\"").unwrap(),
+        blob
+    );
+    assert_eq!(
+        serde_json::from_str::<Blob>("\"\"").unwrap(),
+        Blob::default()
+    );
+
+    assert!(serde_json::from_str::<Blob>("\"7f4\"").is_err());
+    assert!(serde_json::from_str::<Blob>("\"zz\"").is_err());
+}
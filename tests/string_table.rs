@@ -0,0 +1,84 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{StringTable, UnpackError};
+
+const DATA: &[u8] = b"\0.text\0.data\0tail";
+
+#[test]
+fn strings_by_offset() {
+    let table = StringTable::new(DATA);
+
+    assert_eq!(table.get(0).unwrap(), "");
+    assert_eq!(table.get(1).unwrap(), ".text");
+    assert_eq!(table.get(7).unwrap(), ".data");
+    // Offsets may point into the middle of a string
+    assert_eq!(table.get(3).unwrap(), "ext");
+    assert_eq!(table.get_bytes(8).unwrap(), b"data");
+}
+
+#[test]
+fn out_of_range_offsets() {
+    let table = StringTable::new(DATA);
+
+    assert!(matches!(
+        table.get(100),
+        Err(UnpackError::InvalidString { offset: 100 })
+    ));
+    assert!(matches!(
+        table.get(u32::MAX),
+        Err(UnpackError::InvalidString { offset: 0xffffffff })
+    ));
+    assert!(table.get(DATA.len() as u32).is_err());
+    assert!(StringTable::new(&[]).get(0).is_err());
+}
+
+#[test]
+fn unterminated_tail() {
+    let table = StringTable::new(DATA);
+
+    assert!(matches!(
+        table.get(13),
+        Err(UnpackError::InvalidString { offset: 13 })
+    ));
+    assert!(table.get_lossy(15).is_err());
+
+    let strings: Vec<_> = table.iter().collect();
+    assert_eq!(strings.len(), 4);
+    assert_eq!(strings[1].0, 1);
+    assert_eq!(strings[2].0, 7);
+    assert_eq!(*strings[2].1.as_ref().unwrap(), ".data");
+    assert!(matches!(
+        strings[3],
+        (13, Err(UnpackError::InvalidString { offset: 13 }))
+    ));
+}
+
+#[test]
+fn invalid_utf8() {
+    let table = StringTable::new(b"\0ab\xffc\0");
+
+    assert!(table.get(1).is_err());
+    assert_eq!(table.get_bytes(1).unwrap(), b"ab\xffc");
+    assert_eq!(table.get_lossy(1).unwrap(), "ab\u{fffd}c");
+}
+
+#[test]
+fn string_tables_of_real_binary() {
+    let elf = load_fixture("hello");
+
+    let dynstr = elf.find_section(".dynstr").unwrap();
+    let table = dynstr.as_string_table().unwrap();
+    let strings: Vec<&str> = table.iter().map(|(_, s)| s.unwrap()).collect();
+    assert_eq!(strings[0], "");
+    assert!(strings.contains(&"libc.so.6"));
+    assert!(strings.contains(&"printf"));
+
+    assert!(elf
+        .find_section(".text")
+        .unwrap()
+        .as_string_table()
+        .is_none());
+}
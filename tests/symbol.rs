@@ -0,0 +1,207 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{fixture_bytes, load_fixture};
+use elfsmith::{
+    Class, SectionIndex, SectionType, Symbol, SymbolBinding, SymbolSection, SymbolTable,
+    SymbolType, SymbolVisibility,
+};
+
+/// Reads the `.symtab` of the fixture `name`
+fn symtab(name: &str) -> SymbolTable {
+    let elf = load_fixture(name);
+    let index = elf.section_index_by_name(".symtab").unwrap();
+
+    elf.read_symbol_table(&mut Cursor::new(fixture_bytes(name)), index)
+        .unwrap()
+}
+
+/// Returns the symbol named `name` in `table`
+fn symbol<'a>(table: &'a SymbolTable, name: &str) -> &'a Symbol {
+    table
+        .symbols
+        .iter()
+        .find(|s| s.name.as_deref() == Some(name))
+        .unwrap()
+}
+
+#[test]
+fn main_of_executable() {
+    let table = symtab("hello");
+
+    let main = symbol(&table, "main");
+    assert_eq!(main.value, 0x1163);
+    assert_eq!(main.size, 48);
+    assert_eq!(main.section_index, 15);
+}
+
+#[test]
+fn main_of_object() {
+    let table = symtab("hello.o");
+
+    let main = symbol(&table, "main");
+    assert_eq!(main.value, 0x1a);
+    assert_eq!(main.size, 48);
+    assert_eq!(table.symbols.len(), 11);
+}
+
+#[test]
+fn main_of_32_bit_object() {
+    let elf = load_fixture("small32.o");
+    assert_eq!(elf.header.ident.class, Class::ELF32);
+
+    let table = symtab("small32.o");
+    assert_eq!(table.symbols.len(), 5);
+
+    let main = symbol(&table, "main");
+    assert_eq!((main.value, main.size, main.section_index), (0, 42, 1));
+
+    let data = symbol(&table, "data");
+    assert_eq!((data.value, data.size, data.section_index), (0, 4, 3));
+}
+
+#[test]
+fn null_symbol_comes_first() {
+    for name in ["hello", "hello.o", "small32.o"] {
+        let table = symtab(name);
+
+        let null = &table.symbols[0];
+        assert_eq!(null.name.as_deref(), Some(""), "{}", name);
+        assert_eq!(
+            (
+                null.info,
+                null.other,
+                null.section_index,
+                null.value,
+                null.size
+            ),
+            (0, 0, 0, 0, 0),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn symbols_iterate_by_entry_size() {
+    let elf = load_fixture("hello");
+    let (_, dynsym) = elf.sections_of_type(SectionType::DynSymbol).next().unwrap();
+
+    let count = dynsym.iter_symbols(Class::ELF64, false).count() as u64;
+    assert_eq!(count * dynsym.entry_size, dynsym.size);
+    assert_eq!(dynsym.entry_size, Symbol::get_size(Class::ELF64));
+    assert_eq!(Symbol::get_size(Class::ELF32), 16);
+}
+
+#[test]
+fn symbol_kinds_of_object() {
+    let table = symtab("hello.o");
+
+    let weak = symbol(&table, "weak_fn");
+    assert_eq!(weak.binding(), SymbolBinding::Weak);
+    assert_eq!(weak.symbol_type(), SymbolType::Func);
+    assert_eq!(weak.visibility(), SymbolVisibility::Default);
+
+    let hidden = symbol(&table, "hid");
+    assert_eq!(hidden.binding(), SymbolBinding::Global);
+    assert_eq!(hidden.visibility(), SymbolVisibility::Hidden);
+
+    let ifunc = symbol(&table, "ifunc_fn");
+    assert_eq!(ifunc.symbol_type(), SymbolType::GnuIFunc);
+    assert_eq!(ifunc.binding(), SymbolBinding::Global);
+
+    let local = symbol(&table, "ifunc_impl");
+    assert_eq!(local.binding(), SymbolBinding::Local);
+    assert_eq!(local.symbol_type(), SymbolType::Func);
+}
+
+#[test]
+fn symbol_setters_keep_other_bits() {
+    let table = symtab("hello.o");
+    let mut symbol = symbol(&table, "hid").clone();
+    symbol.other |= 0xf0;
+
+    symbol.set_binding(SymbolBinding::Weak);
+    assert_eq!(symbol.binding(), SymbolBinding::Weak);
+    assert_eq!(symbol.symbol_type(), SymbolType::Func);
+
+    symbol.set_symbol_type(SymbolType::GnuIFunc);
+    assert_eq!(symbol.symbol_type(), SymbolType::GnuIFunc);
+    assert_eq!(symbol.binding(), SymbolBinding::Weak);
+    assert_eq!(symbol.info, 0x2a);
+
+    symbol.set_visibility(SymbolVisibility::Protected);
+    assert_eq!(symbol.visibility(), SymbolVisibility::Protected);
+    assert_eq!(symbol.other, 0xf3);
+
+    symbol.set_binding(SymbolBinding::Other(0xd));
+    assert_eq!(symbol.binding(), SymbolBinding::Other(0xd));
+    assert_eq!(symbol.symbol_type(), SymbolType::GnuIFunc);
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_symbol_accessors() {
+    let table = symtab("hello.o");
+    let mut symbol = symbol(&table, "hid").clone();
+    assert_eq!(symbol.ty(), symbol.symbol_type());
+
+    symbol.set_ty(SymbolType::Object);
+    assert_eq!(symbol.symbol_type(), SymbolType::Object);
+
+    let visibility: elfsmith::Visibility = symbol.visibility();
+    assert_eq!(visibility, SymbolVisibility::Hidden);
+}
+
+#[test]
+fn special_section_indices_of_object() {
+    let elf = load_fixture("hello.o");
+    let table = symtab("hello.o");
+
+    let file = symbol(&table, "hello.c");
+    assert_eq!(file.symbol_type(), SymbolType::File);
+    assert_eq!(file.section_index, 0xfff1);
+    assert_eq!(file.section(), SectionIndex::Abs);
+    assert_eq!(elf.section_for_symbol(file), SymbolSection::Abs);
+
+    let common = symbol(&table, "counter");
+    assert_eq!(common.section_index, 0xfff2);
+    assert_eq!(common.section(), SectionIndex::Common);
+    assert_eq!(elf.section_for_symbol(common), SymbolSection::Common);
+
+    let printf = symbol(&table, "printf");
+    assert_eq!(printf.section(), SectionIndex::Undefined);
+    assert_eq!(elf.section_for_symbol(printf), SymbolSection::Undefined);
+
+    let main = symbol(&table, "main");
+    assert_eq!(main.section(), SectionIndex::Normal(1));
+    assert_eq!(
+        elf.section_for_symbol(main),
+        SymbolSection::Section(elf.find_section(".text").unwrap())
+    );
+}
+
+#[test]
+fn unresolvable_section_indices() {
+    let elf = load_fixture("hello.o");
+    let mut symbol = symbol(&symtab("hello.o"), "main").clone();
+
+    for (index, expected) in [
+        (0x7fff, SectionIndex::Normal(0x7fff)),
+        (0xff00, SectionIndex::ProcessorSpecific(0xff00)),
+        (0xff20, SectionIndex::OsSpecific(0xff20)),
+        (0xff80, SectionIndex::Reserved(0xff80)),
+        (0xffff, SectionIndex::XIndex),
+    ] {
+        symbol.section_index = index;
+        assert_eq!(symbol.section(), expected);
+        assert_eq!(expected.value(), index);
+        assert_eq!(
+            elf.section_for_symbol(&symbol),
+            SymbolSection::Unresolved(expected)
+        );
+    }
+}
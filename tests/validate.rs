@@ -0,0 +1,94 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::load_fixture;
+use elfsmith::{ELFValidationError, ProgramHeaderType};
+
+#[test]
+fn valid_files() {
+    for fixture in ["hello", "hello.o", "static", "libgnu.so", "small32.o"] {
+        assert_eq!(load_fixture(fixture).validate(), Vec::new(), "{}", fixture);
+    }
+}
+
+#[test]
+fn overlapping_sections() {
+    let mut elf = load_fixture("hello");
+    let text = elf.section_index_by_name(".text").unwrap();
+    let fini = elf.section_index_by_name(".fini").unwrap();
+
+    elf.section_headers[fini].offset = elf.section_headers[text].offset + 1;
+    assert_eq!(
+        elf.validate(),
+        [ELFValidationError::OverlappingSections {
+            a: text.min(fini),
+            b: text.max(fini)
+        }]
+    );
+
+    elf.section_headers[fini].offset = elf.section_headers[text].offset;
+    assert_eq!(
+        elf.validate(),
+        [ELFValidationError::DuplicateSectionOffset {
+            a: text.min(fini),
+            b: text.max(fini)
+        }]
+    );
+}
+
+#[test]
+fn section_names_index_out_of_range() {
+    let mut elf = load_fixture("hello");
+    elf.header.sh_str_index = elf.section_headers.len() as u16;
+
+    assert_eq!(elf.validate(), [ELFValidationError::ShStrIndexOutOfRange]);
+}
+
+#[test]
+fn section_size_overflow() {
+    let mut elf = load_fixture("hello");
+    let text = elf.section_index_by_name(".text").unwrap();
+    elf.section_headers[text].offset = u64::MAX - 1;
+
+    assert_eq!(
+        elf.validate(),
+        [ELFValidationError::SectionSizeOverflow { index: text }]
+    );
+}
+
+#[test]
+fn header_tables_out_of_bounds() {
+    let mut elf = load_fixture("hello");
+    elf.header.ph_offset = 0x10;
+    elf.header.sh_offset = u64::MAX - 0x10;
+
+    assert_eq!(
+        elf.validate(),
+        [
+            ELFValidationError::SectionHeaderOffsetOutOfBounds,
+            ELFValidationError::ProgramHeaderOffsetOutOfBounds
+        ]
+    );
+}
+
+#[test]
+fn empty_loadable_segment_and_unaligned_section() {
+    let mut elf = load_fixture("hello");
+    let (index, _) = elf
+        .programs_of_type(ProgramHeaderType::Loadable)
+        .next()
+        .unwrap();
+    elf.program_headers[index].mem_size = 0;
+
+    let text = elf.section_index_by_name(".text").unwrap();
+    elf.section_headers[text].addr_align = 3;
+
+    assert_eq!(
+        elf.validate(),
+        [
+            ELFValidationError::ZeroSizeLoadableSegment { index },
+            ELFValidationError::UnalignedSection { index: text }
+        ]
+    );
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{fixture_bytes, load_fixture};
+use elfsmith::{elf_hash, Blob, ELFFile, StringTable, VersionDef, VersionNeed, VersionSymbol};
+
+#[test]
+fn glibc_requirements() {
+    let elf = load_fixture("hello");
+    let needs = elf.version_needs().unwrap();
+
+    assert_eq!(needs.len(), 1);
+    assert_eq!(needs[0].version, 1);
+    assert_eq!(needs[0].file, "libc.so.6");
+
+    // Both entries of the need are followed through `vna_next`
+    let versions: Vec<(&str, u16)> = needs[0]
+        .entries
+        .iter()
+        .map(|e| (e.name.as_str(), e.index))
+        .collect();
+    assert_eq!(versions, [("GLIBC_2.2.5", 3), ("GLIBC_2.34", 2)]);
+
+    assert!(elf.version_definitions().unwrap().is_empty());
+}
+
+#[test]
+fn version_symbol_table() {
+    let elf = load_fixture("hello");
+    let versions = elf.version_symbols().unwrap();
+
+    let indices: Vec<u16> = versions.iter().map(|v| v.index()).collect();
+    assert_eq!(indices, [0, 2, 1, 3, 1, 1, 3]);
+    assert!(versions.iter().all(|v| !v.is_hidden()));
+}
+
+#[test]
+fn version_definitions() {
+    let elf = load_fixture("libgnu.so");
+    let definitions = elf.version_definitions().unwrap();
+
+    assert_eq!(definitions.len(), 2);
+
+    assert_eq!(definitions[0].flags, VersionDef::FLAG_BASE);
+    assert_eq!(definitions[0].index, 1);
+    assert_eq!(definitions[0].name(), Some("libgnu.so.1"));
+
+    assert_eq!(definitions[1].flags, 0);
+    assert_eq!(definitions[1].index, 2);
+    assert_eq!(definitions[1].name(), Some("LIBGNU_1.0"));
+    assert_eq!(definitions[1].hash, elf_hash(b"LIBGNU_1.0"));
+}
+
+#[test]
+fn hidden_bit() {
+    let version = VersionSymbol(VersionSymbol::HIDDEN | 2);
+
+    assert!(version.is_hidden());
+    assert_eq!(version.index(), 2);
+
+    let table =
+        VersionSymbol::parse_table(&Blob::from(vec![0x80, 0x03, 0x00, 0x01]), true).unwrap();
+    assert_eq!(table, [VersionSymbol(0x8003), VersionSymbol(1)]);
+}
+
+#[test]
+fn need_chain_ends_at_zero() {
+    let strings = b"\0libc.so.6\0GLIBC_2.34\0";
+
+    // A need with a single aux entry, both with a `next` offset of 0
+    let mut data = Vec::new();
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&16u32.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&0u16.to_le_bytes());
+    data.extend_from_slice(&2u16.to_le_bytes());
+    data.extend_from_slice(&11u32.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+
+    let needs =
+        VersionNeed::parse_table(&Blob::from(data), false, &StringTable::new(strings)).unwrap();
+
+    assert_eq!(needs.len(), 1);
+    assert_eq!(needs[0].file, "libc.so.6");
+    assert_eq!(needs[0].entries.len(), 1);
+    assert_eq!(needs[0].entries[0].name, "GLIBC_2.34");
+}
+
+/// Returns the `name@version` pairs of the versioned dynamic symbols of the fixture `name`
+fn versioned_symbols(name: &str) -> Vec<(String, Option<String>, bool)> {
+    load_fixture(name)
+        .dynamic_symbols_with_versions()
+        .unwrap()
+        .into_iter()
+        .map(|s| (s.symbol.name.unwrap_or_default(), s.version, s.is_default))
+        .collect()
+}
+
+#[test]
+fn required_symbol_versions() {
+    let symbols = versioned_symbols("hello");
+
+    assert_eq!(symbols.len(), 7);
+    assert_eq!(
+        symbols[1],
+        ("__libc_start_main".into(), Some("GLIBC_2.34".into()), false)
+    );
+    assert_eq!(
+        symbols[3],
+        ("printf".into(), Some("GLIBC_2.2.5".into()), false)
+    );
+
+    // `VER_NDX_LOCAL` and `VER_NDX_GLOBAL` have no version
+    assert_eq!(symbols[0], (String::new(), None, false));
+    assert_eq!(symbols[4], ("__gmon_start__".into(), None, false));
+}
+
+#[test]
+fn defined_symbol_versions() {
+    let symbols = versioned_symbols("libgnu.so");
+
+    assert_eq!(
+        symbols[2],
+        ("puts".into(), Some("GLIBC_2.2.5".into()), false)
+    );
+
+    // Symbols defined by the file refer to its own version definitions
+    for index in [6, 7, 8, 10] {
+        assert_eq!(symbols[index].1.as_deref(), Some("LIBGNU_1.0"));
+        assert!(symbols[index].2, "{:?}", symbols[index]);
+    }
+
+    // The version definition symbol itself is absolute, not undefined
+    assert_eq!(
+        symbols[9],
+        ("LIBGNU_1.0".into(), Some("LIBGNU_1.0".into()), true)
+    );
+}
+
+#[test]
+fn symbol_version_map() {
+    let bytes = fixture_bytes("libgnu.so");
+    let elf = ELFFile::from_bytes(&bytes).unwrap();
+    let map = elf.symbol_version_map(&mut Cursor::new(&bytes)).unwrap();
+
+    assert_eq!(map.len(), 7);
+    assert_eq!(map[&2], "GLIBC_2.2.5");
+    assert_eq!(map[&6], "LIBGNU_1.0");
+    assert!(!map.contains_key(&1));
+}